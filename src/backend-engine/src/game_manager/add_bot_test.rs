@@ -0,0 +1,70 @@
+mod add_bot_tests {
+    use crate::game_manager::pre_game::PreGameInstanceManager;
+    use crate::game_manager::test_util::{join, recv};
+    use backend_framework::wire_api::proto_frj_ngn::ProtoGameType;
+    use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+    use tonic::Code;
+
+    #[tokio::test]
+    async fn host_adds_bots_up_to_max_players() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LoveLetter, (2, 4), 42, Vec::new());
+        let mut host_rcv = join(&mut pre_game, "p1");
+        let _p2_rcv = join(&mut pre_game, "p2");
+        assert!(matches!(recv(&mut host_rcv).await, Inner::JoinGameAck(_)));
+        assert!(matches!(recv(&mut host_rcv).await, Inner::PlayerJoinMsg(_)));
+
+        let status = pre_game.add_bot(&"p2".to_string()).expect_err("p2 isn't host");
+        assert_eq!(Code::FailedPrecondition, status.code());
+
+        assert_eq!("bot:1", pre_game.add_bot(&"p1".to_string()).expect("Host can add a bot"));
+        match recv(&mut host_rcv).await {
+            Inner::PlayerJoinMsg(msg) => assert_eq!("bot:1", msg.player_id),
+            other => panic!("Expected player join, got {:?}", other),
+        }
+
+        // Players who join later see the bot in their ack.
+        let mut p3_rcv = join(&mut pre_game, "p3");
+        match recv(&mut p3_rcv).await {
+            Inner::JoinGameAck(ack) => {
+                let bot = ack.presence.iter().find(|player| player.player_id == "bot:1").expect("Bot has presence");
+                assert!(bot.bot);
+                assert!(bot.connected);
+            },
+            other => panic!("Expected join ack, got {:?}", other),
+        }
+
+        let status = pre_game.add_bot(&"p1".to_string()).expect_err("Game is full");
+        assert_eq!(Code::FailedPrecondition, status.code());
+
+        // Bots are always connected, so they never block the start.
+        let player_ids = pre_game.start_game_pre_check(&"p1".to_string()).expect("Everyone is connected");
+        assert_eq!(vec!["p1".to_string(), "p2".to_string(), "bot:1".to_string(), "p3".to_string()], player_ids);
+    }
+
+    #[tokio::test]
+    async fn kicked_bot_frees_its_player_id() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LoveLetter, (2, 4), 42, Vec::new());
+        let _host_rcv = join(&mut pre_game, "p1");
+        assert_eq!("bot:1", pre_game.add_bot(&"p1".to_string()).unwrap());
+        assert_eq!("bot:2", pre_game.add_bot(&"p1".to_string()).unwrap());
+
+        pre_game.kick_player(&"p1".to_string(), &"bot:1".to_string()).expect("Host can kick a bot");
+        assert_eq!("bot:1", pre_game.add_bot(&"p1".to_string()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn bots_never_become_host() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LoveLetter, (2, 4), 42, Vec::new());
+        let _host_rcv = join(&mut pre_game, "p1");
+        pre_game.add_bot(&"p1".to_string()).unwrap();
+        let _p3_rcv = join(&mut pre_game, "p3");
+
+        pre_game.leave_game(&"p1".to_string()).unwrap();
+        let player_ids = pre_game.start_game_pre_check(&"p3".to_string()).expect("p3 is host");
+        assert_eq!(vec!["bot:1".to_string(), "p3".to_string()], player_ids);
+
+        // A pre-game with only bots left is empty, so it's dropped.
+        pre_game.leave_game(&"p3".to_string()).unwrap();
+        assert!(pre_game.is_empty());
+    }
+}
@@ -1,8 +1,7 @@
+use crate::game_manager::registry::ErasedGameEvent;
 use crate::game_manager::types::GameIdentifier;
-use crate::lost_cities_placeholder::LostCitiesEvent;
-use backend_framework::wire_api::proto_frj_ngn::{ProtoPreGameMessage, ProtoStartGameReply};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoAddBotReply, ProtoKickPlayerReply, ProtoLeaveGameReply, ProtoPreGameMessage, ProtoStartGameReply, ProtoUpdateGameOptionsReply};
 use backend_framework::streaming::StreamSender;
-use love_letter_backend::events::LoveLetterEvent;
 use tokio::sync::oneshot;
 use tonic::Status;
 
@@ -13,21 +12,23 @@ pub trait GameRepository {
     // General (non-game) APIs
 
     fn cleanup_stale_games(&mut self); // Backend only (doesn't exist in RepoClient below)
+    fn handle_expired_deadlines(&mut self); // Backend only
+    fn publish_pregame_presence(&mut self); // Backend only
+    fn play_bot_moves(&mut self); // Backend only
 
     // Pre-game APIs
 
-    fn create_pregame(&mut self, game: GameIdentifier);
+    fn create_pregame(&mut self, game: GameIdentifier, rng_seed: Option<u64>, game_options: Vec<u8>);
     fn register_pregame_stream(&mut self, player_id: String, game: GameIdentifier, stream_out: StreamSender<ProtoPreGameMessage>);
     fn start_game(&mut self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoStartGameReply, Status>>);
-
-    // Data-stream common APIs
-
-    fn notify_game_state(&mut self, player_id: String, game: GameIdentifier);
+    fn leave_game(&mut self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoLeaveGameReply, Status>>);
+    fn kick_player(&mut self, player_id: String, game: GameIdentifier, kicked_player_id: String, response_sender: oneshot::Sender<Result<ProtoKickPlayerReply, Status>>);
+    fn add_bot(&mut self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoAddBotReply, Status>>);
+    fn update_game_options(&mut self, player_id: String, game: GameIdentifier, game_options: Vec<u8>, response_sender: oneshot::Sender<Result<ProtoUpdateGameOptionsReply, Status>>);
 
     // Data-stream game-specific APIs
 
-    fn handle_event_love_letter(&mut self, event: LoveLetterEvent);
-    fn handle_event_lost_cities(&mut self, event: LostCitiesEvent);
+    fn handle_game_event(&mut self, game: GameIdentifier, event: ErasedGameEvent);
 }
 
 /// The "client" or caller of the repository. It has all the same methods as above, just with
@@ -44,16 +45,15 @@ pub trait GameRepositoryClient {
 
     // Pre-game APIs
 
-    fn create_pregame(&self, game: GameIdentifier);
+    fn create_pregame(&self, game: GameIdentifier, rng_seed: Option<u64>, game_options: Vec<u8>);
     fn register_pregame_stream(&self, player_id: String, game: GameIdentifier, stream_out: StreamSender<ProtoPreGameMessage>);
     fn start_game(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoStartGameReply, Status>>);
-
-    // Data-stream common APIs
-
-    fn notify_game_state(&self, player_id: String, game: GameIdentifier);
+    fn leave_game(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoLeaveGameReply, Status>>);
+    fn kick_player(&self, player_id: String, game: GameIdentifier, kicked_player_id: String, response_sender: oneshot::Sender<Result<ProtoKickPlayerReply, Status>>);
+    fn add_bot(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoAddBotReply, Status>>);
+    fn update_game_options(&self, player_id: String, game: GameIdentifier, game_options: Vec<u8>, response_sender: oneshot::Sender<Result<ProtoUpdateGameOptionsReply, Status>>);
 
     // Data-stream game-specific APIs
 
-    fn handle_event_love_letter(&self, event: LoveLetterEvent);
-    fn handle_event_lost_cities(&self, event: LostCitiesEvent);
+    fn handle_game_event(&self, game: GameIdentifier, event: ErasedGameEvent);
 }
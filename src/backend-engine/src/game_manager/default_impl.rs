@@ -1,83 +1,196 @@
 use crate::game_manager::api::GameRepository;
 use crate::game_manager::pre_game::PreGameInstanceManager;
-use crate::game_manager::types::{GameIdentifier, GameType};
-use crate::lost_cities_placeholder::{LostCitiesInstanceManager, LostCitiesEvent};
-use backend_framework::game_instance_manager::GameInstanceManager;
+use crate::game_manager::registry::{ErasedBot, ErasedGameEvent, ErasedGameInstance, GameRegistry};
+use crate::game_manager::store::{GameStore, StoredGame};
+use crate::game_manager::types::GameIdentifier;
+use backend_framework::bot;
+use backend_framework::common_types::ClientInfo;
+use backend_framework::prng::PrngRand;
 use backend_framework::streaming::StreamSender;
-use backend_framework::wire_api::proto_frj_ngn::{ProtoPreGameMessage, ProtoStartGameReply, ProtoGameType};
-use love_letter_backend::LoveLetterInstanceManager;
-use love_letter_backend::events::{LoveLetterEvent, LoveLetterEventType};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoAddBotReply, ProtoKickPlayerReply, ProtoLeaveGameReply, ProtoPreGameMessage, ProtoStartGameReply, ProtoUpdateGameOptionsReply};
+use rand::RngCore;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::oneshot;
-use tonic::Status;
+use tonic::{Code, Status};
 use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::{ProtoJoinGameAck, ProtoGameStartMsg};
-use std::time::{Duration, Instant};
-
-/// Repository for holding instances of games.
-///
-/// TODO:2.5 the following refactor will help game_id collision logic:
-/// ```
-/// # use love_letter_backend::LoveLetterInstanceManager;
-/// # use std::collections::HashMap;
-/// enum GameInstanceManagerMode {
-///     PreGame(PreGameInstanceManager),
-///     LoveLetter(LoveLetterInstanceManager),
-///     // ...
-/// }
-/// struct DefaultGameRepository2 {
-///     games: HashMap<String, GameInstanceManagerMode>,
-/// }
-/// ```
+use std::time::{Duration, Instant, SystemTime};
+
+/// Repository for holding instances of games. It has no knowledge of any specific game, all
+/// game-specific behavior is provided by the plugins in the `GameRegistry`.
 pub(crate) struct DefaultGameRepository {
+    registry: Arc<GameRegistry>,
+    store: Option<Arc<dyn GameStore>>,
     unstarted_games: HashMap<GameIdentifier, PreGameInstanceManager>,
-    love_letter_instances: HashMap<String, LoveLetterInstanceManager>,
-    lost_cities_instances: HashMap<String, LostCitiesInstanceManager>,
+    games: HashMap<GameIdentifier, Box<dyn ErasedGameInstance>>,
+    /// Bots of in-progress games. They aren't saved, since they're recreated from the player IDs.
+    bots: HashMap<GameIdentifier, Vec<Box<dyn ErasedBot>>>,
 }
 
 impl DefaultGameRepository {
 
-    pub fn new() -> Self {
+    pub fn new(registry: Arc<GameRegistry>, store: Option<Arc<dyn GameStore>>) -> Self {
         DefaultGameRepository {
+            registry,
+            store,
             unstarted_games: HashMap::new(),
-            love_letter_instances: HashMap::new(),
-            lost_cities_instances: HashMap::new(),
+            games: HashMap::new(),
+            bots: HashMap::new(),
         }
     }
 
-    fn insert_new_game(&mut self, game: GameIdentifier, player_ids: Vec<String>) -> Result<(), Status> {
-        match game.game_type {
-            GameType::LoveLetter => {
-                if self.love_letter_instances.contains_key(&game.game_id) {
-                    println!("ERROR: Pre-game was created while game with same ID was in progress. This should've been prevented internally, but wasn't. Game: {:?}", game);
-                    return Err(Status::internal("Backend in illegal state, create a new game."));
-                }
-                self.love_letter_instances.insert(game.game_id, LoveLetterInstanceManager::create_new_game(player_ids));
-            },
-            GameType::LostCities => {
-                if self.lost_cities_instances.contains_key(&game.game_id) {
-                    println!("ERROR: Pre-game was created while game with same ID was in progress. This should've been prevented internally, but wasn't. Game: {:?}", game);
-                    return Err(Status::internal("Backend in illegal state, create a new game."));
-                }
-                self.lost_cities_instances.insert(game.game_id, LostCitiesInstanceManager::create_new_game(player_ids));
-            },
+    /// Load games which were in progress before the server restarted. Players reconnect to them
+    /// by re-opening their data streams.
+    pub fn restore_games(&mut self, stored_games: Vec<StoredGame>) {
+        for StoredGame { game, snapshot } in stored_games {
+            let plugin = match self.registry.get(game.game_type) {
+                Some(plugin) => plugin,
+                None => {
+                    println!("WARN: Not restoring game {:?}, its game type is no longer registered.", game);
+                    continue;
+                },
+            };
+
+            match plugin.restore_instance(&snapshot) {
+                Ok(instance) => {
+                    println!("INFO: Restored game {:?}", game);
+                    self.games.insert(game.clone(), instance);
+                    // The game's seed isn't saved, and neither are the bots, so restored bots
+                    // start over with a seed that only depends on the game.
+                    let rng_seed = restored_bots_rng_seed(&game);
+                    self.create_bots(&game, rng_seed);
+                },
+                Err(e) => println!("ERROR: Failed to restore game {:?}: {}", game, e),
+            }
+        }
+    }
+
+    /// Best effort, a game which fails to save is still playable until the server restarts.
+    fn save_game(&self, game: &GameIdentifier) {
+        let store = match &self.store {
+            Some(store) => store,
+            None => return,
+        };
+
+        let snapshot = match self.games.get(game).and_then(|instance| instance.snapshot()) {
+            Some(snapshot) => snapshot,
+            None => return,
+        };
+
+        let stored_game = StoredGame {
+            game: game.clone(),
+            snapshot,
+        };
+        if let Err(e) = store.save(&stored_game) {
+            println!("ERROR: Failed to save game {:?}: {}", game, e);
+        }
+    }
+
+    fn delete_saved_game(&self, game: &GameIdentifier) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.delete(game) {
+                println!("ERROR: Failed to delete saved game {:?}: {}", game, e);
+            }
         }
+    }
+
+    fn insert_new_game(
+        &mut self,
+        game: GameIdentifier,
+        player_ids: Vec<String>,
+        game_options: &[u8],
+        rng_seed: u64,
+    ) -> Result<(), Status> {
+        if self.games.contains_key(&game) {
+            println!("ERROR: Pre-game was created while game with same ID was in progress. This should've been prevented internally, but wasn't. Game: {:?}", game);
+            return Err(Status::internal("Backend in illegal state, create a new game."));
+        }
+
+        let plugin = match self.registry.get(game.game_type) {
+            Some(plugin) => plugin,
+            None => {
+                println!("ERROR: Pre-game was created for a game type with no registered plugin. Game: {:?}", game);
+                return Err(Status::internal("Backend in illegal state, create a new game."));
+            },
+        };
+
+        println!("INFO: Creating game instance {:?} with RNG seed '{}'", game, rng_seed);
+        let game_instance = plugin.create_instance(player_ids, game_options, PrngRand::new(rng_seed))?;
+        self.games.insert(game.clone(), game_instance);
+        self.create_bots(&game, rng_seed);
+        self.save_game(&game);
 
         Ok(())
     }
 
+    /// Take the seat of each of the game's bot players. Bots only get added to games which
+    /// support them, so this only fails if the plugin changed since the game was saved.
+    fn create_bots(&mut self, game: &GameIdentifier, rng_seed: u64) {
+        let (plugin, instance) = match (self.registry.get(game.game_type), self.games.get_mut(game)) {
+            (Some(plugin), Some(instance)) => (plugin, instance),
+            _ => return,
+        };
+
+        let bot_player_ids: Vec<String> = instance.player_ids()
+            .iter()
+            .filter(|player_id| bot::is_bot_player_id(player_id))
+            .cloned()
+            .collect();
+        if bot_player_ids.is_empty() {
+            return;
+        }
+
+        let mut bots = Vec::with_capacity(bot_player_ids.len());
+        for (bot_index, player_id) in bot_player_ids.into_iter().enumerate() {
+            let client_info = ClientInfo {
+                player_id,
+                game_id: game.game_id.clone(),
+            };
+            match plugin.create_bot(client_info.clone(), bot_rng(rng_seed, bot_index)) {
+                Some((event, bot)) => {
+                    instance.handle_event(event);
+                    bots.push(bot);
+                },
+                None => println!("ERROR: Game {:?} has bot player '{}', but the game doesn't support bots.", game, client_info.player_id),
+            }
+        }
+
+        self.bots.insert(game.clone(), bots);
+    }
+
     fn get_player_ids_if_game_exists(&self, game: &GameIdentifier) -> Option<&Vec<String>> {
-        match game.game_type {
-            GameType::LoveLetter => {
-                self.love_letter_instances
-                    .get(&game.game_id)
-                    .map(|gim| gim.player_ids())
+        self.games
+            .get(game)
+            .map(|gim| gim.player_ids())
+    }
+
+    /// Apply a change to the pre-game, and drop the pre-game if no players are left.
+    fn update_pregame<R>(
+        &mut self,
+        game_id: GameIdentifier,
+        update: impl FnOnce(&mut PreGameInstanceManager) -> Result<R, Status>,
+    ) -> Result<R, Status> {
+        let pre_game_instance_manager = match self.unstarted_games.get_mut(&game_id) {
+            Some(instance_manager) => instance_manager,
+            None if self.games.contains_key(&game_id) => {
+                return Err(Status::failed_precondition("Game has already started."));
             },
-            GameType::LostCities => {
-                self.lost_cities_instances
-                    .get(&game.game_id)
-                    .map(|gim| gim.player_ids())
+            None => {
+                return Err(Status::not_found(format!(
+                    "{} Game ID '{}' does not exist.",
+                    self.registry.display_name(game_id.game_type),
+                    game_id.game_id
+                )));
             },
+        };
+
+        let result = update(pre_game_instance_manager);
+        if pre_game_instance_manager.is_empty() {
+            println!("INFO: Every player left pre-game '{}', dropping it.", game_id.game_id);
+            self.unstarted_games.remove(&game_id);
         }
+
+        result
     }
 }
 
@@ -85,10 +198,9 @@ impl GameRepository for DefaultGameRepository {
     /// Garbage collection
     fn cleanup_stale_games(&mut self) {
         println!(
-            "Cleaning up stale games. Games to scan: Unstarted={}, LoveLetter={}, LostCities={}.",
+            "Cleaning up stale games. Games to scan: Unstarted={}, InProgress={}.",
             self.unstarted_games.len(),
-            self.love_letter_instances.len(),
-            self.lost_cities_instances.len()
+            self.games.len()
         );
 
         let before = Instant::now();
@@ -97,35 +209,123 @@ impl GameRepository for DefaultGameRepository {
         // where `n` can be in the tens or hundreds of thousands. Will my games ever be that
         // successful? Probably not.
         let expiry_duration = Duration::from_secs(60 * 10);
+        let complete_game_expiry_duration = Duration::from_secs(60 * 2);
         self.unstarted_games.retain(|_, g| !g.activity_tracker.has_inactivity_elapsed(expiry_duration));
-        self.love_letter_instances.retain(|_, g| !g.is_game_stale(expiry_duration));
-        self.lost_cities_instances.retain(|_, g| !g.is_game_stale(expiry_duration));
+        let stale_games: Vec<GameIdentifier> = self.games
+            .iter()
+            .filter(|(_, g)| if g.is_game_complete() {
+                g.is_game_stale(complete_game_expiry_duration)
+            } else {
+                g.is_game_stale(expiry_duration)
+            })
+            .map(|(game, _)| game.clone())
+            .collect();
+        for game in stale_games {
+            self.games.remove(&game);
+            self.bots.remove(&game);
+            self.delete_saved_game(&game);
+        }
 
         let latency = Instant::now().saturating_duration_since(before);
         println!(
-            "GC done after {}ms. Game count after clean up: Unstarted={}, LoveLetter={}, LostCities={}.",
+            "GC done after {}ms. Game count after clean up: Unstarted={}, InProgress={}.",
             latency.as_millis(),
             self.unstarted_games.len(),
-            self.love_letter_instances.len(),
-            self.lost_cities_instances.len()
+            self.games.len()
         );
     }
 
-    /// Idempotent-ly creates a new generic "pre-game" instance manager for this game.
-    fn create_pregame(&mut self, game: GameIdentifier) {
+    /// Turn timers. Every in-progress game is checked, games without time limits return
+    /// immediately.
+    fn handle_expired_deadlines(&mut self) {
+        let now = SystemTime::now();
+        let changed_games: Vec<GameIdentifier> = self.games
+            .iter_mut()
+            .filter_map(|(game, instance)| if instance.handle_expired_deadlines(now) {
+                Some(game.clone())
+            } else {
+                None
+            })
+            .collect();
+        for game in changed_games {
+            self.save_game(&game);
+        }
+    }
+
+    /// Let pre-game players know who disconnected, since streams closing doesn't trigger any event.
+    fn publish_pregame_presence(&mut self) {
+        for pre_game_instance_manager in self.unstarted_games.values_mut() {
+            pre_game_instance_manager.publish_presence_changes();
+        }
+    }
+
+    /// Bots play at most one batch of moves per call, so humans can follow along.
+    fn play_bot_moves(&mut self) {
+        let mut changed_games = Vec::new();
+        for (game, bots) in self.bots.iter_mut() {
+            let (plugin, instance) = match (self.registry.get(game.game_type), self.games.get_mut(game)) {
+                (Some(plugin), Some(instance)) => (plugin, instance),
+                _ => continue,
+            };
+
+            for bot in bots.iter_mut() {
+                let moves = bot.take_moves();
+                if moves.is_empty() {
+                    continue;
+                }
+
+                let client_info = ClientInfo {
+                    player_id: bot.player_id().to_string(),
+                    game_id: game.game_id.clone(),
+                };
+                for payload in moves {
+                    let changed = match plugin.decode_event(client_info.clone(), &payload) {
+                        Ok(event) => instance.handle_event(event),
+                        Err(status) => {
+                            println!("ERROR: Bot '{}' made a move that can't be decoded: {:?}", client_info.player_id, status);
+                            false
+                        },
+                    };
+                    if changed && !changed_games.contains(game) {
+                        changed_games.push(game.clone());
+                    }
+                }
+            }
+        }
+
+        for game in changed_games {
+            self.save_game(&game);
+        }
+    }
+
+    /// Idempotent-ly creates a new generic "pre-game" instance manager for this game. If there's
+    /// no `rng_seed`, a random one is chosen. `game_options` must already be validated by the
+    /// game's plugin.
+    fn create_pregame(&mut self, game: GameIdentifier, rng_seed: Option<u64>, game_options: Vec<u8>) {
         // Ensure in-progress game doesn't exist with same ID. Don't actually notify client of
-        // failure here, they'll get a failure below in `register_pregame_stream()`. See comments
-        // on struct level above.
+        // failure here, they'll get a failure below in `register_pregame_stream()`.
         if self.get_player_ids_if_game_exists(&game).is_some() {
             println!("WARN: Attempted to create pre-game with colliding game_id as in-progress game.");
             return;
         }
 
+        let player_count_min_max = match self.registry.get(game.game_type) {
+            Some(plugin) => plugin.player_count_min_max(),
+            None => {
+                println!("WARN: Attempted to create pre-game for unsupported game type {:?}.", game.game_type);
+                return;
+            },
+        };
+
         println!("INFO: Creating game {:?}", game);
         let game_type = game.game_type;
         self.unstarted_games
             .entry(game)
-            .or_insert_with(|| PreGameInstanceManager::new(game_type));
+            .or_insert_with(|| {
+                // PRNG can't be seeded with 0.
+                let rng_seed = rng_seed.unwrap_or_else(|| rand::thread_rng().next_u64().max(1));
+                PreGameInstanceManager::new(game_type, player_count_min_max, rng_seed, game_options)
+            });
     }
 
     fn register_pregame_stream(
@@ -148,16 +348,18 @@ impl GameRepository for DefaultGameRepository {
                 // Notify caller of NotFound.
                 let _ = stream_out.send_error_message(Status::not_found(format!(
                     "{} Game ID '{}' does not exist.",
-                    game_id.game_type,
+                    self.registry.display_name(game_id.game_type),
                     game_id.game_id
                 )));
             },
             Some(player_ids) => {
                 let mut player_ids = player_ids.clone();
                 let ack = ProtoJoinGameAck {
-                    game_type: ProtoGameType::from(game_id.game_type) as i32,
+                    game_type: game_id.game_type.into(),
                     host_player_id: player_ids.remove(0),
                     other_player_ids: player_ids,
+                    presence: Vec::new(),
+                    game_options: Vec::new(),
                 };
                 // Notify caller that game started.
                 let _ = stream_out.send_message(ack.into());
@@ -172,7 +374,7 @@ impl GameRepository for DefaultGameRepository {
         game_id: GameIdentifier,
         response_sender: oneshot::Sender<Result<ProtoStartGameReply, Status>>
     ) {
-        let response_sender = StartGameReplySender(response_sender);
+        let response_sender = ReplySender(response_sender);
 
         // Pop the GIM out
         let pre_game_instance_manager = match self.unstarted_games.remove(&game_id) {
@@ -188,7 +390,7 @@ impl GameRepository for DefaultGameRepository {
                     // Notify game not found
                     .ok_or_else(|| Status::not_found(format!(
                         "{} Game ID '{}' does not exist.",
-                        game_id.game_type,
+                        self.registry.display_name(game_id.game_type),
                         game_id.game_id
                     )));
                 response_sender.send(msg);
@@ -207,8 +409,11 @@ impl GameRepository for DefaultGameRepository {
             },
         };
 
-        // Create the specific type of game instance.
-        match self.insert_new_game(game_id, player_ids.clone()) {
+        // Create the specific type of game instance. Fails if the host's game options don't fit the
+        // players, which the host can fix by changing the options or players, so the pre-game is
+        // kept. Any other failure drops the game.
+        let game_options = &pre_game_instance_manager.game_options;
+        match self.insert_new_game(game_id.clone(), player_ids.clone(), game_options, pre_game_instance_manager.rng_seed) {
             Ok(_) => {
                 // Notifying party leader of all player IDs when the game is going to start is redundant,
                 // because the first game data-stream will include relevant game state including player IDs.
@@ -220,6 +425,10 @@ impl GameRepository for DefaultGameRepository {
                 pre_game_instance_manager.start_game_notify_players();
                 println!("DEBUG: Done notifying all players of game start.");
             },
+            Err(msg) if msg.code() == Code::InvalidArgument => {
+                response_sender.send(Err(msg));
+                self.unstarted_games.insert(game_id, pre_game_instance_manager);
+            },
             Err(msg) => {
                 response_sender.send(Err(msg.clone()));
                 pre_game_instance_manager.drop_game_notify_players(msg);
@@ -227,34 +436,107 @@ impl GameRepository for DefaultGameRepository {
         }
     }
 
-    fn notify_game_state(&mut self, _player_id: String, _game: GameIdentifier) {
-        unimplemented!("DefaultGameRepository::notify_game_state()")
+    fn leave_game(
+        &mut self,
+        player_id: String,
+        game_id: GameIdentifier,
+        response_sender: oneshot::Sender<Result<ProtoLeaveGameReply, Status>>
+    ) {
+        println!("INFO: Player '{}' leaving game '{}'", player_id, game_id.game_id);
+        let result = self.update_pregame(game_id, |pre_game_instance_manager| {
+            pre_game_instance_manager.leave_game(&player_id)
+        });
+        ReplySender(response_sender).send(result.map(|_| ProtoLeaveGameReply {}));
     }
 
-    fn handle_event_love_letter(&mut self, event: LoveLetterEvent) {
-        println!("DEBUG: DefaultGameRepository received {:?}", event);
+    fn kick_player(
+        &mut self,
+        player_id: String,
+        game_id: GameIdentifier,
+        kicked_player_id: String,
+        response_sender: oneshot::Sender<Result<ProtoKickPlayerReply, Status>>
+    ) {
+        println!("INFO: Player '{}' kicking '{}' from game '{}'", player_id, kicked_player_id, game_id.game_id);
+        let result = self.update_pregame(game_id, |pre_game_instance_manager| {
+            pre_game_instance_manager.kick_player(&player_id, &kicked_player_id)
+        });
+        ReplySender(response_sender).send(result.map(|_| ProtoKickPlayerReply {}));
+    }
 
-        if let Some(game) = self.love_letter_instances.get_mut(&event.client_info.game_id) {
-            // TODO:3 this unnecessarily leaks `game_id` into individual instance managers
-            game.handle_event(event);
-        } else if let LoveLetterEventType::RegisterDataStream(stream) = event.payload {
-            let _ = stream.send_error_message(Status::not_found(format!("Game {} not found", event.client_info.game_id)));
-        } else {
-            // Can't notify client of game not found (because of how I modeled the code).
-            // Should probably fix this at some point...
+    fn add_bot(
+        &mut self,
+        player_id: String,
+        game_id: GameIdentifier,
+        response_sender: oneshot::Sender<Result<ProtoAddBotReply, Status>>
+    ) {
+        let response_sender = ReplySender(response_sender);
+
+        let supports_bots = match self.registry.get(game_id.game_type) {
+            Some(plugin) => plugin.supports_bots(),
+            None => false,
+        };
+        if !supports_bots {
+            response_sender.send(Err(Status::unimplemented(format!(
+                "{} doesn't support bots.",
+                self.registry.display_name(game_id.game_type)
+            ))));
+            return;
         }
+
+        println!("INFO: Player '{}' adding a bot to game '{}'", player_id, game_id.game_id);
+        let result = self.update_pregame(game_id, |pre_game_instance_manager| {
+            pre_game_instance_manager.add_bot(&player_id)
+        });
+        response_sender.send(result.map(|bot_player_id| ProtoAddBotReply { bot_player_id }));
     }
 
-    fn handle_event_lost_cities(&mut self, _event: LostCitiesEvent) {
-        unimplemented!("DefaultGameRepository::handle_event_lost_cities()")
+    fn update_game_options(
+        &mut self,
+        player_id: String,
+        game_id: GameIdentifier,
+        game_options: Vec<u8>,
+        response_sender: oneshot::Sender<Result<ProtoUpdateGameOptionsReply, Status>>
+    ) {
+        let result = self.update_pregame(game_id, |pre_game_instance_manager| {
+            pre_game_instance_manager.update_game_options(&player_id, game_options)
+        });
+        ReplySender(response_sender).send(result.map(|_| ProtoUpdateGameOptionsReply {}));
+    }
+
+    fn handle_game_event(&mut self, game: GameIdentifier, event: ErasedGameEvent) {
+        if let Some(instance) = self.games.get_mut(&game) {
+            // TODO:3 this unnecessarily leaks `game_id` into individual instance managers
+            if instance.handle_event(event) {
+                self.save_game(&game);
+            }
+        } else if let Some(plugin) = self.registry.get(game.game_type) {
+            plugin.reject_event(event, Status::not_found(format!("Game {} not found", game.game_id)));
+        }
     }
 }
 
-struct StartGameReplySender(oneshot::Sender<Result<ProtoStartGameReply, Status>>);
-impl StartGameReplySender {
-    pub fn send(self, message: Result<ProtoStartGameReply, Status>) {
+struct ReplySender<R>(oneshot::Sender<Result<R, Status>>);
+impl<R> ReplySender<R> {
+    pub fn send(self, message: Result<R, Status>) {
         if let Err(_) = self.0.send(message) {
-            println!("INFO: Failed to respond to StartGame call.");
+            println!("INFO: Failed to respond to req-reply call.");
         }
     }
 }
+
+/// Each bot gets its own sequence, derived from the game's seed, so a game with bots plays out
+/// the same every time it's played from the same seed.
+fn bot_rng(rng_seed: u64, bot_index: usize) -> PrngRand {
+    // Golden ratio increments, like SplitMix64, so no two bots share a seed. PRNG can't be seeded
+    // with 0.
+    let offset = (bot_index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    PrngRand::new(rng_seed.wrapping_add(offset).max(1))
+}
+
+/// FNV-1a hash of the game's ID and type, which is stable across server restarts.
+fn restored_bots_rng_seed(game: &GameIdentifier) -> u64 {
+    game.game_id
+        .bytes()
+        .chain((game.game_type as i32).to_le_bytes().iter().copied())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
@@ -0,0 +1,55 @@
+mod game_options_tests {
+    use crate::game_manager::pre_game::PreGameInstanceManager;
+    use crate::game_manager::test_util::{join, recv};
+    use backend_framework::wire_api::proto_frj_ngn::ProtoGameType;
+    use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+    use tonic::Code;
+
+    #[tokio::test]
+    async fn host_options_are_echoed_and_updates_are_broadcast() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::Mastermind, (2, 2), 42, vec![1, 2]);
+        let mut host_rcv = join(&mut pre_game, "p1");
+        match recv(&mut host_rcv).await {
+            Inner::JoinGameAck(ack) => assert_eq!(vec![1, 2], ack.game_options),
+            other => panic!("Expected join ack, got {:?}", other),
+        }
+
+        pre_game.update_game_options(&"p1".to_string(), vec![3]).expect("Host can update options");
+        match recv(&mut host_rcv).await {
+            Inner::GameOptionsMsg(msg) => assert_eq!(vec![3], msg.game_options),
+            other => panic!("Expected game options, got {:?}", other),
+        }
+
+        // A later joiner sees the updated options.
+        let mut p2_rcv = join(&mut pre_game, "p2");
+        match recv(&mut p2_rcv).await {
+            Inner::JoinGameAck(ack) => assert_eq!(vec![3], ack.game_options),
+            other => panic!("Expected join ack, got {:?}", other),
+        }
+        assert!(matches!(recv(&mut host_rcv).await, Inner::PlayerJoinMsg(_)));
+
+        pre_game.update_game_options(&"p1".to_string(), Vec::new()).expect("Host can reset options");
+        for stream_rcv in [&mut host_rcv, &mut p2_rcv] {
+            match recv(stream_rcv).await {
+                Inner::GameOptionsMsg(msg) => assert!(msg.game_options.is_empty()),
+                other => panic!("Expected game options, got {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn non_host_cannot_update_options() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LoveLetter, (2, 4), 42, vec![1]);
+        let _host_rcv = join(&mut pre_game, "p1");
+        let _p2_rcv = join(&mut pre_game, "p2");
+
+        let status = pre_game.update_game_options(&"p2".to_string(), vec![2]).expect_err("p2 isn't host");
+        assert_eq!(Code::FailedPrecondition, status.code());
+
+        let mut p3_rcv = join(&mut pre_game, "p3");
+        match recv(&mut p3_rcv).await {
+            Inner::JoinGameAck(ack) => assert_eq!(vec![1], ack.game_options),
+            other => panic!("Expected join ack, got {:?}", other),
+        }
+    }
+}
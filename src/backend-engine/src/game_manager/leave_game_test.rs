@@ -0,0 +1,90 @@
+mod leave_game_tests {
+    use crate::game_manager::pre_game::PreGameInstanceManager;
+    use crate::game_manager::test_util::{join, recv};
+    use backend_framework::streaming::StreamReceiver;
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoGameType, ProtoPreGameMessage};
+    use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::{Inner, ProtoPlayerLeaveMsg};
+    use tokio::stream::StreamExt;
+    use tonic::Code;
+
+    /// Every player has already received the join messages.
+    async fn setup(player_ids: &[&str]) -> (PreGameInstanceManager, Vec<StreamReceiver<ProtoPreGameMessage>>) {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LoveLetter, (2, 4), 42, Vec::new());
+        let mut stream_rcvs = Vec::new();
+        for player_id in player_ids {
+            stream_rcvs.push(join(&mut pre_game, player_id));
+        }
+
+        for (i, stream_rcv) in stream_rcvs.iter_mut().enumerate() {
+            // Join ack, then a join message for each player who joined later.
+            for _ in i..player_ids.len() {
+                recv(stream_rcv).await;
+            }
+        }
+
+        (pre_game, stream_rcvs)
+    }
+
+    async fn recv_leave_msg(stream_rcv: &mut StreamReceiver<ProtoPreGameMessage>) -> ProtoPlayerLeaveMsg {
+        match recv(stream_rcv).await {
+            Inner::PlayerLeaveMsg(msg) => msg,
+            other => panic!("Expected player leave, got {:?}", other),
+        }
+    }
+
+    fn leave_msg(player_id: &str, kicked: bool, host_player_id: &str) -> ProtoPlayerLeaveMsg {
+        ProtoPlayerLeaveMsg {
+            player_id: player_id.to_string(),
+            kicked,
+            host_player_id: host_player_id.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn host_leaving_makes_next_player_host() {
+        let (mut pre_game, mut stream_rcvs) = setup(&["p1", "p2", "p3"]).await;
+
+        pre_game.leave_game(&"p1".to_string()).expect("Host can leave");
+        for stream_rcv in stream_rcvs.iter_mut() {
+            assert_eq!(leave_msg("p1", false, "p2"), recv_leave_msg(stream_rcv).await);
+        }
+        // The leaving player's stream is closed.
+        assert!(stream_rcvs[0].next().await.is_none());
+
+        let status = pre_game.start_game_pre_check(&"p1".to_string()).expect_err("p1 is no longer host");
+        assert_eq!(Code::FailedPrecondition, status.code());
+        let player_ids = pre_game.start_game_pre_check(&"p2".to_string()).expect("p2 is host");
+        assert_eq!(vec!["p2".to_string(), "p3".to_string()], player_ids);
+    }
+
+    #[tokio::test]
+    async fn only_host_can_kick_other_players() {
+        let (mut pre_game, mut stream_rcvs) = setup(&["p1", "p2", "p3"]).await;
+
+        let status = pre_game.kick_player(&"p2".to_string(), &"p3".to_string()).expect_err("p2 isn't host");
+        assert_eq!(Code::FailedPrecondition, status.code());
+        let status = pre_game.kick_player(&"p1".to_string(), &"p1".to_string()).expect_err("Host can't kick self");
+        assert_eq!(Code::InvalidArgument, status.code());
+        let status = pre_game.kick_player(&"p1".to_string(), &"p4".to_string()).expect_err("p4 never joined");
+        assert_eq!(Code::NotFound, status.code());
+
+        pre_game.kick_player(&"p1".to_string(), &"p2".to_string()).expect("Host can kick");
+        for stream_rcv in stream_rcvs.iter_mut() {
+            assert_eq!(leave_msg("p2", true, "p1"), recv_leave_msg(stream_rcv).await);
+        }
+        assert!(stream_rcvs[1].next().await.is_none());
+
+        let status = pre_game.leave_game(&"p2".to_string()).expect_err("p2 already left");
+        assert_eq!(Code::NotFound, status.code());
+    }
+
+    #[tokio::test]
+    async fn pre_game_is_empty_once_everyone_leaves() {
+        let (mut pre_game, _stream_rcvs) = setup(&["p1", "p2"]).await;
+
+        pre_game.leave_game(&"p2".to_string()).unwrap();
+        assert!(!pre_game.is_empty());
+        pre_game.leave_game(&"p1".to_string()).unwrap();
+        assert!(pre_game.is_empty());
+    }
+}
@@ -1,5 +1,20 @@
 pub mod api;
+pub mod registry;
+pub mod store;
 pub mod types;
 
 pub(crate) mod default_impl;
 mod pre_game;
+
+#[cfg(test)]
+mod add_bot_test;
+#[cfg(test)]
+mod game_options_test;
+#[cfg(test)]
+mod leave_game_test;
+#[cfg(test)]
+mod pre_game_test;
+#[cfg(test)]
+mod store_test;
+#[cfg(test)]
+pub(crate) mod test_util;
@@ -0,0 +1,26 @@
+use crate::game_manager::pre_game::PreGameInstanceManager;
+use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::ProtoGameOptionsMsg;
+use tonic::Status;
+
+impl PreGameInstanceManager {
+
+    /// `game_options` must already be validated by the game's plugin.
+    pub fn update_game_options(&mut self, requesting_player_id: &String, game_options: Vec<u8>) -> Result<(), Status> {
+        if !self.is_party_leader(requesting_player_id) {
+            println!("INFO: Non-party leader '{}' attempted to update game options. Rejecting the call.", requesting_player_id);
+            return Err(Status::failed_precondition("You are not party leader."));
+        }
+
+        self.game_options = game_options;
+
+        let msg = ProtoGameOptionsMsg {
+            game_options: self.game_options.clone(),
+        };
+        for player_id in self.players.player_ids() {
+            self.players.send_pre_game_message(&player_id, msg.clone());
+        }
+
+        self.activity_tracker.ping();
+        Ok(())
+    }
+}
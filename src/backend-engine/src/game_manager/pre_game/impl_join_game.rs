@@ -1,6 +1,7 @@
 use crate::game_manager::pre_game::PreGameInstanceManager;
+use backend_framework::bot;
 use backend_framework::streaming::StreamSender;
-use backend_framework::wire_api::proto_frj_ngn::{ProtoPreGameMessage, ProtoGameType};
+use backend_framework::wire_api::proto_frj_ngn::ProtoPreGameMessage;
 use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::{ProtoJoinGameAck, ProtoPlayerJoinMsg};
 use tonic::Status;
 
@@ -14,6 +15,7 @@ impl PreGameInstanceManager {
         // Reconnect
         if self.players.contains_player(&player_id) {
             self.add_player_and_send_ack(player_id, client_stream);
+            self.publish_presence_changes();
             return;
         }
 
@@ -27,9 +29,34 @@ impl PreGameInstanceManager {
 
         self.add_player_and_send_ack(player_id.clone(), client_stream);
         self.notify_other_players(player_id);
+        self.publish_presence_changes();
         self.activity_tracker.ping();
     }
 
+    /// The bot takes the first free bot player ID. Returns the bot's player ID. The caller must
+    /// check that the game supports bots.
+    pub fn add_bot(&mut self, requesting_player_id: &String) -> Result<String, Status> {
+        if !self.is_party_leader(requesting_player_id) {
+            println!("INFO: Non-party leader '{}' attempted to add a bot. Rejecting the call.", requesting_player_id);
+            return Err(Status::failed_precondition("You are not party leader."));
+        }
+
+        if self.players.count() >= self.max_players {
+            return Err(Status::failed_precondition("Can't add a bot, game has max players"));
+        }
+
+        let bot_player_id = (1..)
+            .map(bot::bot_player_id)
+            .find(|player_id| !self.players.contains_player(player_id))
+            .expect("Some bot player ID is always free");
+
+        self.players.add_bot(bot_player_id.clone());
+        self.notify_other_players(bot_player_id.clone());
+        self.publish_presence_changes();
+        self.activity_tracker.ping();
+        Ok(bot_player_id)
+    }
+
     fn add_player_and_send_ack(&mut self, player_id: String, client_stream: StreamSender<ProtoPreGameMessage>) {
         self.players.add_player(player_id.clone(), client_stream);
         let host_player_id = self.players.party_leader()
@@ -40,9 +67,11 @@ impl PreGameInstanceManager {
         other_player_ids.retain(|id| { id != &host_player_id });
 
         self.players.send_pre_game_message(&player_id, ProtoJoinGameAck {
-            game_type: ProtoGameType::from(self.game_type).into(),
+            game_type: self.game_type.into(),
             host_player_id,
             other_player_ids,
+            presence: self.players.presence(),
+            game_options: self.game_options.clone(),
         })
     }
 
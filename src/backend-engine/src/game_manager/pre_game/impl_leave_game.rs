@@ -0,0 +1,59 @@
+use crate::game_manager::pre_game::PreGameInstanceManager;
+use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::ProtoPlayerLeaveMsg;
+use tonic::Status;
+
+impl PreGameInstanceManager {
+
+    pub fn leave_game(&mut self, player_id: &String) -> Result<(), Status> {
+        if !self.players.contains_player(player_id) {
+            return Err(Status::not_found("You are not in this game."));
+        }
+
+        self.remove_player_and_notify(player_id, false);
+        Ok(())
+    }
+
+    pub fn kick_player(&mut self, requesting_player_id: &String, kicked_player_id: &String) -> Result<(), Status> {
+        if !self.is_party_leader(requesting_player_id) {
+            println!("INFO: Non-party leader '{}' attempted to kick a player. Rejecting the call.", requesting_player_id);
+            return Err(Status::failed_precondition("You are not party leader."));
+        }
+
+        if requesting_player_id == kicked_player_id {
+            return Err(Status::invalid_argument("You can't kick yourself, leave the game instead."));
+        }
+
+        if !self.players.contains_player(kicked_player_id) {
+            return Err(Status::not_found(format!("Player '{}' is not in this game.", kicked_player_id)));
+        }
+
+        self.remove_player_and_notify(kicked_player_id, true);
+        Ok(())
+    }
+
+    /// True once every player has left, so the pre-game can be dropped. Bots don't count, since
+    /// nobody is left to start the game.
+    pub fn is_empty(&self) -> bool {
+        self.players.human_count() == 0
+    }
+
+    /// The removed player is notified too, and then their stream is closed.
+    fn remove_player_and_notify(&mut self, player_id: &String, kicked: bool) {
+        let removed_stream = self.players.remove_player(player_id);
+
+        let msg = ProtoPlayerLeaveMsg {
+            player_id: player_id.clone(),
+            kicked,
+            host_player_id: self.players.party_leader().cloned().unwrap_or_default(),
+        };
+
+        for other_player_id in self.players.player_ids() {
+            self.players.send_pre_game_message(&other_player_id, msg.clone());
+        }
+        if let Some(stream) = removed_stream {
+            let _ = stream.send_message(msg.into());
+        }
+
+        self.activity_tracker.ping();
+    }
+}
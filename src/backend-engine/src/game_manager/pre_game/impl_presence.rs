@@ -0,0 +1,31 @@
+use crate::game_manager::pre_game::PreGameInstanceManager;
+use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::ProtoPlayerPresenceMsg;
+
+impl PreGameInstanceManager {
+
+    /// Notify all players if a player disconnected or reconnected since the last time presence
+    /// was sent. A new player joining isn't a change, since everyone is already notified of the
+    /// join itself.
+    ///
+    /// Disconnects are only noticed when this is called, so it's called periodically.
+    pub fn publish_presence_changes(&mut self) {
+        let presence = self.players.presence();
+        let has_changed = presence
+            .iter()
+            .any(|player| self.published_presence
+                .iter()
+                .any(|published| published.player_id == player.player_id && published != player));
+        self.published_presence = presence.clone();
+
+        if !has_changed {
+            return;
+        }
+
+        let msg = ProtoPlayerPresenceMsg {
+            players: presence,
+        };
+        for player_id in self.players.player_ids() {
+            self.players.send_pre_game_message(&player_id, msg.clone());
+        }
+    }
+}
@@ -23,13 +23,24 @@ impl PreGameInstanceManager {
         }
 
         // If some of the already-joined players disconnected (fatally) without leaving the game,
-        // then this game state will be doomed.
-        // TODO:3 Check for disconnects before starting.
+        // then this game state will be doomed. They can still reconnect before it starts.
+        let disconnected_player_ids = self.players.disconnected_player_ids();
+        if !disconnected_player_ids.is_empty() {
+            println!(
+                "INFO: Attempted to start game '{:?}' with disconnected players {:?}. Rejecting the call.",
+                self.game_type,
+                disconnected_player_ids
+            );
+            return Err(Status::failed_precondition(format!(
+                "Players are disconnected: {}.",
+                disconnected_player_ids.join(", ")
+            )));
+        }
 
         Ok(self.players.player_ids())
     }
 
-    fn is_party_leader(&self, player_id: &String) -> bool {
+    pub(super) fn is_party_leader(&self, player_id: &String) -> bool {
         match self.players.party_leader() {
             None => false,
             Some(party_leader_id) => party_leader_id == player_id,
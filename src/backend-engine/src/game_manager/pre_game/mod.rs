@@ -1,47 +1,52 @@
-use crate::game_manager::types::GameType;
 use backend_framework::activity_timer::ActivityTracker;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoGameType, ProtoPlayerPresence};
 
+mod impl_game_options;
 mod impl_join_game;
+mod impl_leave_game;
+mod impl_presence;
 mod impl_start_game;
 
 // ----------- PreGameInstanceManager -----------
 
 pub(crate) struct PreGameInstanceManager {
-    pub game_type: GameType,
+    pub game_type: ProtoGameType,
     min_players: usize,
     max_players: usize,
+    /// Seeds the game instance's RNG once the game starts.
+    pub rng_seed: u64,
+    /// The host's encoded `Proto<Game>Options`, validated by the game each time the host picks
+    /// them, and decoded by the game once it starts.
+    pub game_options: Vec<u8>,
     players: streaming::PlayerPreGameStreams,
+    /// Presence as of the last time it was sent to players, to detect when it changes.
+    published_presence: Vec<ProtoPlayerPresence>,
     // TODO:2.5 implement some keep-alive ping for host.
     pub activity_tracker: ActivityTracker,
 }
 
 impl PreGameInstanceManager {
 
-    pub fn new(game_type: GameType) -> Self {
-        let (min, max) = player_count_min_max(&game_type);
-
+    pub fn new(game_type: ProtoGameType, (min, max): (usize, usize), rng_seed: u64, game_options: Vec<u8>) -> Self {
         PreGameInstanceManager {
             game_type,
             min_players: min,
             max_players: max,
+            rng_seed,
+            game_options,
             players: streaming::PlayerPreGameStreams::new(),
+            published_presence: Vec::new(),
             activity_tracker: ActivityTracker::new(),
         }
     }
 }
 
-fn player_count_min_max(game_type: &GameType) -> (usize, usize) {
-    match game_type {
-        GameType::LoveLetter => (2, 4),
-        GameType::LostCities => (2, 2),
-    }
-}
-
 // ----------- PlayerPreGameStreams -----------
 
 mod streaming {
     use backend_framework::streaming::StreamSender;
-    use backend_framework::wire_api::proto_frj_ngn::ProtoPreGameMessage;
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoPlayerPresence, ProtoPreGameMessage};
+    use std::time::SystemTime;
     use tonic::Status;
 
     pub(crate) struct PlayerPreGameStreams {
@@ -51,7 +56,20 @@ mod streaming {
 
     struct PlayerData {
         pub player_id: String,
-        pub pre_game_stream: StreamSender<ProtoPreGameMessage>,
+        /// `None` for bots, which don't have a client to send pre-game messages to.
+        pub pre_game_stream: Option<StreamSender<ProtoPreGameMessage>>,
+    }
+
+    impl PlayerData {
+        fn is_bot(&self) -> bool {
+            self.pre_game_stream.is_none()
+        }
+
+        fn disconnected_since(&self) -> Option<SystemTime> {
+            self.pre_game_stream
+                .as_ref()
+                .and_then(|stream| stream.disconnected_since())
+        }
     }
 
     impl PlayerPreGameStreams {
@@ -63,14 +81,45 @@ mod streaming {
             }
         }
 
+        /// A player who reconnects keeps their place in the join order.
         pub fn add_player(&mut self, player_id: String, pre_game_stream: StreamSender<ProtoPreGameMessage>) {
-            self.remove_player(&player_id);
-            self.inner.push(PlayerData { player_id, pre_game_stream });
+            match self.inner.iter_mut().find(|player| player.player_id == player_id) {
+                Some(player) => player.pre_game_stream = Some(pre_game_stream),
+                None => self.inner.push(PlayerData { player_id, pre_game_stream: Some(pre_game_stream) }),
+            }
         }
 
-        // O(n), could be O(1), but n will always be less than 10.
-        pub fn remove_player(&mut self, player_id: &String) {
-            self.inner.retain(|player| &player.player_id != player_id)
+        pub fn add_bot(&mut self, player_id: String) {
+            self.inner.push(PlayerData { player_id, pre_game_stream: None });
+        }
+
+        /// Returns the player's stream, so the caller can say goodbye. If the party leader is
+        /// removed, the next player in join order becomes party leader. Bots are skipped, since
+        /// they can't lead.
+        pub fn remove_player(&mut self, player_id: &String) -> Option<StreamSender<ProtoPreGameMessage>> {
+            let index = self.inner.iter().position(|player| &player.player_id == player_id)?;
+            let player = self.inner.remove(index);
+
+            if index < self.party_leader_index {
+                self.party_leader_index -= 1;
+            } else if self.party_leader_index >= self.inner.len() {
+                // The party leader was last in join order, so wrap around to the first player.
+                self.party_leader_index = 0;
+            }
+            self.skip_bot_party_leader();
+
+            player.pre_game_stream
+        }
+
+        /// No-op if the party leader is human, or if only bots are left.
+        fn skip_bot_party_leader(&mut self) {
+            let count = self.inner.len();
+            let next_human = (0..count)
+                .map(|offset| (self.party_leader_index + offset) % count)
+                .find(|&index| !self.inner[index].is_bot());
+            if let Some(index) = next_human {
+                self.party_leader_index = index;
+            }
         }
 
         pub fn contains_player(&self, player_id: &String) -> bool {
@@ -88,6 +137,13 @@ mod streaming {
             self.inner.len()
         }
 
+        pub fn human_count(&self) -> usize {
+            self.inner
+                .iter()
+                .filter(|player| !player.is_bot())
+                .count()
+        }
+
         pub fn player_ids(&self) -> Vec<String> {
             self.inner
                 .iter()
@@ -95,6 +151,22 @@ mod streaming {
                 .collect()
         }
 
+        /// Connection status of each player, in join order. Bots are always connected.
+        pub fn presence(&self) -> Vec<ProtoPlayerPresence> {
+            self.inner
+                .iter()
+                .map(|player| ProtoPlayerPresence::new(player.player_id.clone(), player.disconnected_since()))
+                .collect()
+        }
+
+        pub fn disconnected_player_ids(&self) -> Vec<String> {
+            self.inner
+                .iter()
+                .filter(|player| player.disconnected_since().is_some())
+                .map(|player| player.player_id.clone())
+                .collect()
+        }
+
         pub fn party_leader(&self) -> Option<&String> {
             match self.inner.get(self.party_leader_index) {
                 None => None,
@@ -118,6 +190,8 @@ mod streaming {
             self.out_stream(player_id, |out| out.send_error_message(status))
         }
 
+        /// A player whose stream is closed stays in the game, and shows up as disconnected in
+        /// `presence()` until they reconnect. Messages to bots are dropped.
         fn out_stream<F>(
             &mut self,
             player_id: &String,
@@ -126,8 +200,8 @@ mod streaming {
             F: FnOnce(&StreamSender<ProtoPreGameMessage>) -> Result<(), ()>
         {
             if let Some(player) = self.find_player(player_id) {
-                if let Err(_) = send_func(&player.pre_game_stream) {
-                    self.remove_player(&player_id);
+                if let Some(pre_game_stream) = &player.pre_game_stream {
+                    let _ = send_func(pre_game_stream);
                 }
             } else {
                 println!("ERROR: Cannot send message, Player '{}' not found.", player_id);
@@ -0,0 +1,73 @@
+mod pre_game_presence_tests {
+    use crate::game_manager::pre_game::PreGameInstanceManager;
+    use crate::game_manager::test_util::{join, recv};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoGameType, ProtoPlayerPresence};
+    use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+    use tokio::stream::StreamExt;
+    use tonic::Code;
+
+    fn connected(presence: &[ProtoPlayerPresence]) -> Vec<(&str, bool)> {
+        presence
+            .iter()
+            .map(|player| (player.player_id.as_str(), player.connected))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn disconnected_player_blocks_start_until_reconnect() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LostCities, (2, 2), 42, Vec::new());
+        let mut host_rcv = join(&mut pre_game, "p1");
+        let p2_rcv = join(&mut pre_game, "p2");
+        assert!(matches!(recv(&mut host_rcv).await, Inner::JoinGameAck(_)));
+        assert!(matches!(recv(&mut host_rcv).await, Inner::PlayerJoinMsg(_)));
+
+        drop(p2_rcv);
+        pre_game.publish_presence_changes();
+        match recv(&mut host_rcv).await {
+            Inner::PlayerPresenceMsg(msg) => {
+                assert_eq!(vec![("p1", true), ("p2", false)], connected(&msg.players));
+                assert_ne!(0, msg.players[1].disconnected_since);
+            },
+            other => panic!("Expected presence, got {:?}", other),
+        }
+
+        let status = pre_game.start_game_pre_check(&"p1".to_string()).expect_err("p2 is disconnected");
+        assert_eq!(Code::FailedPrecondition, status.code());
+        assert!(status.message().contains("p2"));
+
+        // Reconnecting keeps the join order, so the host doesn't change.
+        let mut p2_rcv = join(&mut pre_game, "p2");
+        match recv(&mut p2_rcv).await {
+            Inner::JoinGameAck(ack) => {
+                assert_eq!("p1", ack.host_player_id);
+                assert_eq!(vec![("p1", true), ("p2", true)], connected(&ack.presence));
+            },
+            other => panic!("Expected join ack, got {:?}", other),
+        }
+        match recv(&mut host_rcv).await {
+            Inner::PlayerPresenceMsg(msg) => assert_eq!(vec![("p1", true), ("p2", true)], connected(&msg.players)),
+            other => panic!("Expected presence, got {:?}", other),
+        }
+
+        let player_ids = pre_game.start_game_pre_check(&"p1".to_string()).expect("Everyone is connected");
+        assert_eq!(vec!["p1".to_string(), "p2".to_string()], player_ids);
+    }
+
+    #[tokio::test]
+    async fn presence_is_only_sent_when_it_changes() {
+        let mut pre_game = PreGameInstanceManager::new(ProtoGameType::LostCities, (2, 2), 42, Vec::new());
+        let host_rcv = join(&mut pre_game, "p1");
+        let _p2_rcv = join(&mut pre_game, "p2");
+
+        pre_game.publish_presence_changes();
+        drop(pre_game);
+
+        let messages: Vec<Inner> = host_rcv
+            .map(|message| message.unwrap().inner.unwrap())
+            .collect()
+            .await;
+        assert_eq!(2, messages.len());
+        assert!(matches!(messages[0], Inner::JoinGameAck(_)));
+        assert!(matches!(messages[1], Inner::PlayerJoinMsg(_)));
+    }
+}
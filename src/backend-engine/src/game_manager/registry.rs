@@ -0,0 +1,275 @@
+use backend_framework::channel::OverflowPolicy;
+use backend_framework::common_types::ClientInfo;
+use backend_framework::game_instance_manager::GameInstanceManager;
+use backend_framework::game_plugin::{BoxedBot, GamePlugin};
+use backend_framework::prng::PrngRand;
+use backend_framework::streaming::{self, StreamReceiver};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoGameType, ProtoGameDataOut};
+use prost::Message;
+use std::any::Any;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::stream::{Stream, StreamExt};
+use tonic::Status;
+
+/// A game event whose concrete type is only known by the game's plugin.
+pub type ErasedGameEvent = Box<dyn Any + Send>;
+
+/// Outgoing half of a player's data stream, with each game message encoded as an opaque payload.
+pub type GameDataStream = Pin<Box<dyn Stream<Item = Result<ProtoGameDataOut, Status>> + Send + Sync>>;
+
+/// The set of games that this server is able to host.
+pub struct GameRegistry {
+    plugins: HashMap<ProtoGameType, Arc<dyn ErasedGamePlugin>>,
+}
+
+impl GameRegistry {
+    pub fn new() -> Self {
+        GameRegistry {
+            plugins: HashMap::new(),
+        }
+    }
+
+    pub fn register<P: GamePlugin>(mut self, plugin: Arc<P>) -> Self {
+        let game_type = plugin.game_type();
+        if self.plugins.insert(game_type, plugin).is_some() {
+            panic!("Registered more than one plugin for game type {:?}", game_type);
+        }
+
+        self
+    }
+
+    pub fn get(&self, game_type: ProtoGameType) -> Option<&dyn ErasedGamePlugin> {
+        self.plugins
+            .get(&game_type)
+            .map(|plugin| plugin.as_ref())
+    }
+
+    pub fn display_name(&self, game_type: ProtoGameType) -> &'static str {
+        self.get(game_type)
+            .map(|plugin| plugin.display_name())
+            .unwrap_or("Unknown")
+    }
+}
+
+impl Default for GameRegistry {
+    fn default() -> Self {
+        GameRegistry::new()
+    }
+}
+
+/// Type erased version of `GamePlugin`, so plugins (and instances) of different games can be
+/// stored together. Implemented for all `GamePlugin`s.
+pub trait ErasedGamePlugin: Send + Sync {
+    fn display_name(&self) -> &'static str;
+    fn player_count_min_max(&self) -> (usize, usize);
+    fn validate_options(&self, game_options: &[u8]) -> Result<(), Status>;
+    fn create_instance(&self, player_ids: Vec<String>, game_options: &[u8], rng: PrngRand) -> Result<Box<dyn ErasedGameInstance>, Status>;
+    fn restore_instance(&self, snapshot: &[u8]) -> Result<Box<dyn ErasedGameInstance>, String>;
+    fn reject_event(&self, event: ErasedGameEvent, status: Status);
+
+    /// Decode the opaque payload of a data stream message into the game's event.
+    fn decode_event(&self, client_info: ClientInfo, payload: &[u8]) -> Result<ErasedGameEvent, Status>;
+
+    /// Create a player's outgoing data stream, and the game's event which registers it.
+    fn open_data_stream(&self, client_info: ClientInfo) -> (ErasedGameEvent, GameDataStream);
+
+    /// Create a spectator's outgoing data stream, and the game's event which registers it. Fails
+    /// if the game doesn't support spectators.
+    fn open_spectator_stream(&self, client_info: ClientInfo, god_view: bool) -> Result<(ErasedGameEvent, GameDataStream), Status>;
+
+    fn supports_bots(&self) -> bool;
+
+    /// Create a bot for a player of an existing game instance, and the game's event which
+    /// registers the bot's data stream. `None` if the game doesn't support bots.
+    fn create_bot(&self, client_info: ClientInfo, rng: PrngRand) -> Option<(ErasedGameEvent, Box<dyn ErasedBot>)>;
+}
+
+/// Type erased version of `Bot`, which also owns the bot's data stream.
+pub trait ErasedBot: Send {
+    fn player_id(&self) -> &str;
+
+    /// Let the bot observe everything the game sent it since the last call, and return its moves
+    /// as encoded `DataIn` payloads. Moves are decoded by the plugin the same way as a client's.
+    fn take_moves(&mut self) -> Vec<Vec<u8>>;
+}
+
+/// Type erased version of `GameInstanceManager`.
+pub trait ErasedGameInstance: Send {
+    fn handle_event(&mut self, event: ErasedGameEvent) -> bool;
+    fn player_ids(&self) -> &Vec<String>;
+    fn is_game_stale(&self, expiry_duration: Duration) -> bool;
+    fn handle_expired_deadlines(&mut self, now: SystemTime) -> bool;
+    fn is_game_complete(&self) -> bool;
+    fn snapshot(&self) -> Option<Vec<u8>>;
+}
+
+impl<P: GamePlugin> ErasedGamePlugin for P {
+    fn display_name(&self) -> &'static str {
+        GamePlugin::display_name(self)
+    }
+
+    fn player_count_min_max(&self) -> (usize, usize) {
+        GamePlugin::player_count_min_max(self)
+    }
+
+    fn validate_options(&self, game_options: &[u8]) -> Result<(), Status> {
+        GamePlugin::decode_options(self, game_options).map(|_| ())
+    }
+
+    fn create_instance(&self, player_ids: Vec<String>, game_options: &[u8], rng: PrngRand) -> Result<Box<dyn ErasedGameInstance>, Status> {
+        GamePlugin::create_instance(self, player_ids, game_options, rng)
+            .map(|inner| Box::new(PluginGameInstance::<P> { inner }) as Box<dyn ErasedGameInstance>)
+    }
+
+    fn restore_instance(&self, snapshot: &[u8]) -> Result<Box<dyn ErasedGameInstance>, String> {
+        GamePlugin::restore_instance(self, snapshot)
+            .map(|inner| Box::new(PluginGameInstance::<P> { inner }) as Box<dyn ErasedGameInstance>)
+    }
+
+    fn reject_event(&self, event: ErasedGameEvent, status: Status) {
+        match event.downcast::<P::Event>() {
+            Ok(event) => GamePlugin::reject_event(self, *event, status),
+            Err(_) => println!("ERROR: Rejected event is not a {} event. Dropping it.", GamePlugin::display_name(self)),
+        }
+    }
+
+    fn decode_event(&self, client_info: ClientInfo, payload: &[u8]) -> Result<ErasedGameEvent, Status> {
+        let message = P::DataIn::decode(payload)
+            .map_err(|e| Status::invalid_argument(format!("Payload is not a valid {} message: {}", GamePlugin::display_name(self), e)))?;
+
+        GamePlugin::decode_event(self, client_info, message)
+            .map(|event| Box::new(event) as ErasedGameEvent)
+    }
+
+    fn open_data_stream(&self, client_info: ClientInfo) -> (ErasedGameEvent, GameDataStream) {
+        // Games only send full state snapshots on data streams, so a slow client only needs
+        // the newest ones.
+        let (stream_out, stream_rcv) = streaming::stream_channel::<P::DataOut>(OverflowPolicy::DropOldest);
+        let event = GamePlugin::register_data_stream_event(self, client_info, stream_out);
+
+        (Box::new(event), Box::pin(stream_rcv.map(encode_envelope::<P::DataOut>)))
+    }
+
+    fn open_spectator_stream(&self, client_info: ClientInfo, god_view: bool) -> Result<(ErasedGameEvent, GameDataStream), Status> {
+        let (stream_out, stream_rcv) = streaming::stream_channel::<P::DataOut>(OverflowPolicy::DropOldest);
+        let event = GamePlugin::register_spectator_stream_event(self, client_info, god_view, stream_out)?;
+
+        Ok((Box::new(event), Box::pin(stream_rcv.map(encode_envelope::<P::DataOut>))))
+    }
+
+    fn supports_bots(&self) -> bool {
+        GamePlugin::supports_bots(self)
+    }
+
+    fn create_bot(&self, client_info: ClientInfo, rng: PrngRand) -> Option<(ErasedGameEvent, Box<dyn ErasedBot>)> {
+        if !GamePlugin::supports_bots(self) {
+            return None;
+        }
+        let bot = GamePlugin::create_bot(self, client_info.player_id.clone(), rng)?;
+
+        // Bots only act on the newest game state, same as clients.
+        let (stream_out, stream_rcv) = streaming::stream_channel::<P::DataOut>(OverflowPolicy::DropOldest);
+        let bot = PluginBot::<P> {
+            player_id: client_info.player_id.clone(),
+            bot,
+            stream_rcv,
+        };
+        let event = GamePlugin::register_data_stream_event(self, client_info, stream_out);
+
+        Some((Box::new(event), Box::new(bot)))
+    }
+}
+
+/// Game instances send their own message type, so this encodes each message into the envelope
+/// which is actually sent to the client.
+fn encode_envelope<M: prost::Message>(result: Result<M, Status>) -> Result<ProtoGameDataOut, Status> {
+    result.and_then(|message| {
+        let mut payload = Vec::with_capacity(message.encoded_len());
+        message.encode(&mut payload)
+            .map(|_| ProtoGameDataOut {
+                clock: 0,
+                payload,
+            })
+            .map_err(|e| Status::internal(format!("Failed to encode game message: {}", e)))
+    })
+}
+
+struct PluginBot<P: GamePlugin> {
+    player_id: String,
+    bot: BoxedBot<P>,
+    stream_rcv: StreamReceiver<P::DataOut>,
+}
+
+impl<P: GamePlugin> ErasedBot for PluginBot<P> {
+    fn player_id(&self) -> &str {
+        &self.player_id
+    }
+
+    fn take_moves(&mut self) -> Vec<Vec<u8>> {
+        let mut has_observed = false;
+        while let Some(result) = self.stream_rcv.try_next() {
+            match result {
+                Ok(message) => {
+                    self.bot.observe(message);
+                    has_observed = true;
+                },
+                Err(status) => println!("WARN: Bot '{}' received an error from its game: {:?}", self.player_id, status),
+            }
+        }
+
+        if !has_observed {
+            return Vec::new();
+        }
+
+        self.bot.next_moves()
+            .into_iter()
+            .map(|message| {
+                let mut payload = Vec::with_capacity(message.encoded_len());
+                message.encode(&mut payload).expect("Vec has enough capacity for the encoded message");
+                payload
+            })
+            .collect()
+    }
+}
+
+struct PluginGameInstance<P: GamePlugin> {
+    inner: P::Instance,
+}
+
+impl<P: GamePlugin> ErasedGameInstance for PluginGameInstance<P> {
+    fn handle_event(&mut self, event: ErasedGameEvent) -> bool {
+        match event.downcast::<P::Event>() {
+            Ok(event) => {
+                println!("DEBUG: Game instance received {:?}", event);
+                self.inner.handle_event(*event)
+            },
+            Err(_) => {
+                println!("ERROR: Event was routed to a game instance of the wrong type. Dropping it.");
+                false
+            },
+        }
+    }
+
+    fn player_ids(&self) -> &Vec<String> {
+        self.inner.player_ids()
+    }
+
+    fn is_game_stale(&self, expiry_duration: Duration) -> bool {
+        self.inner.is_game_stale(expiry_duration)
+    }
+
+    fn handle_expired_deadlines(&mut self, now: SystemTime) -> bool {
+        self.inner.handle_expired_deadlines(now)
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.inner.is_game_complete()
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        self.inner.snapshot()
+    }
+}
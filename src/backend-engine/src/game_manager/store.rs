@@ -0,0 +1,202 @@
+use crate::game_manager::types::GameIdentifier;
+use backend_framework::wire_api::proto_frj_ngn::ProtoGameType;
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+const FILE_EXTENSION: &str = "game";
+const TMP_FILE_EXTENSION: &str = "game.tmp";
+
+/// Snapshot of an in-progress game, as produced by `GameInstanceManager::snapshot()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredGame {
+    pub game: GameIdentifier,
+    pub snapshot: Vec<u8>,
+}
+
+/// Durable storage for in-progress games, so they survive a server restart. Pre-games aren't
+/// stored, since they only live for as long as players are in the lobby.
+pub trait GameStore: Send + Sync {
+    /// Insert or replace the game's snapshot.
+    fn save(&self, game: &StoredGame) -> io::Result<()>;
+
+    /// Idempotent-ly remove the game. Called when the game is garbage collected.
+    fn delete(&self, game: &GameIdentifier) -> io::Result<()>;
+
+    /// Read all stored games. Only called on startup.
+    fn load_all(&self) -> io::Result<Vec<StoredGame>>;
+}
+
+/// Stores each game's snapshot as a separate file in a local directory.
+pub struct FileGameStore {
+    dir: PathBuf,
+}
+
+impl FileGameStore {
+    /// Creates the directory if it doesn't exist.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(FileGameStore {
+            dir,
+        })
+    }
+
+    fn path(&self, game: &GameIdentifier, extension: &str) -> PathBuf {
+        // Game IDs are chosen by clients, so they're hex encoded to be safe to use as a file name.
+        self.dir.join(format!("{}-{}.{}", game.game_type as i32, hex_encode(&game.game_id), extension))
+    }
+
+    fn load(&self, path: &Path) -> io::Result<Option<StoredGame>> {
+        let file_name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(file_name) => file_name,
+            None => return Ok(None),
+        };
+
+        // Leftover tmp files are from a save that crashed part way, so they're ignored.
+        let stem = match file_name.strip_suffix(&format!(".{}", FILE_EXTENSION)) {
+            Some(stem) => stem,
+            None => return Ok(None),
+        };
+
+        match parse_file_stem(stem) {
+            Some(game) => Ok(Some(StoredGame {
+                game,
+                snapshot: fs::read(path)?,
+            })),
+            None => {
+                println!("WARN: Ignoring unrecognized file in game store: {:?}", path);
+                Ok(None)
+            },
+        }
+    }
+}
+
+impl GameStore for FileGameStore {
+    fn save(&self, game: &StoredGame) -> io::Result<()> {
+        // Write to a tmp file and rename it, so a crash never leaves a half written snapshot.
+        let tmp_path = self.path(&game.game, TMP_FILE_EXTENSION);
+        fs::write(&tmp_path, &game.snapshot)?;
+        fs::rename(&tmp_path, self.path(&game.game, FILE_EXTENSION))
+    }
+
+    fn delete(&self, game: &GameIdentifier) -> io::Result<()> {
+        match fs::remove_file(self.path(game, FILE_EXTENSION)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    fn load_all(&self) -> io::Result<Vec<StoredGame>> {
+        let mut games = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            if let Some(game) = self.load(&entry?.path())? {
+                games.push(game);
+            }
+        }
+
+        Ok(games)
+    }
+}
+
+enum StoreWrite {
+    Save(StoredGame),
+    Delete(GameIdentifier),
+}
+
+/// Wraps a store whose writes block, like `FileGameStore`, so repository tasks never wait on
+/// them. Writes are done one at a time in the order they're made, on a blocking thread, so a
+/// game's newest snapshot is always the one that's kept. Errors can't be returned to the caller,
+/// so they're logged instead.
+pub struct BackgroundGameStore {
+    inner: Arc<dyn GameStore>,
+    writes: mpsc::UnboundedSender<StoreWrite>,
+}
+
+impl BackgroundGameStore {
+    /// Must be called from within a tokio runtime, which the writer task is spawned on.
+    pub fn new(inner: Arc<dyn GameStore>) -> Self {
+        let (writes, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(write_in_order(inner.clone(), receiver));
+
+        BackgroundGameStore {
+            inner,
+            writes,
+        }
+    }
+
+    fn write(&self, write: StoreWrite) -> io::Result<()> {
+        self.writes.send(write)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "Game store writer task has stopped"))
+    }
+}
+
+impl GameStore for BackgroundGameStore {
+    fn save(&self, game: &StoredGame) -> io::Result<()> {
+        self.write(StoreWrite::Save(game.clone()))
+    }
+
+    fn delete(&self, game: &GameIdentifier) -> io::Result<()> {
+        self.write(StoreWrite::Delete(game.clone()))
+    }
+
+    fn load_all(&self) -> io::Result<Vec<StoredGame>> {
+        self.inner.load_all()
+    }
+}
+
+async fn write_in_order(store: Arc<dyn GameStore>, mut receiver: mpsc::UnboundedReceiver<StoreWrite>) {
+    while let Some(write) = receiver.recv().await {
+        let store = store.clone();
+        let result = tokio::task::spawn_blocking(move || match write {
+            StoreWrite::Save(game) => store.save(&game)
+                .map_err(|e| format!("Failed to save game {:?}: {}", game.game, e)),
+            StoreWrite::Delete(game) => store.delete(&game)
+                .map_err(|e| format!("Failed to delete saved game {:?}: {}", game, e)),
+        }).await;
+
+        match result {
+            Ok(Ok(())) => {},
+            Ok(Err(message)) => println!("ERROR: {}", message),
+            Err(e) => println!("ERROR: Game store write panicked: {}", e),
+        }
+    }
+}
+
+fn parse_file_stem(stem: &str) -> Option<GameIdentifier> {
+    let mut parts = stem.splitn(2, '-');
+    let game_type = parts.next()?.parse::<i32>().ok()?;
+    let game_type = ProtoGameType::try_from(game_type).ok()?;
+    let game_id = hex_decode(parts.next()?)?;
+
+    Some(GameIdentifier {
+        game_id,
+        game_type,
+    })
+}
+
+fn hex_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn hex_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes()
+        .chunks(2)
+        .map(|byte| match byte {
+            [hi, lo] => Some((hex_digit(*hi)? << 4) | hex_digit(*lo)?),
+            _ => None,
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    String::from_utf8(bytes).ok()
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|digit| digit as u8)
+}
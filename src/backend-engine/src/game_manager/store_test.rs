@@ -0,0 +1,138 @@
+mod file_game_store_tests {
+    use crate::game_manager::store::{BackgroundGameStore, FileGameStore, GameStore, StoredGame};
+    use crate::game_manager::types::GameIdentifier;
+    use backend_framework::wire_api::proto_frj_ngn::ProtoGameType;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Each test gets its own directory, since tests run in parallel.
+    fn store_dir(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("frj-game-store-test-{}-{}", std::process::id(), test_name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn stored_game(game_id: &str, game_type: ProtoGameType, snapshot: &[u8]) -> StoredGame {
+        StoredGame {
+            game: GameIdentifier {
+                game_id: game_id.to_string(),
+                game_type,
+            },
+            snapshot: snapshot.to_vec(),
+        }
+    }
+
+    fn load_sorted(store: &FileGameStore) -> Vec<StoredGame> {
+        let mut games = store.load_all().unwrap();
+        games.sort_by(|a, b| a.game.game_id.cmp(&b.game.game_id));
+        games
+    }
+
+    #[test]
+    fn saved_games_are_loaded() {
+        let dir = store_dir("saved_games_are_loaded");
+        let store = FileGameStore::new(&dir).unwrap();
+        let game_1 = stored_game("game 1", ProtoGameType::LoveLetter, b"snapshot 1");
+        // IDs are chosen by clients, so they can contain characters that aren't valid in a path.
+        let game_2 = stored_game("../game/2", ProtoGameType::LostCities, b"snapshot 2");
+
+        store.save(&game_1).unwrap();
+        store.save(&game_2).unwrap();
+
+        // New store instance, like after a server restart.
+        let store = FileGameStore::new(&dir).unwrap();
+        assert_eq!(vec![game_2, game_1], load_sorted(&store));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn save_replaces_previous_snapshot() {
+        let dir = store_dir("save_replaces_previous_snapshot");
+        let store = FileGameStore::new(&dir).unwrap();
+
+        store.save(&stored_game("game", ProtoGameType::Mastermind, b"old")).unwrap();
+        store.save(&stored_game("game", ProtoGameType::Mastermind, b"new")).unwrap();
+
+        assert_eq!(vec![stored_game("game", ProtoGameType::Mastermind, b"new")], load_sorted(&store));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn same_game_id_for_different_game_types_are_stored_separately() {
+        let dir = store_dir("same_game_id_for_different_game_types");
+        let store = FileGameStore::new(&dir).unwrap();
+        let love_letter = stored_game("game", ProtoGameType::LoveLetter, b"love letter");
+        let lost_cities = stored_game("game", ProtoGameType::LostCities, b"lost cities");
+
+        store.save(&love_letter).unwrap();
+        store.save(&lost_cities).unwrap();
+
+        let games = store.load_all().unwrap();
+        assert_eq!(2, games.len());
+        assert!(games.contains(&love_letter));
+        assert!(games.contains(&lost_cities));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn delete_is_idempotent() {
+        let dir = store_dir("delete_is_idempotent");
+        let store = FileGameStore::new(&dir).unwrap();
+        let game = stored_game("game", ProtoGameType::LoveLetter, b"snapshot");
+
+        store.save(&game).unwrap();
+        store.delete(&game.game).unwrap();
+        store.delete(&game.game).unwrap();
+
+        assert_eq!(Vec::<StoredGame>::new(), store.load_all().unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unrecognized_files_are_ignored() {
+        let dir = store_dir("unrecognized_files_are_ignored");
+        let store = FileGameStore::new(&dir).unwrap();
+        let game = stored_game("game", ProtoGameType::LoveLetter, b"snapshot");
+        store.save(&game).unwrap();
+
+        fs::write(dir.join("notes.txt"), "hello").unwrap();
+        fs::write(dir.join("not-hex.game"), "hello").unwrap();
+        fs::write(dir.join("1-67616d65.game.tmp"), "crashed mid save").unwrap();
+
+        assert_eq!(vec![game], store.load_all().unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn background_writes_are_done_in_order() {
+        let dir = store_dir("background_writes_are_done_in_order");
+        let file_store = Arc::new(FileGameStore::new(&dir).unwrap());
+        let store = BackgroundGameStore::new(file_store.clone());
+
+        store.save(&stored_game("game", ProtoGameType::Mastermind, b"old")).unwrap();
+        store.save(&stored_game("game", ProtoGameType::Mastermind, b"new")).unwrap();
+        store.save(&stored_game("other", ProtoGameType::Mastermind, b"other")).unwrap();
+        store.delete(&stored_game("other", ProtoGameType::Mastermind, b"").game).unwrap();
+        // Writes are done in order, so everything before it is done once this is saved.
+        let last = stored_game("last", ProtoGameType::Mastermind, b"last");
+        store.save(&last).unwrap();
+
+        for _ in 0..100 {
+            if file_store.load_all().unwrap().contains(&last) {
+                break;
+            }
+            tokio::time::delay_for(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(vec![stored_game("game", ProtoGameType::Mastermind, b"new"), last], load_sorted(&file_store));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+use crate::game_manager::pre_game::PreGameInstanceManager;
+use backend_framework::channel::OverflowPolicy;
+use backend_framework::streaming::{self, StreamReceiver};
+use backend_framework::wire_api::proto_frj_ngn::ProtoPreGameMessage;
+use backend_framework::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+use tokio::stream::StreamExt;
+
+pub(crate) fn join(pre_game: &mut PreGameInstanceManager, player_id: &str) -> StreamReceiver<ProtoPreGameMessage> {
+    let (stream_out, stream_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+    pre_game.add_player(player_id.to_string(), stream_out);
+    stream_rcv
+}
+
+pub(crate) async fn recv(stream_rcv: &mut StreamReceiver<ProtoPreGameMessage>) -> Inner {
+    stream_rcv.next().await
+        .expect("Stream should have a message")
+        .expect("Message should not be an error")
+        .inner
+        .expect("Message should be set")
+}
@@ -1,47 +1,77 @@
 use crate::task;
-use crate::grpc_server::love_letter_stream::LoveLetterStreamInitializer;
+use crate::grpc_server::game_stream::GameStreamInitializer;
 use crate::game_manager::api::GameRepositoryClient;
-use crate::game_manager::types::{GameType, GameIdentifier};
+use crate::game_manager::registry::{GameRegistry, GameDataStream};
+use crate::game_manager::store::GameStore;
+use crate::game_manager::types::GameIdentifier;
 use backend_framework::wire_api::proto_frj_ngn::proto_fridge_game_engine_server::ProtoFridgeGameEngine;
-use backend_framework::wire_api::proto_frj_ngn::{ProtoPreGameMessage, ProtoHostGameReq, ProtoJoinGameReq, ProtoGameType, ProtoStartGameReq, ProtoStartGameReply, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut};
-use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoPreGameMessage, ProtoHostGameReq, ProtoJoinGameReq, ProtoGameType, ProtoStartGameReq, ProtoStartGameReply, ProtoGameDataIn};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoLeaveGameReq, ProtoLeaveGameReply, ProtoKickPlayerReq, ProtoKickPlayerReply};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoAddBotReq, ProtoAddBotReply, ProtoUpdateGameOptionsReq, ProtoUpdateGameOptionsReply};
+use backend_framework::bot;
+use backend_framework::channel::OverflowPolicy;
+use backend_framework::streaming::{self, StreamReceiver};
 use std::convert::TryFrom;
 use std::error::Error;
-use tokio::sync::mpsc;
+use std::sync::Arc;
 use tokio::sync::oneshot;
 use tonic::{Request, Response, Status, Streaming, Code};
 
 /// Backend server is the entry point which will implement the gRPC server type.
 pub struct FrjServer {
+    registry: Arc<GameRegistry>,
     game_repo_client: Box<dyn GameRepositoryClient + Send + Sync>,
-    love_letter_stream_opener: LoveLetterStreamInitializer,
+    game_stream_opener: GameStreamInitializer,
 }
 
 impl FrjServer {
 
-    pub fn start() -> Result<Self, Box<dyn Error>> {
-        let game_repo_client = task::start_repository_instance();
-        let love_letter_stream_opener = LoveLetterStreamInitializer::new(game_repo_client.unsized_clone());
-
-        Ok(FrjServer::new(
+    /// Start the server's dependencies. The server hosts exactly the games in `registry`, spread
+    /// across `repo_slot_count` repository tasks. Games saved in `store` by a previous run are
+    /// restored before the server starts accepting requests.
+    pub fn start(
+        registry: GameRegistry,
+        repo_slot_count: usize,
+        store: Option<Arc<dyn GameStore>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let registry = Arc::new(registry);
+        let game_repo_client = task::start_repository_instance(registry.clone(), repo_slot_count, store)?;
+        let game_stream_opener = GameStreamInitializer::new(game_repo_client.unsized_clone(), registry.clone());
+
+        Ok(FrjServer {
+            registry,
             game_repo_client,
-            love_letter_stream_opener
-        ))
+            game_stream_opener,
+        })
     }
 
-    fn new(
-        game_repo_client: Box<dyn GameRepositoryClient + Send + Sync>,
-        love_letter_stream_opener: LoveLetterStreamInitializer,
-    ) -> Self {
-        FrjServer {
-            game_repo_client,
-            love_letter_stream_opener,
+    /// One repository task per CPU.
+    pub fn default_repo_slot_count() -> usize {
+        num_cpus::get()
+    }
+
+    fn game_identifier(&self, game_id: String, proto_game_type: i32) -> Result<GameIdentifier, Status> {
+        let game_type = ProtoGameType::try_from(proto_game_type)?;
+        if self.registry.get(game_type).is_none() {
+            return Err(Status::invalid_argument(format!("Unsupported game type {:?}", game_type)));
+        }
+
+        Ok(GameIdentifier {
+            game_id,
+            game_type,
+        })
+    }
+
+    /// Checked before the pre-game sees the options, so the host finds out right away.
+    fn validate_game_options(&self, game: &GameIdentifier, game_options: &[u8]) -> Result<(), Status> {
+        match self.registry.get(game.game_type) {
+            Some(plugin) => plugin.validate_options(game_options),
+            None => Err(Status::invalid_argument(format!("Unsupported game type {:?}", game.game_type))),
         }
     }
 }
 
-type PreGameStream = mpsc::UnboundedReceiver<Result<ProtoPreGameMessage, Status>>;
-pub type GameDataStream<T> = mpsc::UnboundedReceiver<Result<T, Status>>;
+type PreGameStream = StreamReceiver<ProtoPreGameMessage>;
 
 #[tonic::async_trait]
 impl ProtoFridgeGameEngine for FrjServer {
@@ -51,18 +81,17 @@ impl ProtoFridgeGameEngine for FrjServer {
     async fn host_game(&self, request: Request<ProtoHostGameReq>) -> Result<Response<Self::HostGameStream>, Status> {
         let req = request.into_inner();
 
-        let (tx, rx) = mpsc::unbounded_channel();
-        let client_out = StreamSender::new(tx);
+        // Pre-game messages build on each other, so a client which falls behind can't catch up.
+        let (client_out, rx) = streaming::stream_channel(OverflowPolicy::Disconnect);
 
-        let game_type = GameType::try_from(ProtoGameType::try_from(req.game_type)?)?;
-        let game = GameIdentifier {
-            game_id: req.game_id,
-            game_type
-        };
+        let game = self.game_identifier(req.game_id, req.game_type)?;
+        self.validate_game_options(&game, &req.game_options)?;
+        bot::check_not_bot_player_id(&req.player_id)?;
 
         // This currently relies on the assumption of serialized access, which I'm only like
         // 90% sure will always work as expected. Might have to properly synchronize this later.
-        self.game_repo_client.create_pregame(game.clone());
+        let rng_seed = Some(req.rng_seed).filter(|seed| *seed != 0);
+        self.game_repo_client.create_pregame(game.clone(), rng_seed, req.game_options);
         self.game_repo_client.register_pregame_stream(req.player_id, game, client_out);
 
         Ok(Response::new(rx))
@@ -73,14 +102,11 @@ impl ProtoFridgeGameEngine for FrjServer {
     async fn join_game(&self, request: Request<ProtoJoinGameReq>) -> Result<Response<Self::JoinGameStream>, Status> {
         let req = request.into_inner();
 
-        let (tx, rx) = mpsc::unbounded_channel();
-        let client_out = StreamSender::new(tx);
+        // Pre-game messages build on each other, so a client which falls behind can't catch up.
+        let (client_out, rx) = streaming::stream_channel(OverflowPolicy::Disconnect);
 
-        let game_type = GameType::try_from(ProtoGameType::try_from(req.game_type)?)?;
-        let game = GameIdentifier {
-            game_id: req.game_id,
-            game_type
-        };
+        let game = self.game_identifier(req.game_id, req.game_type)?;
+        bot::check_not_bot_player_id(&req.player_id)?;
 
         self.game_repo_client.register_pregame_stream(req.player_id, game, client_out);
 
@@ -92,11 +118,7 @@ impl ProtoFridgeGameEngine for FrjServer {
 
         let (tx, rx) = oneshot::channel::<Result<ProtoStartGameReply, Status>>();
 
-        let game_type = GameType::try_from(ProtoGameType::try_from(req.game_type)?)?;
-        let game = GameIdentifier {
-            game_id: req.game_id,
-            game_type
-        };
+        let game = self.game_identifier(req.game_id, req.game_type)?;
 
         self.game_repo_client.start_game(req.player_id, game, tx);
 
@@ -108,13 +130,82 @@ impl ProtoFridgeGameEngine for FrjServer {
             .map(|reply| Response::new(reply))
     }
 
-    type OpenLoveLetterDataStreamStream = GameDataStream<ProtoLoveLetterDataOut>;
+    async fn leave_game(&self, request: Request<ProtoLeaveGameReq>) -> Result<Response<ProtoLeaveGameReply>, Status> {
+        let req = request.into_inner();
+
+        let (tx, rx) = oneshot::channel::<Result<ProtoLeaveGameReply, Status>>();
 
-    async fn open_love_letter_data_stream(&self, request: Request<Streaming<ProtoLoveLetterDataIn>>) -> Result<Response<Self::OpenLoveLetterDataStreamStream>, Status> {
+        let game = self.game_identifier(req.game_id, req.game_type)?;
+
+        self.game_repo_client.leave_game(req.player_id, game, tx);
+
+        rx.await
+            .map_err(|e| {
+                println!("ERROR: Failed to leave game. Oneshot sender dropped before sending the reply; Debug: {:?}, Display: {}", e, e);
+                Status::new(Code::Internal, "Failed to leave the game")
+            })?
+            .map(Response::new)
+    }
+
+    async fn kick_player(&self, request: Request<ProtoKickPlayerReq>) -> Result<Response<ProtoKickPlayerReply>, Status> {
+        let req = request.into_inner();
+
+        let (tx, rx) = oneshot::channel::<Result<ProtoKickPlayerReply, Status>>();
+
+        let game = self.game_identifier(req.game_id, req.game_type)?;
+
+        self.game_repo_client.kick_player(req.player_id, game, req.kicked_player_id, tx);
+
+        rx.await
+            .map_err(|e| {
+                println!("ERROR: Failed to kick player. Oneshot sender dropped before sending the reply; Debug: {:?}, Display: {}", e, e);
+                Status::new(Code::Internal, "Failed to kick the player")
+            })?
+            .map(Response::new)
+    }
+
+    async fn add_bot(&self, request: Request<ProtoAddBotReq>) -> Result<Response<ProtoAddBotReply>, Status> {
+        let req = request.into_inner();
+
+        let (tx, rx) = oneshot::channel::<Result<ProtoAddBotReply, Status>>();
+
+        let game = self.game_identifier(req.game_id, req.game_type)?;
+
+        self.game_repo_client.add_bot(req.player_id, game, tx);
+
+        rx.await
+            .map_err(|e| {
+                println!("ERROR: Failed to add bot. Oneshot sender dropped before sending the reply; Debug: {:?}, Display: {}", e, e);
+                Status::new(Code::Internal, "Failed to add the bot")
+            })?
+            .map(Response::new)
+    }
+
+    async fn update_game_options(&self, request: Request<ProtoUpdateGameOptionsReq>) -> Result<Response<ProtoUpdateGameOptionsReply>, Status> {
+        let req = request.into_inner();
+
+        let (tx, rx) = oneshot::channel::<Result<ProtoUpdateGameOptionsReply, Status>>();
+
+        let game = self.game_identifier(req.game_id, req.game_type)?;
+        self.validate_game_options(&game, &req.game_options)?;
+
+        self.game_repo_client.update_game_options(req.player_id, game, req.game_options, tx);
+
+        rx.await
+            .map_err(|e| {
+                println!("ERROR: Failed to update game options. Oneshot sender dropped before sending the reply; Debug: {:?}, Display: {}", e, e);
+                Status::new(Code::Internal, "Failed to update the game options")
+            })?
+            .map(Response::new)
+    }
+
+    type OpenGameDataStreamStream = GameDataStream;
+
+    async fn open_game_data_stream(&self, request: Request<Streaming<ProtoGameDataIn>>) -> Result<Response<Self::OpenGameDataStreamStream>, Status> {
         let stream_in = request.into_inner();
-        self.love_letter_stream_opener
+        self.game_stream_opener
             .handle_new_stream(stream_in)
             .await
-            .map(|stream_out| Response::new(stream_out))
+            .map(Response::new)
     }
-}
\ No newline at end of file
+}
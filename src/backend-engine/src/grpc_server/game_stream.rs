@@ -0,0 +1,163 @@
+use crate::game_manager::api::GameRepositoryClient;
+use crate::game_manager::registry::{GameRegistry, ErasedGamePlugin, GameDataStream};
+use crate::game_manager::types::GameIdentifier;
+use crate::grpc_server::stream_reader::StreamDriver;
+use crate::grpc_server::stream_reader::StreamMessageHandler;
+use backend_framework::bot;
+use backend_framework::common_types::ClientInfo;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoGameDataIn, ProtoGameDataHandshake, ProtoGameType};
+use backend_framework::wire_api::proto_frj_ngn::proto_game_data_in::Inner;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use tonic::{Streaming, Status, Code};
+
+/// This struct is responsible for handling newly opened data streams to the backend. The game
+/// that a stream belongs to is decided by its handshake, so this works for any registered game.
+pub struct GameStreamInitializer {
+    game_repo_client: Box<dyn GameRepositoryClient + Send + Sync>,
+    registry: Arc<GameRegistry>,
+}
+
+impl GameStreamInitializer {
+
+    pub fn new(game_repo_client: Box<dyn GameRepositoryClient + Send + Sync>, registry: Arc<GameRegistry>) -> Self {
+        GameStreamInitializer {
+            game_repo_client,
+            registry,
+        }
+    }
+
+    /// This method is called when the server receives a request to open a data stream. The
+    /// handshake decides which game the stream is for, so it's read before replying.
+    pub async fn handle_new_stream(&self, mut stream_in: Streaming<ProtoGameDataIn>) -> Result<GameDataStream, Status> {
+        // 1. Poll receiver for handshake
+        let handshake = wait_for_handshake_message(&mut stream_in).await?;
+        let game_type = registered_game_type(&self.registry, handshake.game_type)?;
+        let spectator = handshake.spectator;
+        let god_view = handshake.god_view;
+        if god_view && !spectator {
+            return Err(Status::invalid_argument("Only spectators can have a god view."));
+        }
+        if !spectator {
+            bot::check_not_bot_player_id(&handshake.player_id)?;
+        }
+        let client_info = ClientInfo::from(handshake);
+        let game = GameIdentifier {
+            game_id: client_info.game_id.clone(),
+            game_type,
+        };
+
+        // 2. Register sender to backend
+        let (event, stream_out) = if spectator {
+            plugin(&self.registry, game_type).open_spectator_stream(client_info.clone(), god_view)?
+        } else {
+            plugin(&self.registry, game_type).open_data_stream(client_info.clone())
+        };
+        self.game_repo_client.handle_game_event(game.clone(), event);
+
+        // 3. Spawn task to poll receiver
+        spawn_stream_driver_task(self.game_repo_client.unsized_clone(), self.registry.clone(), stream_in, client_info, game, spectator);
+
+        Ok(stream_out)
+    }
+}
+
+async fn wait_for_handshake_message(stream_in_recv: &mut Streaming<ProtoGameDataIn>) -> Result<ProtoGameDataHandshake, Status> {
+    match stream_in_recv.message().await {
+        Err(status) => {
+            println!("WARN: GameStreamInitializer Received Status err when expected Handshake. Err: {:?}", status);
+            Err(Status::new(Code::FailedPrecondition, "Failed to read message from stream upon opening."))
+        },
+        Ok(None) => {
+            println!("INFO: GameStreamInitializer Stream closed as soon as it was opened. wtf!");
+            Err(Status::new(Code::FailedPrecondition, "Read empty message from stream upon opening."))
+        },
+        Ok(Some(message)) => {
+            println!("DEBUG: GameStreamInitializer Received initial stream message: {:?}", message);
+            match message.inner {
+                Some(Inner::Handshake(handshake)) => Ok(handshake),
+                None => Err(Status::failed_precondition("Expected data stream message to have data.")),
+                Some(Inner::Payload(_)) => {
+                    println!("INFO: GameStreamInitializer Stream initial message is not a Handshake.");
+                    Err(Status::failed_precondition("Expected first stream message to be Handshake message."))
+                },
+            }
+        },
+    }
+}
+
+fn registered_game_type(registry: &GameRegistry, proto_game_type: i32) -> Result<ProtoGameType, Status> {
+    let game_type = ProtoGameType::try_from(proto_game_type)?;
+    match registry.get(game_type) {
+        Some(_) => Ok(game_type),
+        None => Err(Status::invalid_argument(format!("Unsupported game type {:?}", game_type))),
+    }
+}
+
+/// Only call with a game type that was checked by `registered_game_type()`.
+fn plugin(registry: &GameRegistry, game_type: ProtoGameType) -> &dyn ErasedGamePlugin {
+    registry.get(game_type).expect("Game type was validated when the stream was opened")
+}
+
+fn spawn_stream_driver_task(
+    game_repo_client: Box<dyn GameRepositoryClient + Send + Sync>,
+    registry: Arc<GameRegistry>,
+    stream_in: Streaming<ProtoGameDataIn>,
+    client: ClientInfo,
+    game: GameIdentifier,
+    spectator: bool,
+) {
+    let stream_id = format!("{}:{}", client.game_id, client.player_id);
+    let handler = GameStreamMessageHandler {
+        game_repo_client,
+        registry,
+        client,
+        game,
+        spectator,
+    };
+
+    let stream_driver = StreamDriver::new(stream_id, stream_in, handler);
+    tokio::spawn(stream_driver.run());
+}
+
+/// This struct is responsible for handling individual messages from the client stream.
+struct GameStreamMessageHandler {
+    game_repo_client: Box<dyn GameRepositoryClient + Send + Sync>,
+    registry: Arc<GameRegistry>,
+    client: ClientInfo,
+    game: GameIdentifier,
+    /// Spectators only receive messages, anything they send is dropped.
+    spectator: bool,
+}
+
+impl GameStreamMessageHandler {
+
+    fn notify_client_invalid_message(&self, status: Status) {
+        // Close stream? Drop message? Idk.
+        // TODO:3 notify client that `messageId` was invalid
+        println!("Client sent invalid message to data stream. Dropping message. Err {:?}", status);
+    }
+}
+
+impl StreamMessageHandler<ProtoGameDataIn> for GameStreamMessageHandler {
+
+    fn handle_message(&self, message: ProtoGameDataIn) {
+        let payload = match message.inner {
+            Some(Inner::Payload(payload)) => payload,
+            Some(Inner::Handshake(_)) => {
+                println!("INFO: Client stream sent Handshake message after handshake is done.");
+                return self.notify_client_invalid_message(Status::failed_precondition("Client sent handshake twice."));
+            },
+            None => return self.notify_client_invalid_message(Status::invalid_argument("Missing inner field.")),
+        };
+
+        if self.spectator {
+            return self.notify_client_invalid_message(Status::permission_denied("Spectators can't send game events."));
+        }
+
+        match plugin(&self.registry, self.game.game_type).decode_event(self.client.clone(), &payload) {
+            Err(status) => self.notify_client_invalid_message(status),
+            Ok(event) => self.game_repo_client.handle_game_event(self.game.clone(), event),
+        }
+    }
+}
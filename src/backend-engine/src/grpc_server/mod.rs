@@ -1,3 +1,3 @@
 pub mod frj_server;
-mod love_letter_stream;
+mod game_stream;
 mod stream_reader;
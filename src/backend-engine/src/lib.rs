@@ -1,5 +1,7 @@
 pub mod grpc_server;
 
+pub use crate::game_manager::registry::GameRegistry;
+pub use crate::game_manager::store::{FileGameStore, GameStore, StoredGame};
+
 pub(crate) mod game_manager;
-mod lost_cities_placeholder;
 mod task;
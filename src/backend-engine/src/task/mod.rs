@@ -1,27 +1,78 @@
 use crate::game_manager::api::{GameRepositoryClient, GameRepository};
 use crate::game_manager::default_impl::DefaultGameRepository;
+use crate::game_manager::registry::{ErasedGameEvent, GameRegistry};
+use crate::game_manager::store::{BackgroundGameStore, GameStore, StoredGame};
 use crate::game_manager::types::GameIdentifier;
-use crate::lost_cities_placeholder::LostCitiesEvent;
+use crate::task::slot_router::{SlotRing, SlotRoutingClient};
+use backend_framework::channel::{bounded_channel, BoundedReceiver, BoundedSender, OverflowPolicy, SendError};
 use backend_framework::streaming::StreamSender;
 use backend_framework::wire_api::proto_frj_ngn::ProtoPreGameMessage;
-use backend_framework::wire_api::proto_frj_ngn::ProtoStartGameReply;
-use love_letter_backend::events::LoveLetterEvent;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoAddBotReply, ProtoKickPlayerReply, ProtoLeaveGameReply, ProtoStartGameReply, ProtoUpdateGameOptionsReply};
 use tonic::Status;
-use tokio::sync::mpsc;
+use tokio::stream::StreamExt;
 use tokio::sync::oneshot;
+use std::io;
+use std::sync::Arc;
 use std::time::Duration;
 use rand::Rng;
 
-// TODO:1 consistent hashing onto parallel backend slots
-pub fn start_repository_instance() -> Box<dyn GameRepositoryClient + Send + Sync> {
-    let (tx, rx) = mpsc::unbounded_channel();
-    let task = GameRepoTask::new(rx);
+mod slot_router;
 
-    tokio::spawn(task.event_loop());
+/// Max events queued for a single repo slot. Beyond this, requests are rejected until the slot
+/// catches up, rather than letting memory grow without limit.
+const SLOT_QUEUE_CAPACITY: usize = 4096;
 
-    let task_client = GameRepoTaskClientAdapter::new(tx);
-    tokio::task::spawn(garbage_collection_heartbeat(task_client.clone()));
-    Box::new(task_client)
+/// How often each repo slot checks its games for expired turn deadlines, its pre-games for
+/// disconnected players, and its bots for moves to play.
+const PERIODIC_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[cfg(test)]
+mod slot_router_test;
+
+/// Start `num_slots` repository tasks, which each own a disjoint set of games. Games are spread
+/// across slots with consistent hashing, so games in different slots are processed in parallel.
+///
+/// If there's a `store`, in-progress games are saved to it in the background, and games that were
+/// saved before the server restarted are restored into the slot that owns them.
+pub fn start_repository_instance(
+    registry: Arc<GameRegistry>,
+    num_slots: usize,
+    store: Option<Arc<dyn GameStore>>,
+) -> io::Result<Box<dyn GameRepositoryClient + Send + Sync>> {
+    let ring = SlotRing::new(num_slots);
+    let stored_games_per_slot = load_stored_games(&ring, num_slots, store.as_deref())?;
+    let store = store.map(|store| Arc::new(BackgroundGameStore::new(store)) as Arc<dyn GameStore>);
+
+    let mut slots = Vec::with_capacity(num_slots);
+    for (slot, stored_games) in stored_games_per_slot.into_iter().enumerate() {
+        let (tx, rx) = bounded_channel(SLOT_QUEUE_CAPACITY, OverflowPolicy::Reject);
+        let mut task = GameRepoTask::new(slot, rx, registry.clone(), store.clone());
+        task.game_repo.restore_games(stored_games);
+
+        tokio::spawn(task.event_loop());
+
+        let task_client = GameRepoTaskClientAdapter::new(slot, tx, registry.clone());
+        tokio::task::spawn(garbage_collection_heartbeat(task_client.clone()));
+        slots.push(task_client);
+    }
+
+    Ok(Box::new(SlotRoutingClient::new(ring, slots)))
+}
+
+fn load_stored_games(ring: &SlotRing, num_slots: usize, store: Option<&dyn GameStore>) -> io::Result<Vec<Vec<StoredGame>>> {
+    let mut stored_games_per_slot: Vec<Vec<StoredGame>> = (0..num_slots).map(|_| Vec::new()).collect();
+
+    let stored_games = match store {
+        Some(store) => store.load_all()?,
+        None => return Ok(stored_games_per_slot),
+    };
+
+    println!("INFO: Loaded {} saved games from game store.", stored_games.len());
+    for stored_game in stored_games {
+        stored_games_per_slot[ring.slot_for(&stored_game.game)].push(stored_game);
+    }
+
+    Ok(stored_games_per_slot)
 }
 
 /// A 1:1 enumeration of GameRepository API methods.
@@ -30,7 +81,11 @@ enum GameRepoTaskEvent {
     // Non-game APIs
     CleanupStaleGames,
     // Pre-game APIs
-    CreatePregame(GameIdentifier),
+    CreatePregame {
+        game: GameIdentifier,
+        rng_seed: Option<u64>,
+        game_options: Vec<u8>,
+    },
     RegisterPregameStream {
         player_id: String,
         game: GameIdentifier,
@@ -41,38 +96,109 @@ enum GameRepoTaskEvent {
         game: GameIdentifier,
         response_sender: oneshot::Sender<Result<ProtoStartGameReply, Status>>,
     },
-    // Data-stream common APIs
-    NotifyGameState {
+    LeaveGame {
         player_id: String,
         game: GameIdentifier,
+        response_sender: oneshot::Sender<Result<ProtoLeaveGameReply, Status>>,
+    },
+    KickPlayer {
+        player_id: String,
+        game: GameIdentifier,
+        kicked_player_id: String,
+        response_sender: oneshot::Sender<Result<ProtoKickPlayerReply, Status>>,
+    },
+    AddBot {
+        player_id: String,
+        game: GameIdentifier,
+        response_sender: oneshot::Sender<Result<ProtoAddBotReply, Status>>,
+    },
+    UpdateGameOptions {
+        player_id: String,
+        game: GameIdentifier,
+        game_options: Vec<u8>,
+        response_sender: oneshot::Sender<Result<ProtoUpdateGameOptionsReply, Status>>,
     },
     // Data-stream game-specific APIs
-    LoveLetter(LoveLetterEvent),
-    LostCities(LostCitiesEvent),
+    GameEvent {
+        game: GameIdentifier,
+        event: ErasedGameEvent,
+    },
+}
+
+impl GameRepoTaskEvent {
+    /// Tell the caller that their request won't be handled, if the event gives us a way to.
+    fn reject(self, registry: &GameRegistry, status: Status) {
+        match self {
+            GameRepoTaskEvent::RegisterPregameStream { stream_out, .. } => {
+                let _ = stream_out.send_error_message(status);
+            },
+            GameRepoTaskEvent::StartGame { response_sender, .. } => {
+                let _ = response_sender.send(Err(status));
+            },
+            GameRepoTaskEvent::LeaveGame { response_sender, .. } => {
+                let _ = response_sender.send(Err(status));
+            },
+            GameRepoTaskEvent::KickPlayer { response_sender, .. } => {
+                let _ = response_sender.send(Err(status));
+            },
+            GameRepoTaskEvent::AddBot { response_sender, .. } => {
+                let _ = response_sender.send(Err(status));
+            },
+            GameRepoTaskEvent::UpdateGameOptions { response_sender, .. } => {
+                let _ = response_sender.send(Err(status));
+            },
+            GameRepoTaskEvent::GameEvent { game, event } => {
+                if let Some(plugin) = registry.get(game.game_type) {
+                    plugin.reject_event(event, status);
+                }
+            },
+            GameRepoTaskEvent::CleanupStaleGames
+            | GameRepoTaskEvent::CreatePregame { .. } => {},
+        }
+    }
 }
 
 /// This is a mpsc Sender (immutable) for accessing a GameRepository (mutable).
 #[derive(Clone)]
 struct GameRepoTaskClientAdapter {
-    sender: mpsc::UnboundedSender<GameRepoTaskEvent>
+    slot: usize,
+    sender: BoundedSender<GameRepoTaskEvent>,
+    registry: Arc<GameRegistry>,
 }
 
 impl GameRepoTaskClientAdapter {
-    fn new(sender: mpsc::UnboundedSender<GameRepoTaskEvent>) -> Self {
+    fn new(slot: usize, sender: BoundedSender<GameRepoTaskEvent>, registry: Arc<GameRegistry>) -> Self {
         GameRepoTaskClientAdapter {
+            slot,
             sender,
+            registry,
         }
     }
 
     fn send(&self, event: GameRepoTaskEvent) {
-        self.sender
-            .send(event)
-            .expect("GameRepo task stopped - this should never happen");
+        let (event, status) = match self.sender.send(event) {
+            Ok(()) => return,
+            Err(SendError::Full(event)) => {
+                println!("WARN: Repo slot {} queue is full, rejecting event {:?}", self.slot, event);
+                (event, Status::resource_exhausted("Server is overloaded, try again later."))
+            },
+            Err(e) => {
+                let event = e.into_inner();
+                println!("ERROR: Repo slot {} task stopped, rejecting event {:?}", self.slot, event);
+                (event, Status::unavailable("Server is unable to handle requests."))
+            },
+        };
+
+        event.reject(&self.registry, status);
     }
 
     pub fn cleanup_stale_games(&self) {
         self.send(GameRepoTaskEvent::CleanupStaleGames)
     }
+
+    pub fn log_queue_metrics(&self) {
+        println!("METRIC: Repo slot {} queue {:?}", self.slot, self.sender.metrics());
+    }
 }
 
 impl GameRepositoryClient for GameRepoTaskClientAdapter {
@@ -81,8 +207,12 @@ impl GameRepositoryClient for GameRepoTaskClientAdapter {
         Box::new(self.clone())
     }
 
-    fn create_pregame(&self, game: GameIdentifier) {
-        self.send(GameRepoTaskEvent::CreatePregame(game))
+    fn create_pregame(&self, game: GameIdentifier, rng_seed: Option<u64>, game_options: Vec<u8>) {
+        self.send(GameRepoTaskEvent::CreatePregame {
+            game,
+            rng_seed,
+            game_options,
+        })
     }
 
     fn register_pregame_stream(&self, player_id: String, game: GameIdentifier, stream_out: StreamSender<ProtoPreGameMessage>) {
@@ -101,44 +231,88 @@ impl GameRepositoryClient for GameRepoTaskClientAdapter {
         })
     }
 
-    fn notify_game_state(&self, player_id: String, game: GameIdentifier) {
-        self.send(GameRepoTaskEvent::NotifyGameState {
+    fn leave_game(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoLeaveGameReply, Status>>) {
+        self.send(GameRepoTaskEvent::LeaveGame {
             player_id,
-            game
+            game,
+            response_sender
         })
     }
 
-    fn handle_event_love_letter(&self, event: LoveLetterEvent) {
-        self.send(GameRepoTaskEvent::LoveLetter(event))
+    fn kick_player(&self, player_id: String, game: GameIdentifier, kicked_player_id: String, response_sender: oneshot::Sender<Result<ProtoKickPlayerReply, Status>>) {
+        self.send(GameRepoTaskEvent::KickPlayer {
+            player_id,
+            game,
+            kicked_player_id,
+            response_sender
+        })
     }
 
-    fn handle_event_lost_cities(&self, event: LostCitiesEvent) {
-        self.send(GameRepoTaskEvent::LostCities(event))
+    fn add_bot(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoAddBotReply, Status>>) {
+        self.send(GameRepoTaskEvent::AddBot {
+            player_id,
+            game,
+            response_sender
+        })
+    }
+
+    fn update_game_options(&self, player_id: String, game: GameIdentifier, game_options: Vec<u8>, response_sender: oneshot::Sender<Result<ProtoUpdateGameOptionsReply, Status>>) {
+        self.send(GameRepoTaskEvent::UpdateGameOptions {
+            player_id,
+            game,
+            game_options,
+            response_sender
+        })
+    }
+
+    fn handle_game_event(&self, game: GameIdentifier, event: ErasedGameEvent) {
+        self.send(GameRepoTaskEvent::GameEvent {
+            game,
+            event
+        })
     }
 }
 
 /// This is a mpsc Receiver wrapped around an instance of a GameRepository.
 struct GameRepoTask<T: GameRepository> {
-    receiver: mpsc::UnboundedReceiver<GameRepoTaskEvent>,
+    slot: usize,
+    receiver: BoundedReceiver<GameRepoTaskEvent>,
     game_repo: T,
 }
 
 impl GameRepoTask<DefaultGameRepository> {
-    pub fn new(receiver: mpsc::UnboundedReceiver<GameRepoTaskEvent>) -> Self {
+    pub fn new(
+        slot: usize,
+        receiver: BoundedReceiver<GameRepoTaskEvent>,
+        registry: Arc<GameRegistry>,
+        store: Option<Arc<dyn GameStore>>,
+    ) -> Self {
         GameRepoTask {
+            slot,
             receiver,
-            game_repo: DefaultGameRepository::new(),
+            game_repo: DefaultGameRepository::new(registry, store),
         }
     }
 
     pub async fn event_loop(mut self) {
-        println!("INFO: Starting event loop.");
+        println!("INFO: Starting event loop for repo slot {}.", self.slot);
 
-        while let Some(event) = self.receiver.recv().await {
-            self.route_event(event);
+        let mut periodic_check_timer = tokio::time::interval(PERIODIC_CHECK_INTERVAL);
+        loop {
+            tokio::select! {
+                event = self.receiver.next() => match event {
+                    Some(event) => self.route_event(event),
+                    None => break,
+                },
+                _ = periodic_check_timer.tick() => {
+                    self.game_repo.handle_expired_deadlines();
+                    self.game_repo.publish_pregame_presence();
+                    self.game_repo.play_bot_moves();
+                },
+            }
         }
 
-        println!("INFO: Exiting event loop.");
+        println!("INFO: Exiting event loop for repo slot {}.", self.slot);
     }
 
     fn route_event(&mut self, event: GameRepoTaskEvent) {
@@ -146,8 +320,8 @@ impl GameRepoTask<DefaultGameRepository> {
             GameRepoTaskEvent::CleanupStaleGames => {
                 self.game_repo.cleanup_stale_games()
             },
-            GameRepoTaskEvent::CreatePregame(game) => {
-                self.game_repo.create_pregame(game)
+            GameRepoTaskEvent::CreatePregame { game, rng_seed, game_options } => {
+                self.game_repo.create_pregame(game, rng_seed, game_options)
             },
             GameRepoTaskEvent::RegisterPregameStream { player_id, game, stream_out } => {
                 self.game_repo.register_pregame_stream(player_id, game, stream_out)
@@ -155,14 +329,20 @@ impl GameRepoTask<DefaultGameRepository> {
             GameRepoTaskEvent::StartGame { player_id, game, response_sender } => {
                 self.game_repo.start_game(player_id, game, response_sender)
             },
-            GameRepoTaskEvent::NotifyGameState { player_id, game } => {
-                self.game_repo.notify_game_state(player_id, game)
+            GameRepoTaskEvent::LeaveGame { player_id, game, response_sender } => {
+                self.game_repo.leave_game(player_id, game, response_sender)
+            },
+            GameRepoTaskEvent::KickPlayer { player_id, game, kicked_player_id, response_sender } => {
+                self.game_repo.kick_player(player_id, game, kicked_player_id, response_sender)
+            },
+            GameRepoTaskEvent::AddBot { player_id, game, response_sender } => {
+                self.game_repo.add_bot(player_id, game, response_sender)
             },
-            GameRepoTaskEvent::LoveLetter(inner) => {
-                self.game_repo.handle_event_love_letter(inner)
+            GameRepoTaskEvent::UpdateGameOptions { player_id, game, game_options, response_sender } => {
+                self.game_repo.update_game_options(player_id, game, game_options, response_sender)
             },
-            GameRepoTaskEvent::LostCities(inner) => {
-                self.game_repo.handle_event_lost_cities(inner)
+            GameRepoTaskEvent::GameEvent { game, event } => {
+                self.game_repo.handle_game_event(game, event)
             },
         }
     }
@@ -180,6 +360,7 @@ async fn garbage_collection_heartbeat(task_client: GameRepoTaskClientAdapter) {
             interval_time_max_seconds
         );
         tokio::time::delay_for(Duration::from_secs(jittered_interval_time_sec)).await;
+        task_client.log_queue_metrics();
         task_client.cleanup_stale_games();
     }
 }
@@ -0,0 +1,115 @@
+use crate::game_manager::api::GameRepositoryClient;
+use crate::game_manager::registry::ErasedGameEvent;
+use crate::game_manager::types::GameIdentifier;
+use crate::task::GameRepoTaskClientAdapter;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoAddBotReply, ProtoKickPlayerReply, ProtoLeaveGameReply, ProtoPreGameMessage, ProtoStartGameReply, ProtoUpdateGameOptionsReply};
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use tokio::sync::oneshot;
+use tonic::Status;
+
+/// Each slot is placed on the ring this many times, which evens out how many games land on
+/// each slot.
+const VIRTUAL_NODES_PER_SLOT: usize = 64;
+
+/// Consistent hash ring which maps a game onto one of N repository slots. Every API call for
+/// the same game must go to the same slot, since the slot owns the game's state.
+pub(crate) struct SlotRing {
+    ring: BTreeMap<u64, usize>,
+}
+
+impl SlotRing {
+    pub fn new(num_slots: usize) -> Self {
+        assert!(num_slots > 0, "Need at least 1 repository slot");
+
+        let mut ring = BTreeMap::new();
+        for slot in 0..num_slots {
+            for virtual_node in 0..VIRTUAL_NODES_PER_SLOT {
+                ring.insert(hash(&(slot, virtual_node)), slot);
+            }
+        }
+
+        SlotRing {
+            ring,
+        }
+    }
+
+    /// The owning slot is the first node clockwise from the game's hash.
+    pub fn slot_for(&self, game: &GameIdentifier) -> usize {
+        let game_hash = hash(game);
+        self.ring
+            .range(game_hash..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, slot)| *slot)
+            .expect("Ring is never empty")
+    }
+}
+
+fn hash<T: Hash>(obj: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    obj.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Routes each API call to the repository slot which owns the game.
+#[derive(Clone)]
+pub(crate) struct SlotRoutingClient {
+    ring: Arc<SlotRing>,
+    slots: Arc<Vec<GameRepoTaskClientAdapter>>,
+}
+
+impl SlotRoutingClient {
+    pub fn new(ring: SlotRing, slots: Vec<GameRepoTaskClientAdapter>) -> Self {
+        SlotRoutingClient {
+            ring: Arc::new(ring),
+            slots: Arc::new(slots),
+        }
+    }
+
+    fn slot(&self, game: &GameIdentifier) -> &GameRepoTaskClientAdapter {
+        &self.slots[self.ring.slot_for(game)]
+    }
+}
+
+impl GameRepositoryClient for SlotRoutingClient {
+
+    fn unsized_clone(&self) -> Box<dyn GameRepositoryClient + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn create_pregame(&self, game: GameIdentifier, rng_seed: Option<u64>, game_options: Vec<u8>) {
+        self.slot(&game).create_pregame(game, rng_seed, game_options)
+    }
+
+    fn register_pregame_stream(&self, player_id: String, game: GameIdentifier, stream_out: StreamSender<ProtoPreGameMessage>) {
+        self.slot(&game).register_pregame_stream(player_id, game, stream_out)
+    }
+
+    fn start_game(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoStartGameReply, Status>>) {
+        self.slot(&game).start_game(player_id, game, response_sender)
+    }
+
+    fn leave_game(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoLeaveGameReply, Status>>) {
+        self.slot(&game).leave_game(player_id, game, response_sender)
+    }
+
+    fn kick_player(&self, player_id: String, game: GameIdentifier, kicked_player_id: String, response_sender: oneshot::Sender<Result<ProtoKickPlayerReply, Status>>) {
+        self.slot(&game).kick_player(player_id, game, kicked_player_id, response_sender)
+    }
+
+    fn add_bot(&self, player_id: String, game: GameIdentifier, response_sender: oneshot::Sender<Result<ProtoAddBotReply, Status>>) {
+        self.slot(&game).add_bot(player_id, game, response_sender)
+    }
+
+    fn update_game_options(&self, player_id: String, game: GameIdentifier, game_options: Vec<u8>, response_sender: oneshot::Sender<Result<ProtoUpdateGameOptionsReply, Status>>) {
+        self.slot(&game).update_game_options(player_id, game, game_options, response_sender)
+    }
+
+    fn handle_game_event(&self, game: GameIdentifier, event: ErasedGameEvent) {
+        self.slot(&game).handle_game_event(game, event)
+    }
+}
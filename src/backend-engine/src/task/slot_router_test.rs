@@ -0,0 +1,231 @@
+mod slot_ring_tests {
+    use crate::game_manager::types::GameIdentifier;
+    use crate::task::slot_router::SlotRing;
+    use backend_framework::wire_api::proto_frj_ngn::ProtoGameType;
+
+    fn game(i: usize) -> GameIdentifier {
+        GameIdentifier {
+            game_id: format!("game-{}", i),
+            game_type: ProtoGameType::LoveLetter,
+        }
+    }
+
+    #[test]
+    fn single_slot_owns_every_game() {
+        let ring = SlotRing::new(1);
+
+        for i in 0..100 {
+            assert_eq!(0, ring.slot_for(&game(i)));
+        }
+    }
+
+    #[test]
+    fn same_game_always_maps_to_same_slot() {
+        let ring = SlotRing::new(8);
+        let other_ring = SlotRing::new(8);
+
+        for i in 0..100 {
+            assert_eq!(ring.slot_for(&game(i)), ring.slot_for(&game(i)));
+            assert_eq!(ring.slot_for(&game(i)), other_ring.slot_for(&game(i)));
+        }
+    }
+
+    #[test]
+    fn games_are_spread_evenly_across_slots() {
+        let num_slots = 8;
+        let num_games = 10_000;
+        let ring = SlotRing::new(num_slots);
+
+        let mut games_per_slot = vec![0; num_slots];
+        for i in 0..num_games {
+            games_per_slot[ring.slot_for(&game(i))] += 1;
+        }
+
+        let mean = num_games / num_slots;
+        for count in games_per_slot {
+            assert!(count > mean / 2, "Slot is underloaded: {} games, mean {}", count, mean);
+            assert!(count < mean * 3 / 2, "Slot is overloaded: {} games, mean {}", count, mean);
+        }
+    }
+
+    #[test]
+    fn adding_a_slot_only_moves_games_onto_the_new_slot() {
+        let ring = SlotRing::new(8);
+        let bigger_ring = SlotRing::new(9);
+
+        let mut moved = 0;
+        for i in 0..1_000 {
+            let before = ring.slot_for(&game(i));
+            let after = bigger_ring.slot_for(&game(i));
+            if before != after {
+                assert_eq!(8, after);
+                moved += 1;
+            }
+        }
+
+        assert!(moved > 0);
+        assert!(moved < 250, "Too many games moved: {}", moved);
+    }
+}
+
+mod load_tests {
+    use crate::game_manager::registry::GameRegistry;
+    use crate::game_manager::types::GameIdentifier;
+    use crate::task::start_repository_instance;
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::common_types::ClientInfo;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::game_plugin::GamePlugin;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming::{self, StreamSender};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoGameDataIn, ProtoGameDataOut, ProtoGameType};
+    use std::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio::sync::{mpsc, oneshot};
+    use tonic::Status;
+
+    const NUM_GAMES: usize = 64;
+    const EVENTS_PER_GAME: usize = 200;
+    const WORK_ITERATIONS_PER_EVENT: u64 = 20_000;
+    /// Stay well below the slot queue capacity, so no events are rejected.
+    const MAX_EVENTS_IN_FLIGHT: usize = 1024;
+
+    /// Game which burns CPU for every event, to simulate game logic.
+    struct BusyWorkPlugin;
+
+    #[derive(Debug)]
+    struct BusyWorkEvent {
+        done: mpsc::UnboundedSender<u64>,
+    }
+
+    struct BusyWorkInstance {
+        player_ids: Vec<String>,
+    }
+
+    impl GameInstanceManager<BusyWorkEvent> for BusyWorkInstance {
+        type Options = ();
+
+        fn create_new_game(player_ids: Vec<String>, _: (), _: PrngRand) -> Self {
+            BusyWorkInstance {
+                player_ids,
+            }
+        }
+
+        fn handle_event(&mut self, event: BusyWorkEvent) -> bool {
+            let mut hasher = DefaultHasher::new();
+            for i in 0..WORK_ITERATIONS_PER_EVENT {
+                i.hash(&mut hasher);
+            }
+            let _ = event.done.send(hasher.finish());
+            false
+        }
+
+        fn player_ids(&self) -> &Vec<String> {
+            &self.player_ids
+        }
+
+        fn is_game_stale(&self, _: Duration) -> bool {
+            false
+        }
+    }
+
+    impl GamePlugin for BusyWorkPlugin {
+        type DataIn = ProtoGameDataIn;
+        type DataOut = ProtoGameDataOut;
+        type Event = BusyWorkEvent;
+        type Instance = BusyWorkInstance;
+
+        fn game_type(&self) -> ProtoGameType {
+            ProtoGameType::Mastermind
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Busy Work"
+        }
+
+        fn player_count_min_max(&self) -> (usize, usize) {
+            (1, 1)
+        }
+
+        fn decode_event(&self, _: ClientInfo, _: ProtoGameDataIn) -> Result<BusyWorkEvent, Status> {
+            Err(Status::unimplemented("Load test game has no data stream"))
+        }
+
+        fn register_data_stream_event(&self, _: ClientInfo, _: StreamSender<ProtoGameDataOut>) -> BusyWorkEvent {
+            unimplemented!("Load test game has no data stream")
+        }
+
+        fn reject_event(&self, _: BusyWorkEvent, _: Status) {}
+    }
+
+    /// Returns events processed per second.
+    async fn measure_throughput(num_slots: usize) -> f64 {
+        let registry = Arc::new(GameRegistry::new().register(Arc::new(BusyWorkPlugin)));
+        let repo_client = start_repository_instance(registry, num_slots, None).unwrap();
+
+        // Create games
+        let mut pre_game_streams = Vec::with_capacity(NUM_GAMES);
+        let mut games = Vec::with_capacity(NUM_GAMES);
+        for i in 0..NUM_GAMES {
+            let game = GameIdentifier {
+                game_id: format!("load-test-{}", i),
+                game_type: ProtoGameType::Mastermind,
+            };
+            let (stream_tx, stream_rx) = streaming::stream_channel(OverflowPolicy::Disconnect);
+            let (start_tx, start_rx) = oneshot::channel();
+
+            repo_client.create_pregame(game.clone(), None, Vec::new());
+            repo_client.register_pregame_stream("host".to_string(), game.clone(), stream_tx);
+            repo_client.start_game("host".to_string(), game.clone(), start_tx);
+            start_rx.await.unwrap().expect("start_game");
+
+            pre_game_streams.push(stream_rx);
+            games.push(game);
+        }
+
+        // Send events round robin across games, topping up as events complete.
+        let (done_tx, mut done_rx) = mpsc::unbounded_channel();
+        let total_events = NUM_GAMES * EVENTS_PER_GAME;
+        let send_event = |i: usize| {
+            repo_client.handle_game_event(games[i % NUM_GAMES].clone(), Box::new(BusyWorkEvent {
+                done: done_tx.clone(),
+            }));
+        };
+
+        let before = Instant::now();
+        let mut sent = 0;
+        while sent < MAX_EVENTS_IN_FLIGHT {
+            send_event(sent);
+            sent += 1;
+        }
+        for _ in 0..total_events {
+            done_rx.recv().await.expect("Game instances should send done signal");
+            if sent < total_events {
+                send_event(sent);
+                sent += 1;
+            }
+        }
+
+        total_events as f64 / before.elapsed().as_secs_f64()
+    }
+
+    /// Run with `cargo test -p backend-engine --release -- --ignored --nocapture load_test`
+    #[tokio::test(threaded_scheduler)]
+    #[ignore]
+    async fn load_test_throughput_scales_with_slot_count() {
+        let max_slots = num_cpus::get();
+        let mut slot_counts = vec![1];
+        while slot_counts[slot_counts.len() - 1] * 2 <= max_slots {
+            slot_counts.push(slot_counts[slot_counts.len() - 1] * 2);
+        }
+
+        let baseline = measure_throughput(1).await;
+        println!("LOAD_TEST: slots={:<3} events/s={:>10.0} speedup=1.00x", 1, baseline);
+        for &num_slots in slot_counts.iter().skip(1) {
+            let throughput = measure_throughput(num_slots).await;
+            println!("LOAD_TEST: slots={:<3} events/s={:>10.0} speedup={:.2}x", num_slots, throughput, throughput / baseline);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use tonic::Status;
+
+/// Player IDs starting with this are reserved for bots, so a client can't impersonate one.
+pub const BOT_PLAYER_ID_PREFIX: &str = "bot:";
+
+pub fn bot_player_id(bot_number: usize) -> String {
+    format!("{}{}", BOT_PLAYER_ID_PREFIX, bot_number)
+}
+
+pub fn is_bot_player_id(player_id: &str) -> bool {
+    player_id.starts_with(BOT_PLAYER_ID_PREFIX)
+}
+
+/// Checked for every player ID a client sends, so a client can't take a bot's seat.
+pub fn check_not_bot_player_id(player_id: &str) -> Result<(), Status> {
+    if is_bot_player_id(player_id) {
+        return Err(Status::invalid_argument(format!("Player IDs starting with '{}' are reserved for bots.", BOT_PLAYER_ID_PREFIX)));
+    }
+
+    Ok(())
+}
+
+/// A player that's played by the server. A bot sees exactly what a client in its seat would see,
+/// and plays by sending the same messages a client would, so game instances don't need to know
+/// which of their players are bots.
+///
+/// Bots are driven from the game repository's task, so they must never block.
+pub trait Bot: Send {
+    /// Same as the plugin's `DataIn`.
+    type DataIn: prost::Message;
+
+    /// Same as the plugin's `DataOut`.
+    type DataOut: prost::Message;
+
+    /// Called for each message the game instance sends to the bot, in order.
+    fn observe(&mut self, message: Self::DataOut);
+
+    /// Called after the bot observed new messages. Returns the messages to send to the game
+    /// instance, which are usually empty when it isn't the bot's turn.
+    fn next_moves(&mut self) -> Vec<Self::DataIn>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bot::{bot_player_id, is_bot_player_id};
+
+    #[test]
+    fn bot_player_ids_are_reserved() {
+        assert_eq!("bot:2", bot_player_id(2));
+        assert!(is_bot_player_id(&bot_player_id(1)));
+        assert!(!is_bot_player_id("robot:1"));
+        assert!(!is_bot_player_id("p1"));
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::SystemTime;
+use tokio::stream::Stream;
+
+/// What a bounded channel does with a message that is sent while the channel is full.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Hand the new message back to the sender, so it can reject the request.
+    Reject,
+    /// Drop the oldest queued message to make room. Only appropriate when every message
+    /// supersedes the ones before it (e.g. full state snapshots).
+    DropOldest,
+    /// The receiver isn't keeping up, so drop everything queued and close the channel.
+    Disconnect,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SendError<T> {
+    /// Channel is full and the policy is `Reject`.
+    Full(T),
+    /// Channel was full and the policy is `Disconnect`. The channel is now closed.
+    Disconnected(T),
+    /// Receiver was dropped, or the channel was already disconnected.
+    Closed(T),
+}
+
+impl<T> SendError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            SendError::Full(t) => t,
+            SendError::Disconnected(t) => t,
+            SendError::Closed(t) => t,
+        }
+    }
+}
+
+/// Point-in-time view of a channel's queue, for monitoring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueMetrics {
+    pub depth: usize,
+    pub max_depth: usize,
+    pub capacity: usize,
+    pub overflow_count: usize,
+}
+
+/// Create a channel which holds at most `capacity` messages. Any number of senders, one receiver.
+pub fn bounded_channel<T>(capacity: usize, policy: OverflowPolicy) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    assert!(capacity > 0, "Channel capacity must be positive");
+
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            receiver_waker: None,
+            receiver_alive: true,
+            disconnected: false,
+            closed_at: None,
+            max_depth: 0,
+            overflow_count: 0,
+        }),
+        sender_count: AtomicUsize::new(1),
+        capacity,
+        policy,
+    });
+
+    let sender = BoundedSender {
+        shared: shared.clone(),
+    };
+    let receiver = BoundedReceiver {
+        shared,
+    };
+
+    (sender, receiver)
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    sender_count: AtomicUsize,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    receiver_waker: Option<Waker>,
+    receiver_alive: bool,
+    disconnected: bool,
+    /// When the receiver was dropped or disconnected, whichever came first.
+    closed_at: Option<SystemTime>,
+    max_depth: usize,
+    overflow_count: usize,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        // A panic while holding the lock can't leave the queue half-modified, so recover.
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn metrics(&self) -> QueueMetrics {
+        let state = self.lock();
+        QueueMetrics {
+            depth: state.queue.len(),
+            max_depth: state.max_depth,
+            capacity: self.capacity,
+            overflow_count: state.overflow_count,
+        }
+    }
+}
+
+fn wake(waker: Option<Waker>) {
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+// ------- BoundedSender --------
+
+pub struct BoundedSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedSender<T> {
+    pub fn send(&self, message: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        if !state.receiver_alive || state.disconnected {
+            return Err(SendError::Closed(message));
+        }
+
+        if state.queue.len() >= self.shared.capacity {
+            state.overflow_count += 1;
+            match self.shared.policy {
+                OverflowPolicy::Reject => return Err(SendError::Full(message)),
+                OverflowPolicy::DropOldest => {
+                    state.queue.pop_front();
+                },
+                OverflowPolicy::Disconnect => {
+                    let dropped_messages = std::mem::take(&mut state.queue);
+                    state.disconnected = true;
+                    state.closed_at.get_or_insert_with(SystemTime::now);
+                    let waker = state.receiver_waker.take();
+                    drop(state);
+                    drop(dropped_messages);
+                    wake(waker);
+                    return Err(SendError::Disconnected(message));
+                },
+            }
+        }
+
+        state.queue.push_back(message);
+        state.max_depth = state.max_depth.max(state.queue.len());
+        let waker = state.receiver_waker.take();
+        drop(state);
+        wake(waker);
+
+        Ok(())
+    }
+
+    pub fn metrics(&self) -> QueueMetrics {
+        self.shared.metrics()
+    }
+
+    /// When the channel stopped accepting messages, because the receiver was dropped or
+    /// disconnected. `None` while the channel is open.
+    pub fn closed_since(&self) -> Option<SystemTime> {
+        self.shared.lock().closed_at
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_count.fetch_add(1, Ordering::SeqCst);
+        BoundedSender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        if self.shared.sender_count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Last sender, so the receiver needs to be woken up to observe the channel closing.
+            let waker = self.shared.lock().receiver_waker.take();
+            wake(waker);
+        }
+    }
+}
+
+impl<T> Debug for BoundedSender<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "BoundedSender {{ {:?} }}", self.metrics())
+    }
+}
+
+// ------- BoundedReceiver --------
+
+pub struct BoundedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedReceiver<T> {
+    /// Returns `None` once all senders are dropped and the queue is drained, or as soon as the
+    /// channel is disconnected by its overflow policy.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.shared.lock();
+        if let Some(message) = state.queue.pop_front() {
+            return Poll::Ready(Some(message));
+        }
+
+        if state.disconnected || self.shared.sender_count.load(Ordering::SeqCst) == 0 {
+            return Poll::Ready(None);
+        }
+
+        state.receiver_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Non-blocking version of `poll_recv()`, for receivers that aren't driven by a task. `None`
+    /// if the queue is empty, whether or not the channel is closed.
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.shared.lock().queue.pop_front()
+    }
+
+    /// True if the channel was closed because the receiver fell too far behind.
+    pub fn is_disconnected(&self) -> bool {
+        self.shared.lock().disconnected
+    }
+
+    pub fn metrics(&self) -> QueueMetrics {
+        self.shared.metrics()
+    }
+}
+
+impl<T> Stream for BoundedReceiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.poll_recv(cx)
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver_alive = false;
+        state.closed_at.get_or_insert_with(SystemTime::now);
+        // Messages may own senders of this same channel, so drop them after unlocking.
+        let dropped_messages = std::mem::take(&mut state.queue);
+        drop(state);
+        drop(dropped_messages);
+    }
+}
+
+impl<T> Debug for BoundedReceiver<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "BoundedReceiver {{ {:?} }}", self.metrics())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::channel::{bounded_channel, OverflowPolicy, SendError};
+    use std::time::SystemTime;
+    use tokio::stream::StreamExt;
+
+    #[tokio::test]
+    async fn receives_in_order_then_closes_when_senders_dropped() {
+        let (tx, mut rx) = bounded_channel(4, OverflowPolicy::Reject);
+        let tx2 = tx.clone();
+        tx.send(1).unwrap();
+        tx2.send(2).unwrap();
+        drop(tx);
+        drop(tx2);
+
+        assert_eq!(Some(1), rx.next().await);
+        assert_eq!(Some(2), rx.next().await);
+        assert_eq!(None, rx.next().await);
+    }
+
+    #[tokio::test]
+    async fn reject_policy_returns_message_when_full() {
+        let (tx, mut rx) = bounded_channel(2, OverflowPolicy::Reject);
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+
+        assert_eq!(Err(SendError::Full(3)), tx.send(3));
+        assert_eq!(1, tx.metrics().overflow_count);
+
+        // Room frees up once the receiver catches up.
+        assert_eq!(Some(1), rx.next().await);
+        tx.send(3).unwrap();
+        assert_eq!(Some(2), rx.next().await);
+        assert_eq!(Some(3), rx.next().await);
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_policy_keeps_newest_messages() {
+        let (tx, mut rx) = bounded_channel(2, OverflowPolicy::DropOldest);
+        for i in 1..=5 {
+            tx.send(i).unwrap();
+        }
+        drop(tx);
+
+        assert_eq!(Some(4), rx.next().await);
+        assert_eq!(Some(5), rx.next().await);
+        assert_eq!(None, rx.next().await);
+    }
+
+    #[tokio::test]
+    async fn disconnect_policy_closes_channel_when_full() {
+        let (tx, mut rx) = bounded_channel(2, OverflowPolicy::Disconnect);
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+
+        assert_eq!(Err(SendError::Disconnected(3)), tx.send(3));
+        assert_eq!(Err(SendError::Closed(4)), tx.send(4));
+        assert!(rx.is_disconnected());
+        assert!(tx.closed_since().is_some());
+        assert_eq!(None, rx.next().await);
+    }
+
+    #[test]
+    fn try_recv_drains_queue_without_waiting() {
+        let (tx, mut rx) = bounded_channel(4, OverflowPolicy::Reject);
+        assert_eq!(None, rx.try_recv());
+
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_eq!(Some(1), rx.try_recv());
+        assert_eq!(Some(2), rx.try_recv());
+        assert_eq!(None, rx.try_recv());
+    }
+
+    #[test]
+    fn send_fails_after_receiver_dropped() {
+        let (tx, rx) = bounded_channel(2, OverflowPolicy::Reject);
+        drop(rx);
+
+        assert_eq!(Err(SendError::Closed(1)), tx.send(1));
+    }
+
+    #[test]
+    fn closed_since_is_set_once_receiver_dropped() {
+        let (tx, rx) = bounded_channel(2, OverflowPolicy::Reject);
+        tx.send(1).unwrap();
+        assert_eq!(None, tx.closed_since());
+
+        let before = SystemTime::now();
+        drop(rx);
+        let closed_since = tx.closed_since().expect("Channel should be closed");
+        assert!(closed_since >= before);
+
+        // Later sends don't move the time forward.
+        let _ = tx.send(2);
+        assert_eq!(Some(closed_since), tx.closed_since());
+    }
+
+    #[test]
+    fn metrics_track_depth() {
+        let (tx, _rx) = bounded_channel(8, OverflowPolicy::Reject);
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        tx.send(3).unwrap();
+
+        let metrics = tx.metrics();
+        assert_eq!(3, metrics.depth);
+        assert_eq!(3, metrics.max_depth);
+        assert_eq!(8, metrics.capacity);
+        assert_eq!(0, metrics.overflow_count);
+    }
+}
@@ -1,15 +1,25 @@
 use crate::streaming::StreamSender;
+use crate::wire_api::proto_frj_ngn::ProtoPlayerPresence;
 use tonic::Status;
 use std::collections::HashMap;
+use std::time::SystemTime;
 
 /// Struct for sending data-stream messages to players; used only once a game has been started.
 ///
 /// Game logic should NOT:
 /// * use this struct to determine which player IDs are in a game (game state should track this)
-/// * react to stream disconnects/reconnects
+/// * react to stream disconnects/reconnects (other than publishing `presence()`)
 pub struct PlayerDataStreams<M: prost::Message> {
     allowed_player_ids: immutable::PlayerIds,
     streams: HashMap<String, StreamSender<M>>,
+    spectator_streams: HashMap<String, SpectatorStream<M>>,
+    /// Players who haven't opened a stream yet count as disconnected since the game was created.
+    created_at: SystemTime,
+}
+
+struct SpectatorStream<M: prost::Message> {
+    god_view: bool,
+    stream: StreamSender<M>,
 }
 
 impl<M: prost::Message> PlayerDataStreams<M> {
@@ -18,6 +28,8 @@ impl<M: prost::Message> PlayerDataStreams<M> {
         PlayerDataStreams {
             allowed_player_ids: immutable::PlayerIds::new(player_ids),
             streams: HashMap::new(),
+            spectator_streams: HashMap::new(),
+            created_at: SystemTime::now(),
         }
     }
 
@@ -29,6 +41,41 @@ impl<M: prost::Message> PlayerDataStreams<M> {
         }
     }
 
+    /// Anyone can spectate, but only the host (the first player) can have a god view.
+    pub fn add_spectator_stream(&mut self, spectator_id: String, god_view: bool, stream: StreamSender<M>) {
+        if god_view && !self.allowed_player_ids.is_host(&spectator_id) {
+            stream.disconnect_with_err(Status::permission_denied("Only the host can spectate with a god view."));
+        } else {
+            self.spectator_streams.insert(spectator_id, SpectatorStream {
+                god_view,
+                stream,
+            });
+        }
+    }
+
+    /// Returns the ID of each spectator, and whether they have a god view. Spectators are
+    /// sent their own messages, which must only have public information (unless god view).
+    pub fn spectators(&self) -> impl Iterator<Item = (&String, bool)> {
+        self.spectator_streams
+            .iter()
+            .map(|(spectator_id, spectator)| (spectator_id, spectator.god_view))
+    }
+
+    /// Connection status of each player, in the order the player IDs were given. Meant to be
+    /// included in the game state, so players can see who they're waiting on.
+    pub fn presence(&self) -> Vec<ProtoPlayerPresence> {
+        self.allowed_player_ids
+            .iter()
+            .map(|player_id| {
+                let disconnected_since = match self.streams.get(player_id) {
+                    Some(stream) => stream.disconnected_since(),
+                    None => Some(self.created_at),
+                };
+                ProtoPlayerPresence::new(player_id.clone(), disconnected_since)
+            })
+            .collect()
+    }
+
     /// Intentionally avoiding to update state when a disconnected stream is detected
     /// because it results in a cascading `mut` up the call chain, that's otherwise not
     /// required.
@@ -46,6 +93,13 @@ impl<M: prost::Message> PlayerDataStreams<M> {
             let _ = stream.send_error_message(status);
         }
     }
+
+    /// See `send_msg()`.
+    pub fn send_spectator_msg(&self, spectator_id: &String, message: impl Into<M>) {
+        if let Some(spectator) = self.spectator_streams.get(spectator_id) {
+            let _ = spectator.stream.send_message(message.into());
+        }
+    }
 }
 
 mod immutable {
@@ -59,5 +113,13 @@ mod immutable {
         pub fn contains(&self, player_id: &String) -> bool {
             self.0.contains(player_id)
         }
+
+        pub fn is_host(&self, player_id: &String) -> bool {
+            self.0.first() == Some(player_id)
+        }
+
+        pub fn iter(&self) -> impl Iterator<Item = &String> {
+            self.0.iter()
+        }
     }
 }
\ No newline at end of file
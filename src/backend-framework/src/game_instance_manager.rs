@@ -1,20 +1,55 @@
-use std::time::Duration;
+use crate::prng::PrngRand;
+use std::time::{Duration, SystemTime};
 
 /// The generic trait which acts as a manager for a single instance of the game.
 /// A game instance comes into play only *after* the pre-game phase, and games
 /// are assumed, once created and started, to have an immutable set of players.
 pub trait GameInstanceManager<T> {
 
-    /// Create a new instance of a game from the set of players.
-    /// TODO:3 add `Random` to input, which is seeded with game_id on caller side.
-    fn create_new_game(player_ids: Vec<String>) -> Self;
+    /// Chosen by the host before the game starts. Games without options use `()`.
+    type Options: Default;
+
+    /// Create a new instance of a game from the set of players. Every random decision in the
+    /// game must come from `rng`, so a game can be reproduced from the seed that `rng` was
+    /// created with.
+    fn create_new_game(player_ids: Vec<String>, options: Self::Options, rng: PrngRand) -> Self;
 
     /// This is the single entry point for manipulating the state of the game.
-    fn handle_event(&mut self, event: T);
+    ///
+    /// Returns true if the game changed. Events which only read the game's state, or which were
+    /// rejected, return false, so the game isn't saved again for them.
+    fn handle_event(&mut self, event: T) -> bool;
 
     /// Accessor to get a reference to the players in the game.
     fn player_ids(&self) -> &Vec<String>;
 
     /// Check if we can delete game
     fn is_game_stale(&self, expiry_duration: Duration) -> bool;
+
+    /// Called about once a second by the repository task. Games with time limits take the
+    /// default action for each player whose deadline is before `now`.
+    ///
+    /// Returns true if the game changed.
+    fn handle_expired_deadlines(&mut self, _now: SystemTime) -> bool {
+        false
+    }
+
+    /// True once nothing can change the game anymore. Finished games are only kept around
+    /// briefly, so players can see the final result.
+    fn is_game_complete(&self) -> bool {
+        false
+    }
+
+    /// Serialize the game's state, so it can be restored after a server restart. Player streams
+    /// aren't part of the snapshot, players re-register them when they reconnect.
+    ///
+    /// Returns `None` if the game doesn't support being restored.
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Inverse of `snapshot()`.
+    fn restore_game(_snapshot: &[u8]) -> Result<Self, String> where Self: Sized {
+        Err("Game doesn't support being restored".to_string())
+    }
 }
@@ -0,0 +1,97 @@
+use crate::bot::Bot;
+use crate::common_types::ClientInfo;
+use crate::game_instance_manager::GameInstanceManager;
+use crate::prng::PrngRand;
+use crate::streaming::StreamSender;
+use crate::wire_api::proto_frj_ngn::ProtoGameType;
+use std::fmt::Debug;
+use tonic::Status;
+
+/// Options type of a plugin's game instances.
+pub type GameOptions<P> = <<P as GamePlugin>::Instance as GameInstanceManager<<P as GamePlugin>::Event>>::Options;
+
+/// Bot type of a plugin, which plays over the plugin's data stream messages.
+pub type BoxedBot<P> = Box<dyn Bot<DataIn = <P as GamePlugin>::DataIn, DataOut = <P as GamePlugin>::DataOut>>;
+
+/// The generic trait which describes a single game to the engine. Each game crate implements
+/// this once, and registers it with the engine at startup. The engine then hosts, creates, and
+/// routes events to instances of the game without any game-specific code.
+pub trait GamePlugin: Send + Sync + 'static {
+    /// Message type sent from client to server, as the payload of the game's data stream.
+    type DataIn: prost::Message + Default + Send + 'static;
+
+    /// Message type sent from server to client, as the payload of the game's data stream.
+    type DataOut: prost::Message + Send + 'static;
+
+    /// The single event type accepted by `Self::Instance`.
+    type Event: Debug + Send + 'static;
+
+    type Instance: GameInstanceManager<Self::Event> + Send + 'static;
+
+    // Metadata
+
+    fn game_type(&self) -> ProtoGameType;
+
+    fn display_name(&self) -> &'static str;
+
+    /// Inclusive bounds on the number of players that can start a game.
+    fn player_count_min_max(&self) -> (usize, usize);
+
+    // Event decoding
+
+    /// Convert a client's data stream message into an event for the game instance.
+    fn decode_event(&self, client_info: ClientInfo, message: Self::DataIn) -> Result<Self::Event, Status>;
+
+    /// Create the event which hands a player's outgoing data stream to the game instance.
+    fn register_data_stream_event(&self, client_info: ClientInfo, stream_out: StreamSender<Self::DataOut>) -> Self::Event;
+
+    /// Create the event which hands a spectator's outgoing data stream to the game instance. The
+    /// spectator doesn't need to be a player. Games which support spectators override this, and
+    /// must only send spectators public information (unless `god_view`).
+    fn register_spectator_stream_event(&self, _client_info: ClientInfo, _god_view: bool, _stream_out: StreamSender<Self::DataOut>) -> Result<Self::Event, Status> {
+        Err(Status::unimplemented(format!("{} doesn't support spectators.", self.display_name())))
+    }
+
+    /// Called when an event can't be delivered to a game instance (e.g. the game doesn't exist).
+    /// Implementations should notify the client if the event gives them a way to.
+    fn reject_event(&self, event: Self::Event, status: Status);
+
+    // Instance creation
+
+    /// `game_options` is the host's encoded `Proto<Game>Options` message, which is empty if the
+    /// host didn't pick any. Games with options override this to decode and validate them.
+    ///
+    /// Called each time the host picks options, so they find out about invalid options before
+    /// starting the game.
+    fn decode_options(&self, game_options: &[u8]) -> Result<GameOptions<Self>, Status> {
+        if !game_options.is_empty() {
+            return Err(Status::invalid_argument(format!("{} doesn't have any game options.", self.display_name())));
+        }
+
+        Ok(GameOptions::<Self>::default())
+    }
+
+    /// Games override this if their options limit which players can start the game.
+    fn create_instance(&self, player_ids: Vec<String>, game_options: &[u8], rng: PrngRand) -> Result<Self::Instance, Status> {
+        let options = self.decode_options(game_options)?;
+        Ok(Self::Instance::create_new_game(player_ids, options, rng))
+    }
+
+    fn restore_instance(&self, snapshot: &[u8]) -> Result<Self::Instance, String> {
+        Self::Instance::restore_game(snapshot)
+    }
+
+    // Bots
+
+    /// Games which support bots override this and `create_bot()`. Hosts can only add bots to
+    /// games which support them.
+    fn supports_bots(&self) -> bool {
+        false
+    }
+
+    /// The bot is created for a player that's already part of the game instance, right before
+    /// its data stream is registered. Only called if `supports_bots()`.
+    fn create_bot(&self, _player_id: String, _rng: PrngRand) -> Option<BoxedBot<Self>> {
+        None
+    }
+}
@@ -12,6 +12,10 @@ impl<T> Holder<T> {
         self.0.take().expect("Invalid state: Holder.take() called when it was empty")
     }
 
+    pub fn get(&self) -> &T {
+        self.0.as_ref().expect("Invalid state: Holder.get() called when it was empty")
+    }
+
     pub fn put(&mut self, item: T) {
         if self.0.is_some() {
             panic!("Invalid state: Holder.put() called when it was full");
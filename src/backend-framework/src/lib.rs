@@ -1,7 +1,10 @@
 pub mod activity_timer;
+pub mod bot;
+pub mod channel;
 pub mod common_types;
 pub mod data_stream;
 pub mod game_instance_manager;
+pub mod game_plugin;
 pub mod holder;
 pub mod prng;
 pub mod shuffler;
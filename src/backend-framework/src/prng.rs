@@ -1,4 +1,5 @@
 use rand::RngCore;
+use serde::{Deserialize, Serialize};
 
 /// Okay, so why would you ever implement your own RNG? Because surprisingly there's no fricking easy
 /// existing rust **Pseudo** RNG! I need a seeded RNG (i.e. PRNG) to be able to reproduce deck shuffling.
@@ -47,6 +48,9 @@ use rand::RngCore;
 /// ```
 ///
 /// Alas. Here we are. I don't know what this does. But it works pretty decently.
+///
+/// It's serializable, so a game that's saved part way through keeps its random sequence.
+#[derive(Serialize, Deserialize)]
 pub struct PrngRand {
     n: u64,
 }
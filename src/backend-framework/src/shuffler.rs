@@ -10,8 +10,9 @@ pub fn shuffle<T>(collection: Vec<T>) -> (Vec<T>, u64) {
     (shuffle_impl(collection, seed), seed)
 }
 
-fn shuffle_impl<T>(mut collection: Vec<T>, seed_for_random: u64) -> Vec<T> {
-    let prng = &mut PrngRand::new(seed_for_random);
+/// Same as `shuffle()`, but draws randomness from the caller's PRNG, so a game that's seeded
+/// once is reproducible from that seed.
+pub fn shuffle_with<T>(mut collection: Vec<T>, prng: &mut PrngRand) -> Vec<T> {
     // Let's get wild
     collection.shuffle(prng);
     collection.reverse();
@@ -22,6 +23,10 @@ fn shuffle_impl<T>(mut collection: Vec<T>, seed_for_random: u64) -> Vec<T> {
     collection
 }
 
+fn shuffle_impl<T>(collection: Vec<T>, seed_for_random: u64) -> Vec<T> {
+    shuffle_with(collection, &mut PrngRand::new(seed_for_random))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,33 +1,70 @@
+use crate::channel::{bounded_channel, BoundedReceiver, BoundedSender, OverflowPolicy, SendError, QueueMetrics};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
-use tokio::sync::mpsc;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::SystemTime;
+use tokio::stream::Stream;
 use tonic::Status;
 
+/// Max messages queued for a single client before the stream's overflow policy kicks in.
+const STREAM_CAPACITY: usize = 64;
+
+/// Create the server side of a stream to a single client. The receiver is handed to tonic.
+pub fn stream_channel<M: prost::Message>(policy: OverflowPolicy) -> (StreamSender<M>, StreamReceiver<M>) {
+    let (sender, receiver) = bounded_channel(STREAM_CAPACITY, policy);
+
+    let sender = StreamSender {
+        sender,
+    };
+    let receiver = StreamReceiver {
+        receiver,
+        sent_disconnect_err: false,
+    };
+
+    (sender, receiver)
+}
+
+// ------- StreamSender --------
+
 pub struct StreamSender<M: prost::Message> {
-    sender: mpsc::UnboundedSender<Result<M, Status>>,
+    sender: BoundedSender<Result<M, Status>>,
 }
 
 impl<M: prost::Message> StreamSender<M> {
-    pub fn new(sender: mpsc::UnboundedSender<Result<M, Status>>) -> Self {
-        StreamSender {
-            sender
-        }
-    }
-
     pub fn send_message(&self, message: M) -> Result<(), ()> {
-        self.sender.send(Ok(message))
-            .map_err(|msg| println!("WARN: Client stream dropped. We failed to send message: {:?}", msg))
+        self.send(Ok(message))
     }
 
     pub fn send_error_message(&self, status: Status) -> Result<(), ()> {
-        self.sender.send(Err(status))
-            .map_err(|msg| println!("WARN: Client stream dropped. We failed to send message: {:?}", msg))
+        self.send(Err(status))
+    }
+
+    pub fn metrics(&self) -> QueueMetrics {
+        self.sender.metrics()
+    }
+
+    /// When the client's stream was closed, or `None` if it's still connected.
+    pub fn disconnected_since(&self) -> Option<SystemTime> {
+        self.sender.closed_since()
     }
 
     pub(crate) fn disconnect_with_err(self, status: Status) {
         let _ = self.sender.send(Err(status));
         // Drop `self` closes the stream
     }
+
+    fn send(&self, message: Result<M, Status>) -> Result<(), ()> {
+        self.sender.send(message)
+            .map_err(|e| match e {
+                SendError::Closed(msg) => {
+                    println!("WARN: Client stream dropped. We failed to send message: {:?}", msg);
+                },
+                SendError::Full(msg) | SendError::Disconnected(msg) => {
+                    println!("WARN: Client stream is not keeping up ({:?}). We failed to send message: {:?}", self.sender.metrics(), msg);
+                },
+            })
+    }
 }
 
 impl<M: prost::Message> Debug for StreamSender<M> {
@@ -35,3 +72,39 @@ impl<M: prost::Message> Debug for StreamSender<M> {
         write!(f, "StreamSender {{...}}")
     }
 }
+
+// ------- StreamReceiver --------
+
+/// Ends with a `RESOURCE_EXHAUSTED` error if the stream was disconnected for being too slow.
+pub struct StreamReceiver<M: prost::Message> {
+    receiver: BoundedReceiver<Result<M, Status>>,
+    sent_disconnect_err: bool,
+}
+
+impl<M: prost::Message> StreamReceiver<M> {
+    /// The next queued message, without waiting for one. For streams the server reads itself,
+    /// i.e. a bot's stream.
+    pub fn try_next(&mut self) -> Option<Result<M, Status>> {
+        self.receiver.try_recv()
+    }
+}
+
+impl<M: prost::Message> Stream for StreamReceiver<M> {
+    type Item = Result<M, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.receiver.poll_recv(cx) {
+            Poll::Ready(None) if self.receiver.is_disconnected() && !self.sent_disconnect_err => {
+                self.sent_disconnect_err = true;
+                Poll::Ready(Some(Err(Status::resource_exhausted("Stream closed, client is not keeping up with messages."))))
+            },
+            poll => poll,
+        }
+    }
+}
+
+impl<M: prost::Message> Debug for StreamReceiver<M> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "StreamReceiver {{...}}")
+    }
+}
@@ -1,12 +1,16 @@
 /// Wrappers of the `oneof` message type in protobuf.
 mod oneof_wrappers {
+    use crate::wire_api::proto_frj_ngn::proto_lost_cities_data_out::ProtoLoCiOut;
     use crate::wire_api::proto_frj_ngn::proto_love_letter_data_out::ProtoLvLeOut;
+    use crate::wire_api::proto_frj_ngn::proto_mastermind_data_out::ProtoMmOut;
     use crate::wire_api::proto_frj_ngn::proto_pre_game_message::{
-        ProtoGameStartMsg, ProtoJoinGameAck, ProtoPlayerJoinMsg,
+        ProtoGameOptionsMsg, ProtoGameStartMsg, ProtoJoinGameAck, ProtoPlayerJoinMsg,
+        ProtoPlayerLeaveMsg, ProtoPlayerPresenceMsg,
     };
     use crate::wire_api::proto_frj_ngn::ProtoPreGameMessage;
     use crate::wire_api::proto_frj_ngn::{
-        proto_pre_game_message, ProtoLoveLetterDataOut, ProtoLvLeGameState,
+        proto_pre_game_message, ProtoLoCiGameState, ProtoLostCitiesDataOut, ProtoLoveLetterDataOut,
+        ProtoLvLeGameState, ProtoMastermindDataOut, ProtoMmGameState,
     };
 
     impl From<ProtoJoinGameAck> for ProtoPreGameMessage {
@@ -33,6 +37,30 @@ mod oneof_wrappers {
         }
     }
 
+    impl From<ProtoGameOptionsMsg> for ProtoPreGameMessage {
+        fn from(msg: ProtoGameOptionsMsg) -> Self {
+            ProtoPreGameMessage {
+                inner: Some(proto_pre_game_message::Inner::GameOptionsMsg(msg)),
+            }
+        }
+    }
+
+    impl From<ProtoPlayerLeaveMsg> for ProtoPreGameMessage {
+        fn from(msg: ProtoPlayerLeaveMsg) -> Self {
+            ProtoPreGameMessage {
+                inner: Some(proto_pre_game_message::Inner::PlayerLeaveMsg(msg)),
+            }
+        }
+    }
+
+    impl From<ProtoPlayerPresenceMsg> for ProtoPreGameMessage {
+        fn from(msg: ProtoPlayerPresenceMsg) -> Self {
+            ProtoPreGameMessage {
+                inner: Some(proto_pre_game_message::Inner::PlayerPresenceMsg(msg)),
+            }
+        }
+    }
+
     impl From<ProtoLvLeGameState> for ProtoLoveLetterDataOut {
         fn from(game_state: ProtoLvLeGameState) -> Self {
             ProtoLoveLetterDataOut {
@@ -41,6 +69,24 @@ mod oneof_wrappers {
             }
         }
     }
+
+    impl From<ProtoLoCiGameState> for ProtoLostCitiesDataOut {
+        fn from(game_state: ProtoLoCiGameState) -> Self {
+            ProtoLostCitiesDataOut {
+                clock: 0,
+                proto_lo_ci_out: Some(ProtoLoCiOut::GameState(game_state)),
+            }
+        }
+    }
+
+    impl From<ProtoMmGameState> for ProtoMastermindDataOut {
+        fn from(game_state: ProtoMmGameState) -> Self {
+            ProtoMastermindDataOut {
+                clock: 0,
+                proto_mm_out: Some(ProtoMmOut::GameState(game_state)),
+            }
+        }
+    }
 }
 
 /// All enums need a convert method like this because prost generated a `from_i32` method via macros
@@ -66,7 +112,37 @@ mod enum_converters {
         }
     }
 
+    mod lost_cities {
+        use crate::wire_api::proto_frj_ngn::proto_lo_ci_play_card_req::ProtoLoCiPlayTarget;
+        use crate::wire_api::proto_frj_ngn::ProtoLoCiColor;
+        use std::convert::TryFrom;
+        use tonic::{Code, Status};
+
+        impl TryFrom<i32> for ProtoLoCiPlayTarget {
+            type Error = Status;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                ProtoLoCiPlayTarget::from_i32(value).ok_or(Status::new(
+                    Code::InvalidArgument,
+                    format!("Illegal PlayTarget i32 value '{}'", value),
+                ))
+            }
+        }
+
+        impl TryFrom<i32> for ProtoLoCiColor {
+            type Error = Status;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                ProtoLoCiColor::from_i32(value).ok_or(Status::new(
+                    Code::InvalidArgument,
+                    format!("Illegal LoCiColor i32 value '{}'", value),
+                ))
+            }
+        }
+    }
+
     mod love_letter {
+        use crate::wire_api::proto_frj_ngn::proto_lv_le_options::ProtoLvLeDeckVariant;
         use crate::wire_api::proto_frj_ngn::proto_lv_le_play_card_req::ProtoLvLeCardSource;
         use crate::wire_api::proto_frj_ngn::ProtoLvLeCard;
         use std::convert::TryFrom;
@@ -93,23 +169,38 @@ mod enum_converters {
                 ))
             }
         }
+
+        impl TryFrom<i32> for ProtoLvLeDeckVariant {
+            type Error = Status;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                ProtoLvLeDeckVariant::from_i32(value).ok_or(Status::new(
+                    Code::InvalidArgument,
+                    format!("Illegal LvLeDeckVariant i32 value '{}'", value),
+                ))
+            }
+        }
     }
 }
 
 /// Non-enum and non-oneof converters
 mod normal_converters {
+    use crate::bot;
     use crate::common_types::ClientInfo;
     use crate::wire_api::proto_frj_ngn::proto_lv_le_card_outcome::{
-        ProtoBaronOutcome, ProtoGuardOutcome, ProtoPriestOutcome, ProtoPrinceOutcome,
+        ProtoBaronOutcome, ProtoBaronessOutcome, ProtoBishopOutcome, ProtoCardinalOutcome,
+        ProtoGuardOutcome, ProtoNoEffectOutcome, ProtoPriestOutcome, ProtoPrinceOutcome,
     };
     use crate::wire_api::proto_frj_ngn::proto_lv_le_card_selection::{
-        ProtoBaronSelection, ProtoGuardSelection, ProtoKingSelection, ProtoPriestSelection,
-        ProtoPrinceSelection,
+        ProtoBaronSelection, ProtoBaronessSelection, ProtoBishopSelection, ProtoCardinalSelection,
+        ProtoDowagerQueenSelection, ProtoGuardSelection, ProtoJesterSelection, ProtoKingSelection,
+        ProtoPriestSelection, ProtoPrinceSelection, ProtoSycophantSelection,
     };
     use crate::wire_api::proto_frj_ngn::{
         proto_lv_le_card_outcome, proto_lv_le_card_selection, ProtoGameDataHandshake,
-        ProtoLvLeCardOutcome, ProtoLvLeCardSelection, ProtoLvLeCommittedPlay,
+        ProtoLvLeCardOutcome, ProtoLvLeCardSelection, ProtoLvLeCommittedPlay, ProtoPlayerPresence,
     };
+    use std::time::{SystemTime, UNIX_EPOCH};
 
     impl From<ProtoGameDataHandshake> for ClientInfo {
         fn from(handshake: ProtoGameDataHandshake) -> Self {
@@ -183,6 +274,91 @@ mod normal_converters {
         }
     }
 
+    impl From<(ProtoCardinalSelection, ProtoCardinalOutcome)> for ProtoLvLeCommittedPlay {
+        fn from((selection, outcome): (ProtoCardinalSelection, ProtoCardinalOutcome)) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: Some(ProtoLvLeCardSelection {
+                    inner: Some(proto_lv_le_card_selection::Inner::Cardinal(selection)),
+                }),
+                outcome: Some(ProtoLvLeCardOutcome {
+                    inner: Some(proto_lv_le_card_outcome::Inner::Cardinal(outcome)),
+                }),
+            }
+        }
+    }
+
+    impl From<(ProtoBaronessSelection, ProtoBaronessOutcome)> for ProtoLvLeCommittedPlay {
+        fn from((selection, outcome): (ProtoBaronessSelection, ProtoBaronessOutcome)) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: Some(ProtoLvLeCardSelection {
+                    inner: Some(proto_lv_le_card_selection::Inner::Baroness(selection)),
+                }),
+                outcome: Some(ProtoLvLeCardOutcome {
+                    inner: Some(proto_lv_le_card_outcome::Inner::Baroness(outcome)),
+                }),
+            }
+        }
+    }
+
+    impl From<(ProtoDowagerQueenSelection, ProtoBaronOutcome)> for ProtoLvLeCommittedPlay {
+        fn from((selection, outcome): (ProtoDowagerQueenSelection, ProtoBaronOutcome)) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: Some(ProtoLvLeCardSelection {
+                    inner: Some(proto_lv_le_card_selection::Inner::DowagerQueen(selection)),
+                }),
+                outcome: Some(ProtoLvLeCardOutcome {
+                    inner: Some(proto_lv_le_card_outcome::Inner::DowagerQueen(outcome)),
+                }),
+            }
+        }
+    }
+
+    impl From<(ProtoBishopSelection, ProtoBishopOutcome)> for ProtoLvLeCommittedPlay {
+        fn from((selection, outcome): (ProtoBishopSelection, ProtoBishopOutcome)) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: Some(ProtoLvLeCardSelection {
+                    inner: Some(proto_lv_le_card_selection::Inner::Bishop(selection)),
+                }),
+                outcome: Some(ProtoLvLeCardOutcome {
+                    inner: Some(proto_lv_le_card_outcome::Inner::Bishop(outcome)),
+                }),
+            }
+        }
+    }
+
+    impl From<ProtoJesterSelection> for ProtoLvLeCommittedPlay {
+        fn from(selection: ProtoJesterSelection) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: Some(ProtoLvLeCardSelection {
+                    inner: Some(proto_lv_le_card_selection::Inner::Jester(selection)),
+                }),
+                outcome: None,
+            }
+        }
+    }
+
+    impl From<ProtoSycophantSelection> for ProtoLvLeCommittedPlay {
+        fn from(selection: ProtoSycophantSelection) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: Some(ProtoLvLeCardSelection {
+                    inner: Some(proto_lv_le_card_selection::Inner::Sycophant(selection)),
+                }),
+                outcome: None,
+            }
+        }
+    }
+
+    impl From<ProtoNoEffectOutcome> for ProtoLvLeCommittedPlay {
+        fn from(outcome: ProtoNoEffectOutcome) -> Self {
+            ProtoLvLeCommittedPlay {
+                selection: None,
+                outcome: Some(ProtoLvLeCardOutcome {
+                    inner: Some(proto_lv_le_card_outcome::Inner::NoEffect(outcome)),
+                }),
+            }
+        }
+    }
+
     impl ProtoLvLeCommittedPlay {
         pub fn empty() -> Self {
             ProtoLvLeCommittedPlay {
@@ -191,4 +367,17 @@ mod normal_converters {
             }
         }
     }
+
+    impl ProtoPlayerPresence {
+        pub fn new(player_id: String, disconnected_since: Option<SystemTime>) -> Self {
+            ProtoPlayerPresence {
+                bot: bot::is_bot_player_id(&player_id),
+                player_id,
+                connected: disconnected_since.is_none(),
+                disconnected_since: disconnected_since
+                    .and_then(|since| since.duration_since(UNIX_EPOCH).ok())
+                    .map_or(0, |since_epoch| since_epoch.as_millis() as u64),
+            }
+        }
+    }
 }
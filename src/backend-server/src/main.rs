@@ -1,14 +1,35 @@
 use std::net::SocketAddr;
 use tonic::transport::Server;
+use backend_engine::{FileGameStore, GameRegistry, GameStore};
 use backend_engine::grpc_server::frj_server::FrjServer;
 use backend_framework::wire_api::proto_frj_ngn::proto_fridge_game_engine_server::ProtoFridgeGameEngineServer;
+use lost_cities_backend::plugin::LostCitiesPlugin;
+use love_letter_backend::plugin::LoveLetterPlugin;
+use mastermind_backend::plugin::MastermindPlugin;
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli_args = cli::CliArgs::parse();
 
     println!("INFO: Starting local server and dependencies.");
-    let frj_server = FrjServer::start()?;
+    let registry = GameRegistry::new()
+        .register(Arc::new(LoveLetterPlugin))
+        .register(Arc::new(LostCitiesPlugin))
+        .register(Arc::new(MastermindPlugin));
+    let repo_slot_count = cli_args.repo_slots.unwrap_or_else(FrjServer::default_repo_slot_count);
+    println!("INFO: Using {} game repository slots.", repo_slot_count);
+    let store = match cli_args.store_dir {
+        Some(store_dir) => {
+            println!("INFO: Saving in-progress games to '{}'.", store_dir);
+            Some(Arc::new(FileGameStore::new(store_dir)?) as Arc<dyn GameStore>)
+        },
+        None => {
+            println!("INFO: No game store directory given, in-progress games will be lost on restart.");
+            None
+        },
+    };
+    let frj_server = FrjServer::start(registry, repo_slot_count, store)?;
 
     let socket_address: SocketAddr = format!("[::]:{}", cli_args.port)
         .parse()
@@ -30,6 +51,8 @@ mod cli {
 
     pub struct CliArgs {
         pub port: u16,
+        pub repo_slots: Option<usize>,
+        pub store_dir: Option<String>,
     }
 
     impl CliArgs {
@@ -52,15 +75,29 @@ mod cli {
                     DEFAULT_PORT
                 });
 
+            // Arg 2
+            let repo_slots = cli_args.next()
+                .map(|slots_str| match slots_str.parse() {
+                    Ok(slots) if slots > 0 => slots,
+                    _ => CliArgs::print_usage_exit(&program_name),
+                });
+
+            // Arg 3
+            let store_dir = cli_args.next();
+
             CliArgs {
                 port,
+                repo_slots,
+                store_dir,
             }
         }
 
         fn print_usage_exit(program_name: &str) -> ! {
             eprintln!();
-            eprintln!("Usage:  \t{} <server port>", program_name);
+            eprintln!("Usage:  \t{} <server port> [game repository slots] [game store directory]", program_name);
             eprintln!("Example:\t{} 3000", program_name);
+            eprintln!("Example:\t{} 3000 8", program_name);
+            eprintln!("Example:\t{} 3000 8 ./saved-games", program_name);
             eprintln!();
             process::exit(1);
         }
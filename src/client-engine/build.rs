@@ -9,9 +9,9 @@ const HASH_FILE: &str = "./.proto_hash";
 /// the code that's generated doesn't show up in my IDE (which hinders my speed of
 /// learning).
 ///
-/// This build script automatically detects changes in the .proto file and only
-/// re-generates the src code if .proto changes. This won't work for all cases,
-/// e.g. if we change our tonic version without changing the .proto file.
+/// This build script automatically detects changes in the .proto files and only
+/// re-generates the src code if any .proto file changes. This won't work for all cases,
+/// e.g. if we change our tonic version without changing any .proto file.
 ///
 /// To force a clean generation of protobuf code, delete the `.proto_hash` file.
 fn main() -> io::Result<()> {
@@ -1,7 +1,11 @@
 pub mod wrapper {
-    use crate::wire_api::proto_frj_ngn::{ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq, ProtoStartGameReply, ProtoPreGameMessage, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut};
+    use crate::wire_api::proto_frj_ngn::{ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq, ProtoStartGameReply, ProtoPreGameMessage, ProtoGameDataHandshake, ProtoGameDataIn, ProtoGameDataOut};
+    use crate::wire_api::proto_frj_ngn::{ProtoLeaveGameReq, ProtoLeaveGameReply, ProtoKickPlayerReq, ProtoKickPlayerReply, ProtoAddBotReq, ProtoAddBotReply, ProtoUpdateGameOptionsReq, ProtoUpdateGameOptionsReply};
+    use crate::wire_api::proto_frj_ngn::proto_game_data_in::Inner;
     use crate::wire_api::proto_frj_ngn::proto_fridge_game_engine_client::ProtoFridgeGameEngineClient;
     use std::error::Error;
+    use std::pin::Pin;
+    use tokio::stream::{self, Stream, StreamExt};
     use tokio::sync::mpsc;
     use tonic::transport::{Channel, Endpoint};
     use tonic::{Status, Streaming};
@@ -11,7 +15,10 @@ pub mod wrapper {
         inner_client: ProtoFridgeGameEngineClient<Channel>
     }
 
-    type DataStream<I, O> = (mpsc::UnboundedSender<I>, Streaming<O>);
+    /// Incoming half of a game's data stream, with each payload already decoded.
+    pub type DataStreamRecv<O> = Pin<Box<dyn Stream<Item = Result<O, Status>> + Send>>;
+
+    type DataStream<I, O> = (mpsc::UnboundedSender<I>, DataStreamRecv<O>);
 
     impl GameClient {
 
@@ -49,13 +56,74 @@ pub mod wrapper {
                 .map(|response| response.into_inner())
         }
 
-        pub async fn open_love_letter_stream(&mut self) -> Result<DataStream<ProtoLoveLetterDataIn, ProtoLoveLetterDataOut>, Status> {
+        pub async fn leave_game(&mut self, req: ProtoLeaveGameReq) -> Result<ProtoLeaveGameReply, Status> {
+            self.inner_client
+                .leave_game(req)
+                .await
+                .map(|response| response.into_inner())
+        }
+
+        pub async fn kick_player(&mut self, req: ProtoKickPlayerReq) -> Result<ProtoKickPlayerReply, Status> {
+            self.inner_client
+                .kick_player(req)
+                .await
+                .map(|response| response.into_inner())
+        }
+
+        pub async fn add_bot(&mut self, req: ProtoAddBotReq) -> Result<ProtoAddBotReply, Status> {
+            self.inner_client
+                .add_bot(req)
+                .await
+                .map(|response| response.into_inner())
+        }
+
+        pub async fn update_game_options(&mut self, req: ProtoUpdateGameOptionsReq) -> Result<ProtoUpdateGameOptionsReply, Status> {
+            self.inner_client
+                .update_game_options(req)
+                .await
+                .map(|response| response.into_inner())
+        }
+
+        /// Open the data stream for the game named in `handshake`. `I` and `O` must be that
+        /// game's data stream message types.
+        pub async fn open_game_data_stream<I, O>(&mut self, handshake: ProtoGameDataHandshake) -> Result<DataStream<I, O>, Status>
+            where
+                I: prost::Message + Send + Sync + 'static,
+                O: prost::Message + Default + Send + 'static,
+        {
             let (snd, rcv) = mpsc::unbounded_channel();
 
+            let handshake = ProtoGameDataIn {
+                clock: 0,
+                inner: Some(Inner::Handshake(handshake)),
+            };
+            let stream_out = stream::once(handshake)
+                .chain(rcv.map(encode_payload::<I>));
+
             self.inner_client
-                .open_love_letter_data_stream(rcv)
+                .open_game_data_stream(stream_out)
                 .await
-                .map(|response| (snd, response.into_inner()))
+                .map(|response| {
+                    let stream_in: DataStreamRecv<O> = Box::pin(response.into_inner().map(decode_payload::<O>));
+                    (snd, stream_in)
+                })
         }
     }
-}
\ No newline at end of file
+
+    fn encode_payload<I: prost::Message>(message: I) -> ProtoGameDataIn {
+        let mut payload = Vec::with_capacity(message.encoded_len());
+        message.encode(&mut payload).expect("Vec has enough capacity for the encoded message");
+
+        ProtoGameDataIn {
+            clock: 0,
+            inner: Some(Inner::Payload(payload)),
+        }
+    }
+
+    fn decode_payload<O: prost::Message + Default>(result: Result<ProtoGameDataOut, Status>) -> Result<O, Status> {
+        result.and_then(|message| {
+            O::decode(message.payload.as_slice())
+                .map_err(|e| Status::internal(format!("Server sent an undecodable payload: {}", e)))
+        })
+    }
+}
@@ -3,10 +3,20 @@
 pub struct ProtoGameDataHandshake {
     #[prost(string, tag = "1")]
     pub player_id: std::string::String,
-    /// Game type not needed, since it can be inferred from the stream
-    /// type that the message is contained in.
     #[prost(string, tag = "2")]
     pub game_id: std::string::String,
+    /// All games share one data stream RPC, so the server uses this to
+    /// decide which game's payloads the stream carries.
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+    /// Watch the game instead of playing it. `player_id` doesn't need to be a player in the game.
+    /// Spectators only receive public information, and the server ignores their messages.
+    #[prost(bool, tag = "4")]
+    pub spectator: bool,
+    /// Only for the host's spectator stream, in games which support it. Reveals hidden
+    /// information once it can no longer affect the game.
+    #[prost(bool, tag = "5")]
+    pub god_view: bool,
 }
 /// Empty: This means "send me the latest state for the game stream I have opened".
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -14,6 +24,22 @@ pub struct ProtoGameDataStateReq {}
 /// Empty: Player clicks "ready" button on various screen.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoGameDataReadyUpClick {}
+/// Whether a player's stream to the server is open. Games include one for each player in their
+/// game state, and the pre-game stream sends them whenever a player disconnects or reconnects.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoPlayerPresence {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(bool, tag = "2")]
+    pub connected: bool,
+    /// Milliseconds since the Unix epoch, or 0 if connected. A player that hasn't opened their
+    /// stream yet is disconnected since the game started.
+    #[prost(uint64, tag = "3")]
+    pub disconnected_since: u64,
+    /// Bots are played by the server, so they're always connected.
+    #[prost(bool, tag = "4")]
+    pub bot: bool,
+}
 // ======================================================
 // Common types needed for all games.
 // ======================================================
@@ -24,6 +50,662 @@ pub enum ProtoGameType {
     UnspecifiedGameType = 0,
     LoveLetter = 1,
     LostCities = 2,
+    Mastermind = 3,
+}
+// ======================================================
+// API Request and Reply messages for Pre-game RPCs
+// ======================================================
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHostGameReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+    /// Optional. Seeds all randomness in the game (e.g. shuffling), so a game can be reproduced.
+    /// If 0, the server picks a random seed.
+    #[prost(uint64, tag = "4")]
+    pub rng_seed: u64,
+    /// Optional. The options message of the game named in `game_type`, in the protobuf binary
+    /// encoding. Like `ProtoGameDataIn.payload`, it's bytes rather than a oneof, so the engine
+    /// doesn't depend on each game's messages:
+    ///   LOVE_LETTER: `ProtoLvLeOptions` in love_letter.proto
+    ///   MASTERMIND: `ProtoMmOptions` in mastermind.proto
+    ///   LOST_CITIES: has no options, so this must be empty
+    /// If empty, the game's default options are used. Options which don't decode, or which the
+    /// game doesn't allow, fail the call with INVALID_ARGUMENT.
+    #[prost(bytes, tag = "5")]
+    pub game_options: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoJoinGameReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+}
+/// Stream message type
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoPreGameMessage {
+    #[prost(oneof = "proto_pre_game_message::Inner", tags = "1, 2, 3, 4, 5, 6")]
+    pub inner: ::std::option::Option<proto_pre_game_message::Inner>,
+}
+pub mod proto_pre_game_message {
+    /// Initial response in PreGame stream
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoJoinGameAck {
+        #[prost(enumeration = "super::ProtoGameType", tag = "1")]
+        pub game_type: i32,
+        #[prost(string, tag = "2")]
+        pub host_player_id: std::string::String,
+        #[prost(string, repeated, tag = "3")]
+        pub other_player_ids: ::std::vec::Vec<std::string::String>,
+        /// Every joined player, in join order. Empty if the game already started, in which case
+        /// the game state has it.
+        #[prost(message, repeated, tag = "4")]
+        pub presence: ::std::vec::Vec<super::ProtoPlayerPresence>,
+        /// The options chosen by the host, encoded as in `ProtoHostGameReq.game_options`. Empty
+        /// if the host didn't pick any, or if the game already started.
+        #[prost(bytes, tag = "5")]
+        pub game_options: std::vec::Vec<u8>,
+    }
+    /// N intermediate messages received in PreGame stream
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoPlayerJoinMsg {
+        #[prost(string, tag = "1")]
+        pub player_id: std::string::String,
+    }
+    /// N intermediate messages received in PreGame stream, each time a joined player
+    /// disconnects or reconnects.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoPlayerPresenceMsg {
+        /// Every joined player, in join order.
+        #[prost(message, repeated, tag = "1")]
+        pub players: ::std::vec::Vec<super::ProtoPlayerPresence>,
+    }
+    /// N intermediate messages received in PreGame stream, each time a player leaves or is kicked.
+    /// Also the terminal message of the stream of the player who left.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoPlayerLeaveMsg {
+        #[prost(string, tag = "1")]
+        pub player_id: std::string::String,
+        /// True if the host kicked the player, rather than the player leaving.
+        #[prost(bool, tag = "2")]
+        pub kicked: bool,
+        /// The host once the player is gone. Changes to the next player in join order if the
+        /// host left.
+        #[prost(string, tag = "3")]
+        pub host_player_id: std::string::String,
+    }
+    /// N intermediate messages received in PreGame stream, each time the host changes the
+    /// game options.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoGameOptionsMsg {
+        /// Encoded as in `ProtoHostGameReq.game_options`.
+        #[prost(bytes, tag = "1")]
+        pub game_options: std::vec::Vec<u8>,
+    }
+    /// Terminal message received in PreGame stream
+    ///
+    /// Empty
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoGameStartMsg {}
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Inner {
+        #[prost(message, tag = "1")]
+        JoinGameAck(ProtoJoinGameAck),
+        #[prost(message, tag = "2")]
+        PlayerJoinMsg(ProtoPlayerJoinMsg),
+        #[prost(message, tag = "3")]
+        GameStartMsg(ProtoGameStartMsg),
+        #[prost(message, tag = "4")]
+        PlayerPresenceMsg(ProtoPlayerPresenceMsg),
+        #[prost(message, tag = "5")]
+        PlayerLeaveMsg(ProtoPlayerLeaveMsg),
+        #[prost(message, tag = "6")]
+        GameOptionsMsg(ProtoGameOptionsMsg),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoStartGameReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoStartGameReply {
+    #[prost(string, repeated, tag = "1")]
+    pub player_ids: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLeaveGameReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+}
+/// Empty
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLeaveGameReply {}
+/// Only the host can kick players.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoKickPlayerReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+    #[prost(string, tag = "4")]
+    pub kicked_player_id: std::string::String,
+}
+/// Empty
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoKickPlayerReply {}
+/// Only the host can add bots, and only to games that support them. The bot takes a seat like any
+/// other player, and is played by the server once the game starts. Kick the bot to remove it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoAddBotReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoAddBotReply {
+    /// Bot player IDs start with "bot:", which is reserved for bots.
+    #[prost(string, tag = "1")]
+    pub bot_player_id: std::string::String,
+}
+/// Only the host can change the game options, and only before the game starts.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoUpdateGameOptionsReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameType", tag = "3")]
+    pub game_type: i32,
+    /// Replaces all of the game options. Encoded, and validated, as in
+    /// `ProtoHostGameReq.game_options`.
+    #[prost(bytes, tag = "4")]
+    pub game_options: std::vec::Vec<u8>,
+}
+/// Empty
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoUpdateGameOptionsReply {}
+// ======================================================
+// API Request and Reply messages for In-game RPCs
+// ======================================================
+
+/// Envelope for every client -> server message on a game's data stream.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGameDataIn {
+    /// Logical clock for this game instance, provided for OCC if game needs it.
+    #[prost(uint64, tag = "1")]
+    pub clock: u64,
+    #[prost(oneof = "proto_game_data_in::Inner", tags = "2, 3")]
+    pub inner: ::std::option::Option<proto_game_data_in::Inner>,
+}
+pub mod proto_game_data_in {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Inner {
+        /// Must be the first message on the stream, and only sent once.
+        #[prost(message, tag = "2")]
+        Handshake(super::ProtoGameDataHandshake),
+        /// Encoded `Proto<Game>DataIn` message of the game named in the handshake.
+        #[prost(bytes, tag = "3")]
+        Payload(std::vec::Vec<u8>),
+    }
+}
+/// Envelope for every server -> client message on a game's data stream.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGameDataOut {
+    /// Logical clock for this game instance, provided for OCC if game needs it.
+    #[prost(uint64, tag = "1")]
+    pub clock: u64,
+    /// Encoded `Proto<Game>DataOut` message of the game named in the handshake.
+    #[prost(bytes, tag = "2")]
+    pub payload: std::vec::Vec<u8>,
+}
+#[doc = r" Generated client implementations."]
+pub mod proto_fridge_game_engine_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct ProtoFridgeGameEngineClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ProtoFridgeGameEngineClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ProtoFridgeGameEngineClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        pub async fn host_game(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoHostGameReq>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ProtoPreGameMessage>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/HostGame",
+            );
+            self.inner
+                .server_streaming(request.into_request(), path, codec)
+                .await
+        }
+        pub async fn join_game(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoJoinGameReq>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ProtoPreGameMessage>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/JoinGame",
+            );
+            self.inner
+                .server_streaming(request.into_request(), path, codec)
+                .await
+        }
+        pub async fn start_game(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoStartGameReq>,
+        ) -> Result<tonic::Response<super::ProtoStartGameReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/StartGame",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn leave_game(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoLeaveGameReq>,
+        ) -> Result<tonic::Response<super::ProtoLeaveGameReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/LeaveGame",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn kick_player(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoKickPlayerReq>,
+        ) -> Result<tonic::Response<super::ProtoKickPlayerReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/KickPlayer",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn add_bot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoAddBotReq>,
+        ) -> Result<tonic::Response<super::ProtoAddBotReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/proto_frj_ngn.ProtoFridgeGameEngine/AddBot");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn update_game_options(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoUpdateGameOptionsReq>,
+        ) -> Result<tonic::Response<super::ProtoUpdateGameOptionsReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/UpdateGameOptions",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn open_game_data_stream(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::ProtoGameDataIn>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::ProtoGameDataOut>>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_frj_ngn.ProtoFridgeGameEngine/OpenGameDataStream",
+            );
+            self.inner
+                .streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+    }
+    impl<T: Clone> Clone for ProtoFridgeGameEngineClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ProtoFridgeGameEngineClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ProtoFridgeGameEngineClient {{ ... }}")
+        }
+    }
+} // =======================================
+  // Data Stream Messages
+  // =======================================
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLostCitiesDataIn {
+    /// Logical clock for this game instance, provided for OCC if game needs it.
+    #[prost(uint64, tag = "1")]
+    pub clock: u64,
+    /// The actual message
+    #[prost(oneof = "proto_lost_cities_data_in::ProtoLoCiIn", tags = "3, 4, 5, 6")]
+    pub proto_lo_ci_in: ::std::option::Option<proto_lost_cities_data_in::ProtoLoCiIn>,
+}
+pub mod proto_lost_cities_data_in {
+    /// The actual message
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ProtoLoCiIn {
+        /// 2 was the handshake, which now lives in the ProtoGameDataIn envelope.
+        #[prost(message, tag = "3")]
+        GameState(super::ProtoGameDataStateReq),
+        #[prost(message, tag = "4")]
+        PlayCard(super::ProtoLoCiPlayCardReq),
+        #[prost(message, tag = "5")]
+        DrawCard(super::ProtoLoCiDrawCardReq),
+        #[prost(message, tag = "6")]
+        ReadyUp(super::ProtoGameDataReadyUpClick),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLostCitiesDataOut {
+    /// Logical clock for this game instance, provided for OCC if game needs it.
+    #[prost(uint64, tag = "1")]
+    pub clock: u64,
+    /// The actual message
+    #[prost(oneof = "proto_lost_cities_data_out::ProtoLoCiOut", tags = "2")]
+    pub proto_lo_ci_out: ::std::option::Option<proto_lost_cities_data_out::ProtoLoCiOut>,
+}
+pub mod proto_lost_cities_data_out {
+    /// The actual message
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ProtoLoCiOut {
+        #[prost(message, tag = "2")]
+        GameState(super::ProtoLoCiGameState),
+    }
+}
+// =======================================
+// API Request and Reply messages
+// =======================================
+
+// --- GameState
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLoCiGameState {
+    #[prost(message, repeated, tag = "1")]
+    pub players: ::std::vec::Vec<proto_lo_ci_game_state::ProtoLoCiPlayer>,
+    /// 1-indexed
+    #[prost(uint32, tag = "2")]
+    pub round_number: u32,
+    #[prost(uint32, tag = "3")]
+    pub total_rounds: u32,
+    #[prost(message, repeated, tag = "7")]
+    pub presence: ::std::vec::Vec<ProtoPlayerPresence>,
+    #[prost(oneof = "proto_lo_ci_game_state::Stage", tags = "4, 5, 6")]
+    pub stage: ::std::option::Option<proto_lo_ci_game_state::Stage>,
+}
+pub mod proto_lo_ci_game_state {
+    // -- nested message types
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLoCiPlayer {
+        #[prost(string, tag = "1")]
+        pub player_id: std::string::String,
+        /// Sum of the player's score from all completed rounds.
+        #[prost(sint32, tag = "2")]
+        pub total_score: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLoCiRoundState {
+        #[prost(message, repeated, tag = "1")]
+        pub my_hand: ::std::vec::Vec<super::ProtoLoCiCard>,
+        #[prost(message, repeated, tag = "2")]
+        pub my_expeditions: ::std::vec::Vec<super::ProtoLoCiExpedition>,
+        #[prost(message, repeated, tag = "3")]
+        pub opponent_expeditions: ::std::vec::Vec<super::ProtoLoCiExpedition>,
+        /// Head => bottom of pile, Tail => top of pile
+        #[prost(message, repeated, tag = "4")]
+        pub discard_piles: ::std::vec::Vec<super::ProtoLoCiDiscardPile>,
+        #[prost(uint32, tag = "5")]
+        pub draw_pile_size: u32,
+        #[prost(string, tag = "6")]
+        pub current_turn_player_id: std::string::String,
+        #[prost(enumeration = "ProtoLoCiTurnPhase", tag = "7")]
+        pub turn_phase: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLoCiResultState {
+        /// Score from the most recently completed round.
+        #[prost(map = "string, sint32", tag = "1")]
+        pub round_scores: ::std::collections::HashMap<std::string::String, i32>,
+        #[prost(string, repeated, tag = "2")]
+        pub unready_player_ids: ::std::vec::Vec<std::string::String>,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ProtoLoCiTurnPhase {
+        UnspecifiedTurnPhase = 0,
+        PlayCard = 1,
+        DrawCard = 2,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Stage {
+        #[prost(message, tag = "4")]
+        RoundInProgress(ProtoLoCiRoundState),
+        #[prost(message, tag = "5")]
+        RoundIntermission(ProtoLoCiResultState),
+        #[prost(message, tag = "6")]
+        GameComplete(ProtoLoCiResultState),
+    }
+}
+// --- PlayCard
+
+/// Req: First action taken during a turn
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLoCiPlayCardReq {
+    #[prost(message, optional, tag = "1")]
+    pub card: ::std::option::Option<ProtoLoCiCard>,
+    #[prost(
+        enumeration = "proto_lo_ci_play_card_req::ProtoLoCiPlayTarget",
+        tag = "2"
+    )]
+    pub target: i32,
+}
+pub mod proto_lo_ci_play_card_req {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ProtoLoCiPlayTarget {
+        UnspecifiedPlayTarget = 0,
+        Expedition = 1,
+        DiscardPile = 2,
+    }
+}
+// --- DrawCard
+
+/// Req: Second (and final) action taken during a turn
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLoCiDrawCardReq {
+    #[prost(oneof = "proto_lo_ci_draw_card_req::Source", tags = "1, 2")]
+    pub source: ::std::option::Option<proto_lo_ci_draw_card_req::Source>,
+}
+pub mod proto_lo_ci_draw_card_req {
+    /// Empty
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLoCiDrawPile {}
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        #[prost(message, tag = "1")]
+        DrawPile(ProtoLoCiDrawPile),
+        /// The color of the discard pile to draw from.
+        #[prost(enumeration = "super::ProtoLoCiColor", tag = "2")]
+        DiscardPile(i32),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLoCiCard {
+    #[prost(enumeration = "ProtoLoCiColor", tag = "1")]
+    pub color: i32,
+    /// 0 => wager card, 2-10 => number card
+    #[prost(uint32, tag = "2")]
+    pub value: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLoCiExpedition {
+    #[prost(enumeration = "ProtoLoCiColor", tag = "1")]
+    pub color: i32,
+    /// Head => first played. 0 => wager card, 2-10 => number card
+    #[prost(uint32, repeated, tag = "2")]
+    pub values: ::std::vec::Vec<u32>,
+    /// Score of this expedition if the round ended right now.
+    #[prost(sint32, tag = "3")]
+    pub score: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLoCiDiscardPile {
+    #[prost(enumeration = "ProtoLoCiColor", tag = "1")]
+    pub color: i32,
+    /// Head => bottom of pile, Tail => top of pile. 0 => wager card, 2-10 => number card
+    #[prost(uint32, repeated, tag = "2")]
+    pub values: ::std::vec::Vec<u32>,
+}
+// =======================================
+// Common sub types
+// =======================================
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoLoCiColor {
+    UnspecifiedColor = 0,
+    Yellow = 1,
+    Blue = 2,
+    White = 3,
+    Green = 4,
+    Red = 5,
+}
+// =======================================
+// Game Options
+// =======================================
+
+/// Sent encoded in `ProtoHostGameReq.game_options` and `ProtoUpdateGameOptionsReq.game_options`
+/// for LOVE_LETTER games.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLvLeOptions {
+    /// Number of round wins needed to win the game. If 0, the official count for the
+    /// number of players is used: 7, 5, or 4 wins for 2, 3, or 4 players.
+    #[prost(uint32, tag = "1")]
+    pub win_threshold: u32,
+    /// If unspecified, the classic deck is used.
+    #[prost(enumeration = "proto_lv_le_options::ProtoLvLeDeckVariant", tag = "2")]
+    pub deck_variant: i32,
+    /// Seconds the current player has to play their turn, before the lowest legal card is
+    /// played for them. If 0, there's no time limit.
+    #[prost(uint32, tag = "3")]
+    pub turn_timeout_secs: u32,
+    /// Seconds players have to ready up between turns and rounds, before they're readied up
+    /// automatically. If 0, there's no time limit.
+    #[prost(uint32, tag = "4")]
+    pub ready_up_timeout_secs: u32,
+}
+pub mod proto_lv_le_options {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ProtoLvLeDeckVariant {
+        UnspecifiedDeckVariant = 0,
+        /// 16 cards, 2-4 players.
+        Classic = 1,
+        /// 21 cards, 2-6 players. Adds the Spy and Chancellor, and renumbers the King(7),
+        /// Countess(8) and Princess(9).
+        Revised2019 = 2,
+        /// 32 cards, 2-8 players. Adds the Jester, Assassin, Cardinal, Baroness, Sycophant, Count,
+        /// Constable, Dowager Queen and Bishop.
+        Premium = 3,
+    }
 }
 // =======================================
 // Data Stream Messages
@@ -37,7 +719,7 @@ pub struct ProtoLoveLetterDataIn {
     /// The actual message
     #[prost(
         oneof = "proto_love_letter_data_in::ProtoLvLeIn",
-        tags = "2, 3, 4, 5, 6, 7, 8"
+        tags = "3, 4, 5, 6, 7, 8"
     )]
     pub proto_lv_le_in: ::std::option::Option<proto_love_letter_data_in::ProtoLvLeIn>,
 }
@@ -45,8 +727,7 @@ pub mod proto_love_letter_data_in {
     /// The actual message
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum ProtoLvLeIn {
-        #[prost(message, tag = "2")]
-        Handshake(super::ProtoGameDataHandshake),
+        /// 2 was the handshake, which now lives in the ProtoGameDataIn envelope.
         #[prost(message, tag = "3")]
         GameState(super::ProtoGameDataStateReq),
         #[prost(message, tag = "4")]
@@ -107,7 +788,19 @@ pub struct ProtoLvLeGameState {
     pub clock: u64,
     #[prost(message, repeated, tag = "2")]
     pub players: ::std::vec::Vec<proto_lv_le_game_state::ProtoLvLePlayer>,
-    #[prost(oneof = "proto_lv_le_game_state::Stage", tags = "3, 4")]
+    /// Number of round wins needed to win the game.
+    #[prost(uint32, tag = "6")]
+    pub win_threshold: u32,
+    #[prost(enumeration = "proto_lv_le_options::ProtoLvLeDeckVariant", tag = "7")]
+    pub deck_variant: i32,
+    /// When the current turn or ready up times out, in milliseconds since the Unix epoch. 0 if
+    /// there's no time limit.
+    #[prost(uint64, tag = "8")]
+    pub deadline: u64,
+    /// In turn order.
+    #[prost(message, repeated, tag = "9")]
+    pub presence: ::std::vec::Vec<ProtoPlayerPresence>,
+    #[prost(oneof = "proto_lv_le_game_state::Stage", tags = "3, 4, 5")]
     pub stage: ::std::option::Option<proto_lv_le_game_state::Stage>,
 }
 pub mod proto_lv_le_game_state {
@@ -136,6 +829,19 @@ pub mod proto_lv_le_game_state {
         /// API model must closely resemble the backend state (yuck!).
         #[prost(string, repeated, tag = "9")]
         pub handmaid_player_ids: ::std::vec::Vec<std::string::String>,
+        /// The next card that's played must target this player, if it targets anyone.
+        #[prost(string, tag = "10")]
+        pub sycophant_player_id: std::string::String,
+        /// Jester player ID => the player they think will win the round.
+        #[prost(map = "string, string", tag = "11")]
+        pub jester_target_player_ids:
+            ::std::collections::HashMap<std::string::String, std::string::String>,
+        /// Cards discarded by each player this round, in order. Includes players who are out.
+        #[prost(map = "string, message", tag = "12")]
+        pub discards: ::std::collections::HashMap<std::string::String, ProtoLvLeDiscards>,
+        /// Set aside at the start of 2 player rounds, visible to everyone.
+        #[prost(enumeration = "super::ProtoLvLeCard", repeated, tag = "13")]
+        pub face_up_cards: ::std::vec::Vec<i32>,
         #[prost(oneof = "proto_lv_le_round_state::Turn", tags = "3, 4, 5")]
         pub turn: ::std::option::Option<proto_lv_le_round_state::Turn>,
     }
@@ -151,6 +857,11 @@ pub mod proto_lv_le_game_state {
         }
     }
     #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLvLeDiscards {
+        #[prost(enumeration = "super::ProtoLvLeCard", repeated, tag = "1")]
+        pub cards: ::std::vec::Vec<i32>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ProtoLvLeTurnIntermissionState {
         #[prost(string, repeated, tag = "1")]
         pub unready_player_ids: ::std::vec::Vec<std::string::String>,
@@ -162,6 +873,60 @@ pub mod proto_lv_le_game_state {
         pub final_cards: ::std::collections::HashMap<std::string::String, i32>,
         #[prost(string, repeated, tag = "2")]
         pub unready_player_ids: ::std::vec::Vec<std::string::String>,
+        /// Only sent to the host's god view spectator stream.
+        #[prost(message, optional, tag = "3")]
+        pub opt_hidden_cards: ::std::option::Option<ProtoLvLeHiddenCards>,
+        /// Players who won a token for winning the round. More than one if the tiebreak is tied.
+        /// Tokens from the Jester and Spy aren't included.
+        #[prost(string, repeated, tag = "4")]
+        pub winner_player_ids: ::std::vec::Vec<std::string::String>,
+        #[prost(
+            enumeration = "proto_lv_le_result_state::ProtoLvLeRoundEndReason",
+            tag = "5"
+        )]
+        pub end_reason: i32,
+        /// Total value of each remaining player's discards, which breaks ties in final cards.
+        #[prost(map = "string, uint32", tag = "6")]
+        pub discard_totals: ::std::collections::HashMap<std::string::String, u32>,
+    }
+    pub mod proto_lv_le_result_state {
+        #[derive(
+            Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+        )]
+        #[repr(i32)]
+        pub enum ProtoLvLeRoundEndReason {
+            UnspecifiedRoundEndReason = 0,
+            /// Everyone else was knocked out.
+            LastPlayerStanding = 1,
+            /// The deck ran out, and the highest card won.
+            DeckExhausted = 2,
+            /// The deck ran out, and the highest cards were tied. The highest discard total won.
+            Tiebreak = 3,
+            /// A player reached the win threshold mid-round (e.g. with the Bishop or Constable).
+            /// Nobody won the round.
+            WinThresholdReached = 4,
+        }
+    }
+    /// The cards which nobody saw during the round.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLvLeHiddenCards {
+        #[prost(enumeration = "super::ProtoLvLeCard", tag = "1")]
+        pub opt_burn_card: i32,
+        /// The next card to be drawn is last.
+        #[prost(enumeration = "super::ProtoLvLeCard", repeated, tag = "2")]
+        pub undrawn_cards: ::std::vec::Vec<i32>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoLvLeGameCompleteState {
+        /// Result of the final round. Nobody needs to ready up.
+        #[prost(message, optional, tag = "1")]
+        pub final_round: ::std::option::Option<ProtoLvLeResultState>,
+        /// All players, most round wins first. Players with equal wins are in turn order.
+        #[prost(message, repeated, tag = "2")]
+        pub final_standings: ::std::vec::Vec<ProtoLvLePlayer>,
+        /// Players who reached the win threshold with the most wins. More than one if tied.
+        #[prost(string, repeated, tag = "3")]
+        pub winner_player_ids: ::std::vec::Vec<std::string::String>,
     }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Stage {
@@ -169,6 +934,8 @@ pub mod proto_lv_le_game_state {
         RoundInProgress(ProtoLvLeRoundState),
         #[prost(message, tag = "4")]
         RoundIntermission(ProtoLvLeResultState),
+        #[prost(message, tag = "5")]
+        GameComplete(ProtoLvLeGameCompleteState),
     }
 }
 // --- TurnIndicator
@@ -244,6 +1011,41 @@ pub struct ProtoLvLeCommitSelectionRepl {
     #[prost(message, optional, tag = "1")]
     pub outcome: ::std::option::Option<ProtoLvLeCardOutcome>,
 }
+// --- Errors
+
+/// Sent as the details of a FAILED_PRECONDITION status, when a staged play breaks the rules.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLvLeIllegalPlay {
+    #[prost(
+        enumeration = "proto_lv_le_illegal_play::ProtoLvLeIllegalPlayReason",
+        tag = "1"
+    )]
+    pub reason: i32,
+}
+pub mod proto_lv_le_illegal_play {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ProtoLvLeIllegalPlayReason {
+        UnspecifiedIllegalPlayReason = 0,
+        NotYourTurn = 1,
+        /// Holding the Countess with the King or Prince, so the Countess must be played.
+        CountessMustBePlayed = 2,
+        CardHasNoTargetPlayer = 3,
+        TargetNotInRound = 4,
+        TargetIsSelf = 5,
+        TargetHasHandmaid = 6,
+        /// The Sycophant chose who the played card must target.
+        TargetNotSycophantChoice = 7,
+        TooManyTargets = 8,
+        CardHasNoTargetCard = 9,
+        CantGuessGuard = 10,
+        CardNotInDeck = 11,
+        /// The Chancellor's kept card must be in hand, or one of the drawn cards.
+        CardNotAvailable = 12,
+        /// Committed without selecting the played card's targets.
+        MissingSelection = 13,
+    }
+}
 /// Input selection for card:
 /// 1 - Guard    : `(String, Card)` - the player+card that is guessed
 /// 2 - Priest   : `(String)` - player to view card
@@ -253,9 +1055,13 @@ pub struct ProtoLvLeCommitSelectionRepl {
 /// 6 - King     : `(String)` - player to swap with
 /// 7 - Countess : `()`
 /// 8 - Princess : `()`
+/// The other editions' cards follow the same pattern, fields are numbered by `ProtoLvLeCard`.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoLvLeCardSelection {
-    #[prost(oneof = "proto_lv_le_card_selection::Inner", tags = "1, 2, 3, 5, 6")]
+    #[prost(
+        oneof = "proto_lv_le_card_selection::Inner",
+        tags = "1, 2, 3, 5, 6, 10, 11, 13, 14, 15, 18, 19"
+    )]
     pub inner: ::std::option::Option<proto_lv_le_card_selection::Inner>,
 }
 pub mod proto_lv_le_card_selection {
@@ -286,6 +1092,53 @@ pub mod proto_lv_le_card_selection {
         #[prost(string, tag = "1")]
         pub opt_player_id: std::string::String,
     }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoChancellorSelection {
+        /// Only sent to the player who played the Chancellor.
+        #[prost(enumeration = "super::ProtoLvLeCard", repeated, tag = "1")]
+        pub opt_drawn_cards: ::std::vec::Vec<i32>,
+        /// One of the drawn cards, or the player's card in hand.
+        #[prost(enumeration = "super::ProtoLvLeCard", tag = "2")]
+        pub opt_kept_card: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoJesterSelection {
+        #[prost(string, tag = "1")]
+        pub opt_player_id: std::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoCardinalSelection {
+        /// Selecting an already selected player un-selects them.
+        #[prost(string, tag = "1")]
+        pub opt_player_id: std::string::String,
+        #[prost(string, tag = "2")]
+        pub opt_second_player_id: std::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoBaronessSelection {
+        /// Selecting an already selected player un-selects them.
+        #[prost(string, tag = "1")]
+        pub opt_player_id: std::string::String,
+        #[prost(string, tag = "2")]
+        pub opt_second_player_id: std::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoSycophantSelection {
+        #[prost(string, tag = "1")]
+        pub opt_player_id: std::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoDowagerQueenSelection {
+        #[prost(string, tag = "1")]
+        pub opt_player_id: std::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoBishopSelection {
+        #[prost(string, tag = "1")]
+        pub opt_player_id: std::string::String,
+        #[prost(enumeration = "super::ProtoLvLeCard", tag = "2")]
+        pub opt_card: i32,
+    }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Inner {
         #[prost(message, tag = "1")]
@@ -298,6 +1151,20 @@ pub mod proto_lv_le_card_selection {
         Prince(ProtoPrinceSelection),
         #[prost(message, tag = "6")]
         King(ProtoKingSelection),
+        #[prost(message, tag = "10")]
+        Chancellor(ProtoChancellorSelection),
+        #[prost(message, tag = "11")]
+        Jester(ProtoJesterSelection),
+        #[prost(message, tag = "13")]
+        Cardinal(ProtoCardinalSelection),
+        #[prost(message, tag = "14")]
+        Baroness(ProtoBaronessSelection),
+        #[prost(message, tag = "15")]
+        Sycophant(ProtoSycophantSelection),
+        #[prost(message, tag = "18")]
+        DowagerQueen(ProtoDowagerQueenSelection),
+        #[prost(message, tag = "19")]
+        Bishop(ProtoBishopSelection),
     }
 }
 /// Publicly broadcasted data after playing a card:
@@ -311,7 +1178,10 @@ pub mod proto_lv_le_card_selection {
 /// 8 - Princess : `()`
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoLvLeCardOutcome {
-    #[prost(oneof = "proto_lv_le_card_outcome::Inner", tags = "1, 2, 3, 5")]
+    #[prost(
+        oneof = "proto_lv_le_card_outcome::Inner",
+        tags = "1, 2, 3, 5, 13, 14, 18, 19, 20"
+    )]
     pub inner: ::std::option::Option<proto_lv_le_card_outcome::Inner>,
 }
 pub mod proto_lv_le_card_outcome {
@@ -319,6 +1189,9 @@ pub mod proto_lv_le_card_outcome {
     pub struct ProtoGuardOutcome {
         #[prost(bool, tag = "1")]
         pub correct: bool,
+        /// The target had an Assassin, so the Guard player is out.
+        #[prost(bool, tag = "2")]
+        pub assassinated: bool,
     }
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ProtoPriestOutcome {
@@ -342,9 +1215,35 @@ pub mod proto_lv_le_card_outcome {
         }
     }
     #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct ProtoPrinceOutcome {
+    pub struct ProtoPrinceOutcome {
+        #[prost(enumeration = "super::ProtoLvLeCard", tag = "1")]
+        pub discarded_card: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoCardinalOutcome {
+        /// None => you are not allowed to see it
+        /// Some => the first selected player's new card
+        #[prost(enumeration = "super::ProtoLvLeCard", tag = "1")]
+        pub opt_viewed_card: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoBaronessOutcome {
+        /// Empty => you are not allowed to see them
+        #[prost(map = "string, enumeration(super::ProtoLvLeCard)", tag = "1")]
+        pub opt_viewed_cards: ::std::collections::HashMap<std::string::String, i32>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoBishopOutcome {
+        #[prost(bool, tag = "1")]
+        pub correct: bool,
+        /// Set if the guess was correct, which makes the target discard their card.
+        #[prost(enumeration = "super::ProtoLvLeCard", tag = "2")]
+        pub opt_discarded_card: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtoNoEffectOutcome {
         #[prost(enumeration = "super::ProtoLvLeCard", tag = "1")]
-        pub discarded_card: i32,
+        pub played_card: i32,
     }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Inner {
@@ -356,6 +1255,18 @@ pub mod proto_lv_le_card_outcome {
         Baron(ProtoBaronOutcome),
         #[prost(message, tag = "5")]
         Prince(ProtoPrinceOutcome),
+        #[prost(message, tag = "13")]
+        Cardinal(ProtoCardinalOutcome),
+        #[prost(message, tag = "14")]
+        Baroness(ProtoBaronessOutcome),
+        #[prost(message, tag = "18")]
+        DowagerQueen(ProtoBaronOutcome),
+        #[prost(message, tag = "19")]
+        Bishop(ProtoBishopOutcome),
+        /// Not a card. The played card had no valid target, because all other players were
+        /// protected by the Handmaid.
+        #[prost(message, tag = "20")]
+        NoEffect(ProtoNoEffectOutcome),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -369,6 +1280,7 @@ pub struct ProtoLvLeCommittedPlay {
 // Common sub types
 // =======================================
 
+/// The numbers in comments are the value of each card, which isn't the same as the enum value.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ProtoLvLeCard {
@@ -383,210 +1295,311 @@ pub enum ProtoLvLeCard {
     Handmaid = 4,
     /// Choose any player (including self) to discard their card and draw a new one.
     Prince = 5,
-    /// Trade hands with any other player.
+    /// Trade hands with any other player. 7 in the 2019 edition.
     King = 6,
-    /// Must be discarded if other card is King(6) or Prince(5).
+    /// Must be discarded if other card is King(6) or Prince(5). 8 in the 2019 edition.
     Countess = 7,
-    /// If this card is ever discarded, that player is out.
+    /// If this card is ever discarded, that player is out. 9 in the 2019 edition.
     Princess = 8,
+    /// 2019 edition
+    ///
+    /// 0 - At round end, if you're the only player left who discarded a Spy, gain a token.
+    Spy = 9,
+    /// 6 - Draw 2 cards, keep 1 of your 3 cards, put the other 2 on the bottom of the deck.
+    Chancellor = 10,
+    /// Premium edition
+    ///
+    /// 0 - Choose another player. If they win the round, you gain a token.
+    Jester = 11,
+    /// 0 - If a Guard targets you, that player is out instead. Discard this and draw a new card.
+    Assassin = 12,
+    /// 2 - Choose 2 players (including self) to trade hands. See the first player's new card.
+    Cardinal = 13,
+    /// 3 - See 1 or 2 other players' cards.
+    Baroness = 14,
+    /// 4 - Choose any player (including self). The next card played must target them.
+    Sycophant = 15,
+    /// 5 - At round end, adds 1 to the value of your card.
+    Count = 16,
+    /// 6 - If you're knocked out while this is in your discards, gain a token.
+    Constable = 17,
+    /// 7 - Privately compare card with another player. Higher card is out.
+    DowagerQueen = 18,
+    /// 9 - Guess another player's card, if correct, gain a token and they discard and draw. Loses to Princess(8).
+    Bishop = 19,
 }
-// ======================================================
-// API Request and Reply messages for Pre-game RPCs
-// ======================================================
+// =======================================
+// Game Options
+// =======================================
 
+/// Sent encoded in `ProtoHostGameReq.game_options` and `ProtoUpdateGameOptionsReq.game_options`
+/// for MASTERMIND games.
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProtoHostGameReq {
-    #[prost(string, tag = "1")]
-    pub player_id: std::string::String,
-    #[prost(string, tag = "2")]
-    pub game_id: std::string::String,
-    #[prost(enumeration = "ProtoGameType", tag = "3")]
-    pub game_type: i32,
+pub struct ProtoMmOptions {
+    /// Number of colors a peg can be, from 2 to 10. If 0, 8 colors are used.
+    #[prost(uint32, tag = "1")]
+    pub num_colors: u32,
+    /// Number of pegs in the password and in each guess, from 3 to 8. If 0, 4 pegs are used.
+    #[prost(uint32, tag = "2")]
+    pub num_pegs: u32,
+    /// Passwords can't use a color more than once. Guesses still can.
+    #[prost(bool, tag = "3")]
+    pub no_duplicate_colors: bool,
+    /// Pegs can be left blank in passwords and guesses. A blank is scored like any other color.
+    #[prost(bool, tag = "4")]
+    pub allow_blanks: bool,
+    /// Number of guesses a player gets before their board fails, from 1 to 20. If 0, 10 rows are used.
+    #[prost(uint32, tag = "5")]
+    pub max_rows: u32,
+    /// Number of rounds in the match, from 1 to 9. If 0, a single round is played. Each round, both
+    /// players create a password and guess their opponent's, so both play code-maker and
+    /// code-breaker every round. Only 2 player games can have more than 1 round.
+    #[prost(uint32, tag = "6")]
+    pub num_rounds: u32,
+    /// Every player guesses on a single shared board, against a password the server picks. Single
+    /// player games are always played this way, and it's required for more than 2 players.
+    #[prost(bool, tag = "7")]
+    pub cooperative: bool,
 }
+// =======================================
+// Data Stream Messages
+// =======================================
+
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProtoJoinGameReq {
-    #[prost(string, tag = "1")]
-    pub player_id: std::string::String,
-    #[prost(string, tag = "2")]
-    pub game_id: std::string::String,
-    #[prost(enumeration = "ProtoGameType", tag = "3")]
-    pub game_type: i32,
+pub struct ProtoMastermindDataIn {
+    /// Logical clock for this game instance, provided for OCC if game needs it.
+    #[prost(uint64, tag = "1")]
+    pub clock: u64,
+    /// The actual message
+    #[prost(oneof = "proto_mastermind_data_in::ProtoMmIn", tags = "3, 4, 5, 6")]
+    pub proto_mm_in: ::std::option::Option<proto_mastermind_data_in::ProtoMmIn>,
+}
+pub mod proto_mastermind_data_in {
+    /// The actual message
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ProtoMmIn {
+        /// 2 was the handshake, which now lives in the ProtoGameDataIn envelope.
+        #[prost(message, tag = "3")]
+        GameState(super::ProtoGameDataStateReq),
+        #[prost(message, tag = "4")]
+        PutPeg(super::ProtoMmPutPegReq),
+        #[prost(message, tag = "5")]
+        CommitRow(super::ProtoMmCommitRowReq),
+        #[prost(message, tag = "6")]
+        SubmitPassword(super::ProtoMmSubmitPasswordReq),
+    }
 }
-/// Stream message type
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProtoPreGameMessage {
-    #[prost(oneof = "proto_pre_game_message::Inner", tags = "1, 2, 3")]
-    pub inner: ::std::option::Option<proto_pre_game_message::Inner>,
+pub struct ProtoMastermindDataOut {
+    /// Logical clock for this game instance, provided for OCC if game needs it.
+    #[prost(uint64, tag = "1")]
+    pub clock: u64,
+    /// The actual message
+    #[prost(oneof = "proto_mastermind_data_out::ProtoMmOut", tags = "2")]
+    pub proto_mm_out: ::std::option::Option<proto_mastermind_data_out::ProtoMmOut>,
 }
-pub mod proto_pre_game_message {
-    /// Initial response in PreGame stream
-    #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct ProtoJoinGameAck {
-        #[prost(enumeration = "super::ProtoGameType", tag = "1")]
-        pub game_type: i32,
-        #[prost(string, tag = "2")]
-        pub host_player_id: std::string::String,
-        #[prost(string, repeated, tag = "3")]
-        pub other_player_ids: ::std::vec::Vec<std::string::String>,
-    }
-    /// N intermediate messages received in PreGame stream
-    #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct ProtoPlayerJoinMsg {
-        #[prost(string, tag = "1")]
-        pub player_id: std::string::String,
-    }
-    /// Terminal message received in PreGame stream
-    ///
-    /// Empty
-    #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct ProtoGameStartMsg {}
+pub mod proto_mastermind_data_out {
+    /// The actual message
     #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Inner {
-        #[prost(message, tag = "1")]
-        JoinGameAck(ProtoJoinGameAck),
+    pub enum ProtoMmOut {
         #[prost(message, tag = "2")]
-        PlayerJoinMsg(ProtoPlayerJoinMsg),
-        #[prost(message, tag = "3")]
-        GameStartMsg(ProtoGameStartMsg),
+        GameState(super::ProtoMmGameState),
     }
 }
+// =======================================
+// API Request and Reply messages
+// =======================================
+
+// --- GameState
+
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProtoStartGameReq {
+pub struct ProtoMmGameState {
+    #[prost(enumeration = "proto_mm_game_state::ProtoMmStage", tag = "1")]
+    pub stage: i32,
+    #[prost(uint32, tag = "2")]
+    pub num_colors: u32,
+    #[prost(message, optional, tag = "3")]
+    pub my_board: ::std::option::Option<ProtoMmBoard>,
+    #[prost(message, optional, tag = "4")]
+    pub opponent_board: ::std::option::Option<ProtoMmBoard>,
+    #[prost(message, repeated, tag = "5")]
+    pub presence: ::std::vec::Vec<ProtoPlayerPresence>,
+    #[prost(uint32, tag = "6")]
+    pub num_pegs: u32,
+    #[prost(bool, tag = "7")]
+    pub no_duplicate_colors: bool,
+    #[prost(bool, tag = "8")]
+    pub allow_blanks: bool,
+    #[prost(uint32, tag = "9")]
+    pub max_rows: u32,
+    #[prost(uint32, tag = "10")]
+    pub num_rounds: u32,
+    /// Starts at 1. The next round starts as soon as both players are done guessing.
+    #[prost(uint32, tag = "11")]
+    pub round: u32,
+    /// Head => first round. Includes the current round once it's complete.
+    #[prost(message, repeated, tag = "12")]
+    pub round_results: ::std::vec::Vec<ProtoMmRoundResult>,
+    /// Cumulative score of each player over all rounds.
+    #[prost(message, repeated, tag = "13")]
+    pub match_scores: ::std::vec::Vec<ProtoMmMatchScore>,
+    /// Set once the match is complete, unless it's a draw. The most points wins, then the most
+    /// round wins.
+    #[prost(string, tag = "14")]
+    pub match_winner_player_id: std::string::String,
+    /// Set for single player and cooperative games. Every player guesses on `my_board`, against a
+    /// password the server picked. `opponent_board`, the round results and the match scores are
+    /// unset.
+    #[prost(bool, tag = "15")]
+    pub shared_board: bool,
+}
+pub mod proto_mm_game_state {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ProtoMmStage {
+        UnspecifiedStage = 0,
+        /// Both players are creating a password for the other player to guess.
+        PasswordSelection = 1,
+        /// At least one player is still guessing.
+        Guessing = 2,
+        /// Both players have guessed their opponent's password.
+        Complete = 3,
+    }
+}
+/// A single player's view of the game.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmBoard {
+    /// Empty for a shared board.
     #[prost(string, tag = "1")]
     pub player_id: std::string::String,
-    #[prost(string, tag = "2")]
-    pub game_id: std::string::String,
-    #[prost(enumeration = "ProtoGameType", tag = "3")]
-    pub game_type: i32,
+    /// The password that this player created for their opponent to guess. Only visible to the
+    /// player who created it, until the game is complete. A shared board's password is only
+    /// visible once the board is done.
+    #[prost(message, optional, tag = "2")]
+    pub password: ::std::option::Option<ProtoMmRow>,
+    #[prost(bool, tag = "3")]
+    pub password_submitted: bool,
+    /// Head => first guess, Tail => most recent guess
+    #[prost(message, repeated, tag = "4")]
+    pub completed_rows: ::std::vec::Vec<ProtoMmCompletedRow>,
+    #[prost(message, optional, tag = "5")]
+    pub current_guess: ::std::option::Option<ProtoMmRow>,
+    /// Set once this player has guessed their opponent's password.
+    #[prost(bool, tag = "6")]
+    pub solved: bool,
+    #[prost(uint64, tag = "7")]
+    pub solve_duration_millis: u64,
+    /// Set if this player used every row without guessing their opponent's password.
+    #[prost(bool, tag = "8")]
+    pub failed: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProtoStartGameReply {
-    #[prost(string, repeated, tag = "1")]
-    pub player_ids: ::std::vec::Vec<std::string::String>,
+pub struct ProtoMmRoundResult {
+    /// Fewest rows wins, then fastest time. A failed board loses to any solved board. Empty if
+    /// the round is a tie.
+    #[prost(string, tag = "1")]
+    pub winner_player_id: std::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub players: ::std::vec::Vec<ProtoMmPlayerRoundResult>,
 }
-#[doc = r" Generated client implementations."]
-pub mod proto_fridge_game_engine_client {
-    #![allow(unused_variables, dead_code, missing_docs)]
-    use tonic::codegen::*;
-    pub struct ProtoFridgeGameEngineClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl ProtoFridgeGameEngineClient<tonic::transport::Channel> {
-        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: std::convert::TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> ProtoFridgeGameEngineClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::ResponseBody: Body + HttpBody + Send + 'static,
-        T::Error: Into<StdError>,
-        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
-            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
-            Self { inner }
-        }
-        pub async fn host_game(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ProtoHostGameReq>,
-        ) -> Result<
-            tonic::Response<tonic::codec::Streaming<super::ProtoPreGameMessage>>,
-            tonic::Status,
-        > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/proto_frj_ngn.ProtoFridgeGameEngine/HostGame",
-            );
-            self.inner
-                .server_streaming(request.into_request(), path, codec)
-                .await
-        }
-        pub async fn join_game(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ProtoJoinGameReq>,
-        ) -> Result<
-            tonic::Response<tonic::codec::Streaming<super::ProtoPreGameMessage>>,
-            tonic::Status,
-        > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/proto_frj_ngn.ProtoFridgeGameEngine/JoinGame",
-            );
-            self.inner
-                .server_streaming(request.into_request(), path, codec)
-                .await
-        }
-        pub async fn start_game(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ProtoStartGameReq>,
-        ) -> Result<tonic::Response<super::ProtoStartGameReply>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/proto_frj_ngn.ProtoFridgeGameEngine/StartGame",
-            );
-            self.inner.unary(request.into_request(), path, codec).await
-        }
-        pub async fn open_love_letter_data_stream(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::ProtoLoveLetterDataIn>,
-        ) -> Result<
-            tonic::Response<tonic::codec::Streaming<super::ProtoLoveLetterDataOut>>,
-            tonic::Status,
-        > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/proto_frj_ngn.ProtoFridgeGameEngine/OpenLoveLetterDataStream",
-            );
-            self.inner
-                .streaming(request.into_streaming_request(), path, codec)
-                .await
-        }
-    }
-    impl<T: Clone> Clone for ProtoFridgeGameEngineClient<T> {
-        fn clone(&self) -> Self {
-            Self {
-                inner: self.inner.clone(),
-            }
-        }
-    }
-    impl<T> std::fmt::Debug for ProtoFridgeGameEngineClient<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "ProtoFridgeGameEngineClient {{ ... }}")
-        }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmPlayerRoundResult {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    /// Rows this player used to guess their opponent's password.
+    #[prost(uint32, tag = "2")]
+    pub rows_used: u32,
+    #[prost(bool, tag = "3")]
+    pub solved: bool,
+    #[prost(uint64, tag = "4")]
+    pub solve_duration_millis: u64,
+    /// Earned as code-maker: a point for each row the opponent used, and a bonus point if the
+    /// opponent failed.
+    #[prost(uint32, tag = "5")]
+    pub points: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmMatchScore {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(uint32, tag = "2")]
+    pub points: u32,
+    #[prost(uint32, tag = "3")]
+    pub round_wins: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmRow {
+    /// 0 => no color, or a blank if the game allows blanks
+    #[prost(uint32, repeated, tag = "1")]
+    pub pegs: ::std::vec::Vec<u32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmCompletedRow {
+    #[prost(message, optional, tag = "1")]
+    pub guess: ::std::option::Option<ProtoMmRow>,
+    #[prost(uint32, tag = "2")]
+    pub correct: u32,
+    #[prost(uint32, tag = "3")]
+    pub correct_color_wrong_slot: u32,
+}
+// --- PutPeg
+
+/// Req: Set a single peg of the password (during password selection) or of the current guess.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmPutPegReq {
+    #[prost(uint32, tag = "1")]
+    pub peg_index: u32,
+    /// 0 => clear the peg
+    #[prost(uint32, tag = "2")]
+    pub color: u32,
+}
+// --- CommitRow
+
+/// Req: Submit current guess to be scored.
+///
+/// Empty
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmCommitRowReq {}
+// --- SubmitPassword
+
+/// Req: Lock in password. Guessing starts once both players submit their password.
+///
+/// Empty
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmSubmitPasswordReq {}
+// --- Errors
+
+/// Sent as the details of an error status on the data stream, when a move is rejected. The game
+/// state is unchanged.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMmIllegalMove {
+    #[prost(
+        enumeration = "proto_mm_illegal_move::ProtoMmIllegalMoveReason",
+        tag = "1"
+    )]
+    pub reason: i32,
+}
+pub mod proto_mm_illegal_move {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ProtoMmIllegalMoveReason {
+        UnspecifiedIllegalMoveReason = 0,
+        PegOutOfBounds = 1,
+        ColorOutOfBounds = 2,
+        /// A peg is unset, and the game doesn't allow blanks.
+        RowIncomplete = 3,
+        /// The password uses a color more than once, and the game doesn't allow duplicates.
+        DuplicateColors = 4,
+        /// Passwords are still being selected, so there's nothing to guess yet.
+        NotGuessing = 5,
+        /// Guessing has started, so passwords can no longer change.
+        PasswordSelectionOver = 6,
+        /// This player already guessed their opponent's password.
+        AlreadySolved = 7,
+        GameComplete = 8,
+        /// The server picks the password in single player and cooperative games.
+        NoPasswordSelection = 9,
+        /// This player used every row without guessing their opponent's password.
+        OutOfRows = 10,
     }
 }
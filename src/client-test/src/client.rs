@@ -1,11 +1,15 @@
-use client_engine::game_client::wrapper::GameClient;
-use client_engine::wire_api::proto_frj_ngn::{ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq, ProtoStartGameReply, ProtoPreGameMessage, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut};
+use client_engine::game_client::wrapper::{GameClient, DataStreamRecv};
+use client_engine::wire_api::proto_frj_ngn::{ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq, ProtoStartGameReply, ProtoPreGameMessage, ProtoGameDataHandshake, ProtoGameType, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut, ProtoLostCitiesDataIn, ProtoLostCitiesDataOut, ProtoMastermindDataIn, ProtoMastermindDataOut};
+use client_engine::wire_api::proto_frj_ngn::{ProtoLeaveGameReq, ProtoLeaveGameReply, ProtoKickPlayerReq, ProtoKickPlayerReply, ProtoAddBotReq, ProtoAddBotReply, ProtoUpdateGameOptionsReq, ProtoUpdateGameOptionsReply};
+use client_engine::wire_api::proto_frj_ngn::proto_lost_cities_data_in::ProtoLoCiIn;
 use client_engine::wire_api::proto_frj_ngn::proto_love_letter_data_in::ProtoLvLeIn;
+use client_engine::wire_api::proto_frj_ngn::proto_mastermind_data_in::ProtoMmIn;
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use tokio::stream::{Stream, StreamExt};
 use tokio::sync::mpsc;
-use tonic::{Status, Streaming};
+use tonic::Status;
 
 fn time() -> String {
     format!("{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.6f"))
@@ -14,7 +18,7 @@ fn time() -> String {
 // ------- LoggingStreamRecv --------
 
 pub struct LoggingStreamRecv<T: prost::Message> {
-    inner: Streaming<T>,
+    inner: DataStreamRecv<T>,
     player_id: String,
 }
 
@@ -25,15 +29,15 @@ pub enum StreamMsg<T: prost::Message> {
 }
 
 impl<T: prost::Message> LoggingStreamRecv<T> {
-    pub fn new(tonic_stream: Streaming<T>, player_id: String) -> Self {
+    pub fn new(stream: impl Stream<Item = Result<T, Status>> + Send + 'static, player_id: String) -> Self {
         LoggingStreamRecv {
-            inner: tonic_stream,
+            inner: Box::pin(stream),
             player_id
         }
     }
 
     pub async fn recv(&mut self) -> StreamMsg<T> {
-        let message = self.inner.message().await;
+        let message = self.inner.next().await.transpose();
 
         println!("STREAM_RECV ({}) [{}]: {:?}", time(), self.player_id, message);
 
@@ -128,6 +132,30 @@ impl LoggingStreamSender<ProtoLoveLetterDataIn> {
     }
 }
 
+impl LoggingStreamSender<ProtoLostCitiesDataIn> {
+    pub fn send_loci(&self, message_payload: ProtoLoCiIn) {
+        let message = ProtoLostCitiesDataIn {
+            clock: 0,
+            proto_lo_ci_in: Some(message_payload)
+        };
+
+        self.send(message)
+            .expect("gRPC mpsc Receiver (that tunnels to sending to server) dropped.");
+    }
+}
+
+impl LoggingStreamSender<ProtoMastermindDataIn> {
+    pub fn send_mm(&self, message_payload: ProtoMmIn) {
+        let message = ProtoMastermindDataIn {
+            clock: 0,
+            proto_mm_in: Some(message_payload)
+        };
+
+        self.send(message)
+            .expect("gRPC mpsc Receiver (that tunnels to sending to server) dropped.");
+    }
+}
+
 // ------- LoggingBiStream --------
 
 #[derive(Debug)]
@@ -175,12 +203,73 @@ impl LoggingGameClient {
         self.log_result(result)
     }
 
-    pub async fn open_love_letter_stream(&mut self) -> Result<
+    pub async fn leave_game(&mut self, req: ProtoLeaveGameReq) -> Result<ProtoLeaveGameReply, Status> {
+        self.log_request(&req);
+        let result = self.inner.leave_game(req).await;
+        self.log_result(result)
+    }
+
+    pub async fn kick_player(&mut self, req: ProtoKickPlayerReq) -> Result<ProtoKickPlayerReply, Status> {
+        self.log_request(&req);
+        let result = self.inner.kick_player(req).await;
+        self.log_result(result)
+    }
+
+    pub async fn add_bot(&mut self, req: ProtoAddBotReq) -> Result<ProtoAddBotReply, Status> {
+        self.log_request(&req);
+        let result = self.inner.add_bot(req).await;
+        self.log_result(result)
+    }
+
+    pub async fn update_game_options(&mut self, req: ProtoUpdateGameOptionsReq) -> Result<ProtoUpdateGameOptionsReply, Status> {
+        self.log_request(&req);
+        let result = self.inner.update_game_options(req).await;
+        self.log_result(result)
+    }
+
+    pub async fn open_love_letter_stream(&mut self, game_id: impl Into<String>) -> Result<
+        LoggingBiStream<ProtoLoveLetterDataIn, ProtoLoveLetterDataOut>,
+        Status
+    > {
+        self.open_game_data_stream(game_id, ProtoGameType::LoveLetter, false).await
+    }
+
+    /// Watch a game as a spectator, any messages sent on the stream are dropped by the server.
+    pub async fn open_love_letter_spectator_stream(&mut self, game_id: impl Into<String>) -> Result<
         LoggingBiStream<ProtoLoveLetterDataIn, ProtoLoveLetterDataOut>,
         Status
     > {
-        self.log_request(&"OpenLoveLetterDataStream");
-        let result = self.inner.open_love_letter_stream().await;
+        self.open_game_data_stream(game_id, ProtoGameType::LoveLetter, true).await
+    }
+
+    pub async fn open_lost_cities_stream(&mut self, game_id: impl Into<String>) -> Result<
+        LoggingBiStream<ProtoLostCitiesDataIn, ProtoLostCitiesDataOut>,
+        Status
+    > {
+        self.open_game_data_stream(game_id, ProtoGameType::LostCities, false).await
+    }
+
+    pub async fn open_mastermind_stream(&mut self, game_id: impl Into<String>) -> Result<
+        LoggingBiStream<ProtoMastermindDataIn, ProtoMastermindDataOut>,
+        Status
+    > {
+        self.open_game_data_stream(game_id, ProtoGameType::Mastermind, false).await
+    }
+
+    async fn open_game_data_stream<I, O>(&mut self, game_id: impl Into<String>, game_type: ProtoGameType, spectator: bool) -> Result<LoggingBiStream<I, O>, Status>
+        where
+            I: prost::Message + Send + Sync + 'static,
+            O: prost::Message + Default + Send + 'static,
+    {
+        let handshake = ProtoGameDataHandshake {
+            player_id: self.player_id.clone(),
+            game_id: game_id.into(),
+            game_type: game_type as i32,
+            spectator,
+            god_view: false,
+        };
+        self.log_request(&handshake);
+        let result = self.inner.open_game_data_stream(handshake).await;
         self.log_result(result.map(|(snd, rcv)| self.make_bi_stream(snd, rcv)))
     }
 
@@ -200,11 +289,11 @@ impl LoggingGameClient {
     fn make_bi_stream<I: prost::Message, O: prost::Message>(
         &self,
         tx: mpsc::UnboundedSender<I>,
-        tonic_stream: Streaming<O>
+        stream: impl Stream<Item = Result<O, Status>> + Send + 'static,
     ) -> LoggingBiStream<I, O> {
         LoggingBiStream {
             sender: self.make_stream_sender(tx),
-            receiver: self.make_stream_recv(tonic_stream),
+            receiver: self.make_stream_recv(stream),
             my_player_id: self.player_id.clone(),
         }
     }
@@ -213,7 +302,7 @@ impl LoggingGameClient {
         LoggingStreamSender::new(tx, self.player_id.clone())
     }
 
-    fn make_stream_recv<T: prost::Message>(&self, tonic_stream: Streaming<T>) -> LoggingStreamRecv<T> {
-        LoggingStreamRecv::new(tonic_stream, self.player_id.clone())
+    fn make_stream_recv<T: prost::Message>(&self, stream: impl Stream<Item = Result<T, Status>> + Send + 'static) -> LoggingStreamRecv<T> {
+        LoggingStreamRecv::new(stream, self.player_id.clone())
     }
 }
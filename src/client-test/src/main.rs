@@ -1,5 +1,5 @@
 use client_engine::wire_api::proto_frj_ngn::ProtoGameType;
-use client_test::test_cases::{pre_game_stream, love_letter_happy_path};
+use client_test::test_cases::{pre_game_stream, pre_game_leave, love_letter_happy_path, lost_cities_happy_path, mastermind_happy_path, mastermind_vs_bot};
 use std::error::Error;
 use std::collections::HashMap;
 
@@ -14,12 +14,36 @@ async fn main() -> Result<(), Box<dyn Error>> {
     };
     pass_fail("pre_game_stream", pre_game_stream::run(config).await);
 
+    let config = pre_game_leave::Config {
+        game_id: game_id(),
+        players: [player_id(), player_id(), player_id()],
+    };
+    pass_fail("pre_game_leave", pre_game_leave::run(config).await);
+
     let config = love_letter_happy_path::runner::Config {
         game_id: game_id(),
         players: [player_id(), player_id(), player_id()],
     };
     pass_fail("love_letter_happy_path", love_letter_happy_path::runner::run(config).await);
 
+    let config = lost_cities_happy_path::runner::Config {
+        game_id: game_id(),
+        players: [player_id(), player_id()],
+    };
+    pass_fail("lost_cities_happy_path", lost_cities_happy_path::runner::run(config).await);
+
+    let config = mastermind_happy_path::runner::Config {
+        game_id: game_id(),
+        players: [player_id(), player_id()],
+    };
+    pass_fail("mastermind_happy_path", mastermind_happy_path::runner::run(config).await);
+
+    let config = mastermind_vs_bot::Config {
+        game_id: game_id(),
+        player: player_id(),
+    };
+    pass_fail("mastermind_vs_bot", mastermind_vs_bot::run(config).await);
+
     Ok(())
 }
 
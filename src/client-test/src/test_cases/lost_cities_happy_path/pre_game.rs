@@ -0,0 +1,56 @@
+use crate::client::{LoggingGameClient, LoggingBiStream};
+use crate::test_cases::lost_cities_happy_path::runner::Config;
+use client_engine::wire_api::proto_frj_ngn::{ProtoGameType, ProtoLostCitiesDataIn, ProtoLostCitiesDataOut, ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq};
+use client_engine::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+
+pub async fn run_loci_pregame(config: Config) -> (
+    LoggingBiStream<ProtoLostCitiesDataIn, ProtoLostCitiesDataOut>,
+    LoggingBiStream<ProtoLostCitiesDataIn, ProtoLostCitiesDataOut>,
+) {
+    // -- setup --
+    let game_id = config.game_id.clone();
+    let game_type = ProtoGameType::LostCities as i32;
+    let p1 = config.players[0].to_owned();
+    let p2 = config.players[1].to_owned();
+
+    // -- connect --
+    let mut client1 = LoggingGameClient::new(&p1).await.expect("connect1");
+    let mut client2 = LoggingGameClient::new(&p2).await.expect("connect2");
+
+    // -- pre game --
+    let mut p1_stream = client1.host_game(ProtoHostGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        rng_seed: 0,
+        game_options: Vec::new(),
+    }).await.expect("host_game");
+    p1_stream.recv_data("p1_stream joinack").await;
+
+    let mut p2_stream = client2.join_game(ProtoJoinGameReq {
+        player_id: p2.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect("join_game p2");
+    p2_stream.recv_data("p2_stream joinack").await;
+    p1_stream.recv_data("p1_stream p2join").await;
+
+    client1.start_game(ProtoStartGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect("start_game p1");
+
+    for (stream, stream_name) in [(&mut p1_stream, "p1_stream end"), (&mut p2_stream, "p2_stream end")] {
+        match stream.recv_data(stream_name).await.inner {
+            Some(Inner::GameStartMsg(_)) => { /* it worked! */ },
+            other => panic!("Received unexpected message: {:?}", other),
+        }
+    }
+
+    // -- data stream connect --
+    let bi_stream_1 = client1.open_lost_cities_stream(game_id.clone()).await.expect("p1 data_stream");
+    let bi_stream_2 = client2.open_lost_cities_stream(game_id.clone()).await.expect("p2 data_stream");
+
+    (bi_stream_1, bi_stream_2)
+}
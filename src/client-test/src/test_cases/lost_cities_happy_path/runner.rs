@@ -0,0 +1,30 @@
+use crate::test_cases::lost_cities_happy_path::pre_game::run_loci_pregame;
+use crate::test_cases::lost_cities_happy_path::simple_ai::run_simple_game_ai;
+use std::error::Error;
+use std::time::Instant;
+
+pub struct Config {
+    pub game_id: String,
+    pub players: [String; 2],
+}
+
+pub async fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    // -- pregame --
+    let (stream1, stream2) = run_loci_pregame(config).await;
+
+    // -- game --
+    let before = Instant::now();
+
+    let ai1 = tokio::task::spawn(run_simple_game_ai(stream1));
+    let ai2 = tokio::task::spawn(run_simple_game_ai(stream2));
+
+    let score1 = ai1.await?;
+    println!("==== DONE1 with score {}", score1);
+    let score2 = ai2.await?;
+    println!("==== DONE2 with score {}", score2);
+
+    let after = Instant::now();
+    println!("Completed in {}ms", after.duration_since(before).as_millis());
+
+    Ok(())
+}
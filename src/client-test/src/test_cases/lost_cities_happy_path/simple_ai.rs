@@ -0,0 +1,107 @@
+use crate::client::{LoggingBiStream, LoggingStreamRecv};
+use client_engine::wire_api::proto_frj_ngn::{ProtoLostCitiesDataIn, ProtoLostCitiesDataOut, ProtoLoCiGameState, ProtoLoCiPlayCardReq, ProtoLoCiDrawCardReq, ProtoLoCiCard, ProtoGameDataReadyUpClick};
+use client_engine::wire_api::proto_frj_ngn::proto_lost_cities_data_in::ProtoLoCiIn;
+use client_engine::wire_api::proto_frj_ngn::proto_lost_cities_data_out::ProtoLoCiOut;
+use client_engine::wire_api::proto_frj_ngn::proto_lo_ci_game_state::{Stage, ProtoLoCiRoundState, ProtoLoCiTurnPhase};
+use client_engine::wire_api::proto_frj_ngn::proto_lo_ci_play_card_req::ProtoLoCiPlayTarget;
+use client_engine::wire_api::proto_frj_ngn::proto_lo_ci_draw_card_req::{Source, ProtoLoCiDrawPile};
+
+/// This AI has simple rules:
+/// 1. Play the first card in hand that can legally be added to an expedition
+/// 2. Otherwise, discard the first card in hand
+/// 3. Always draw from the draw pile
+/// 4. Ready up after each round, and disconnect once the game is complete
+///
+/// Returns the AI's final score.
+pub async fn run_simple_game_ai(bi_stream: LoggingBiStream<ProtoLostCitiesDataIn, ProtoLostCitiesDataOut>) -> i32 {
+    let sender = bi_stream.sender;
+    let mut game_state_receiver = GameStateReceiver(bi_stream.receiver);
+    let my_player_id = bi_stream.my_player_id;
+
+    let mut last_ready_round = 0;
+
+    loop {
+        let game_state = game_state_receiver.recv().await;
+
+        match game_state.stage.expect("stage missing") {
+            Stage::RoundInProgress(round_state) => {
+                if round_state.current_turn_player_id != my_player_id {
+                    continue;
+                }
+
+                match ProtoLoCiTurnPhase::from_i32(round_state.turn_phase).expect("unknown turn phase") {
+                    ProtoLoCiTurnPhase::PlayCard => {
+                        sender.send_loci(ProtoLoCiIn::PlayCard(choose_play(&round_state)));
+                    },
+                    ProtoLoCiTurnPhase::DrawCard => {
+                        sender.send_loci(ProtoLoCiIn::DrawCard(ProtoLoCiDrawCardReq {
+                            source: Some(Source::DrawPile(ProtoLoCiDrawPile {})),
+                        }));
+                    },
+                    ProtoLoCiTurnPhase::UnspecifiedTurnPhase => panic!("UnspecifiedTurnPhase"),
+                }
+            },
+            Stage::RoundIntermission(result_state) => {
+                if last_ready_round < game_state.round_number && result_state.unready_player_ids.contains(&my_player_id) {
+                    sender.send_loci(ProtoLoCiIn::ReadyUp(ProtoGameDataReadyUpClick {}));
+                    last_ready_round = game_state.round_number;
+                }
+            },
+            Stage::GameComplete(_) => {
+                assert_eq!(game_state.round_number, game_state.total_rounds);
+                let my_score = game_state.players
+                    .iter()
+                    .find(|player| player.player_id == my_player_id)
+                    .expect("I'm missing from players")
+                    .total_score;
+                println!("-- ({}) Game complete with score {}", &my_player_id, my_score);
+                return my_score;
+            },
+        }
+    }
+}
+
+fn choose_play(round_state: &ProtoLoCiRoundState) -> ProtoLoCiPlayCardReq {
+    let playable_card = round_state.my_hand
+        .iter()
+        .find(|card| can_play_to_expedition(round_state, card));
+
+    match playable_card {
+        Some(card) => ProtoLoCiPlayCardReq {
+            card: Some(card.clone()),
+            target: ProtoLoCiPlayTarget::Expedition as i32,
+        },
+        None => ProtoLoCiPlayCardReq {
+            card: Some(round_state.my_hand.first().expect("hand is empty").clone()),
+            target: ProtoLoCiPlayTarget::DiscardPile as i32,
+        },
+    }
+}
+
+/// Wagers (0) can only be played before number cards. Number cards must be increasing.
+fn can_play_to_expedition(round_state: &ProtoLoCiRoundState, card: &ProtoLoCiCard) -> bool {
+    let top_value = round_state.my_expeditions
+        .iter()
+        .find(|expedition| expedition.color == card.color)
+        .and_then(|expedition| expedition.values.last());
+
+    match top_value {
+        None | Some(0) => true,
+        Some(top_value) => card.value > *top_value,
+    }
+}
+
+struct GameStateReceiver(LoggingStreamRecv<ProtoLostCitiesDataOut>);
+impl GameStateReceiver {
+    pub async fn recv(&mut self) -> ProtoLoCiGameState {
+        let msg = self.0
+            .recv_data("AI stream")
+            .await
+            .proto_lo_ci_out
+            .expect("payload missing");
+
+        match msg {
+            ProtoLoCiOut::GameState(game_state) => game_state,
+        }
+    }
+}
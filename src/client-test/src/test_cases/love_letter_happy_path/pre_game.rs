@@ -1,8 +1,7 @@
 use crate::client::{LoggingGameClient, LoggingBiStream};
 use crate::test_cases::love_letter_happy_path::runner::Config;
 use crate::test_cases::pre_game_stream;
-use client_engine::wire_api::proto_frj_ngn::{ProtoGameType, ProtoGameDataHandshake, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut};
-use client_engine::wire_api::proto_frj_ngn::proto_love_letter_data_in::ProtoLvLeIn;
+use client_engine::wire_api::proto_frj_ngn::{ProtoGameType, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut};
 use std::collections::HashMap;
 
 pub async fn run_lvle_pregame(config: Config) -> (
@@ -35,23 +34,9 @@ pub async fn run_lvle_pregame(config: Config) -> (
     pre_game_stream::run(pre_game_config).await.expect("pre_game");
 
     // -- data stream connect --
-    let bi_stream_1 = client1.open_love_letter_stream().await.expect("p1 data_stream");
-    let bi_stream_2 = client2.open_love_letter_stream().await.expect("p2 data_stream");
-    let bi_stream_3 = client3.open_love_letter_stream().await.expect("p3 data_stream");
-
-    // -- handshakes --
-    bi_stream_1.sender.send_lvle(ProtoLvLeIn::Handshake(ProtoGameDataHandshake {
-        player_id: p1.clone(),
-        game_id: game_id.clone(),
-    }));
-    bi_stream_2.sender.send_lvle(ProtoLvLeIn::Handshake(ProtoGameDataHandshake {
-        player_id: p2.clone(),
-        game_id: game_id.clone(),
-    }));
-    bi_stream_3.sender.send_lvle(ProtoLvLeIn::Handshake(ProtoGameDataHandshake {
-        player_id: p3.clone(),
-        game_id: game_id.clone(),
-    }));
+    let bi_stream_1 = client1.open_love_letter_stream(game_id.clone()).await.expect("p1 data_stream");
+    let bi_stream_2 = client2.open_love_letter_stream(game_id.clone()).await.expect("p2 data_stream");
+    let bi_stream_3 = client3.open_love_letter_stream(game_id.clone()).await.expect("p3 data_stream");
 
     return (
         bi_stream_1,
@@ -10,7 +10,7 @@ use client_engine::wire_api::proto_frj_ngn::proto_lv_le_play_card_req::ProtoLvLe
 /// 1. Always keep higher value card (if possible, i.e. Countess)
 /// 2. Always select first (allowed) player
 /// 3. When playing Guard, always select Princess
-/// 4. Disconnect after 5 rounds
+/// 4. Disconnect when the game is complete
 ///
 /// The state handling is messy and just enough to do the job. Don't judge me, I am planning to
 /// finish this game implementation ASAP and move on to doing others more thoroughly.
@@ -19,7 +19,6 @@ pub async fn run_simple_game_ai(bi_stream: LoggingBiStream<ProtoLoveLetterDataIn
     let mut game_state_receiver = GameStateReceiver(bi_stream.receiver);
     let my_player_id = bi_stream.my_player_id;
 
-    let mut num_rounds_to_play = 10u8;
     let mut is_round_intermission = false;
     let mut skip_my_turn_actions = false;
 
@@ -61,16 +60,20 @@ pub async fn run_simple_game_ai(bi_stream: LoggingBiStream<ProtoLoveLetterDataIn
                 skip_my_turn_actions = false;
                 if !is_round_intermission {
                     is_round_intermission = true;
-                    num_rounds_to_play -= 1;
-                    if num_rounds_to_play == 0 {
-                        println!("-- ({}) Game complete: {:#?}", &my_player_id, payload.players);
-                        break;
-                    } else {
-                        println!("-- ({}) Round complete: {:#?}", &my_player_id, round_result.final_cards);
-                        sender.send_lvle(ProtoLvLeIn::ReadyUp(ProtoGameDataReadyUpClick {}));
-                    }
+                    println!("-- ({}) Round complete: {:#?}", &my_player_id, round_result.final_cards);
+                    sender.send_lvle(ProtoLvLeIn::ReadyUp(ProtoGameDataReadyUpClick {}));
                 }
             },
+            Stage::GameComplete(game_result) => {
+                let winner_wins = game_result.final_standings
+                    .first()
+                    .expect("Final standings are missing")
+                    .round_wins;
+                assert!(winner_wins >= payload.win_threshold, "Game completed before anyone reached the win threshold");
+                assert!(!game_result.winner_player_ids.is_empty(), "Game completed without a winner");
+                println!("-- ({}) Game complete, won by {:?}: {:#?}", &my_player_id, game_result.winner_player_ids, game_result.final_standings);
+                break;
+            },
         }
     }
 }
@@ -96,10 +99,12 @@ fn take_my_turn(
         card_source = ProtoLvLeCardSource::Hand;
     }
 
-    // For example, in a 1v1 situation, if someone plays handmaid, then we allow targeting self,
-    // which results in a no-op (expect for Prince).
+    // For example, in a 1v1 situation, if someone plays handmaid, then there's no other player to
+    // target. The card is committed without a selection and has no effect (except for Prince,
+    // which targets self).
     remaining_non_handmaid_player_ids.retain(|x| x != my_player_id);
-    let target_player_id = if !remaining_non_handmaid_player_ids.is_empty() {
+    let has_other_target = !remaining_non_handmaid_player_ids.is_empty();
+    let target_player_id = if has_other_target {
         remaining_non_handmaid_player_ids.remove(0)
     } else {
         my_player_id.to_string()
@@ -118,6 +123,7 @@ fn take_my_turn(
         ProtoLvLeCardSource::UnspecifiedCardSource => panic!("UnspecifiedCardSource"),
     };
     match played_card {
+        ProtoLvLeCard::Guard | ProtoLvLeCard::Priest | ProtoLvLeCard::Baron | ProtoLvLeCard::King if !has_other_target => { /* no effect */ },
         ProtoLvLeCard::Guard => {
             sender.send_lvle(ProtoLvLeIn::SelectTargetPlayer(ProtoLvLeSelectTargetPlayer {
                 target_player_id
@@ -132,6 +138,17 @@ fn take_my_turn(
             }));
         },
         ProtoLvLeCard::Handmaid | ProtoLvLeCard::Countess | ProtoLvLeCard::Princess => { /* no-op */ },
+        ProtoLvLeCard::Spy
+        | ProtoLvLeCard::Chancellor
+        | ProtoLvLeCard::Jester
+        | ProtoLvLeCard::Assassin
+        | ProtoLvLeCard::Cardinal
+        | ProtoLvLeCard::Baroness
+        | ProtoLvLeCard::Sycophant
+        | ProtoLvLeCard::Count
+        | ProtoLvLeCard::Constable
+        | ProtoLvLeCard::DowagerQueen
+        | ProtoLvLeCard::Bishop => panic!("Happy path only plays with the classic deck, but was dealt {:?}", played_card),
         ProtoLvLeCard::UnspecifiedLoveLetterCard => panic!("UnspecifiedLoveLetterCard"),
     }
 
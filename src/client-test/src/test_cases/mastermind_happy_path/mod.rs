@@ -0,0 +1,3 @@
+pub mod pre_game;
+pub mod runner;
+pub mod simple_ai;
@@ -0,0 +1,106 @@
+use crate::client::{LoggingGameClient, LoggingBiStream};
+use crate::test_cases::mastermind_happy_path::runner::Config;
+use client_engine::wire_api::proto_frj_ngn::{ProtoGameType, ProtoMastermindDataIn, ProtoMastermindDataOut, ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq, ProtoMmOptions, ProtoUpdateGameOptionsReq};
+use client_engine::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+use prost::Message;
+use tonic::Code;
+
+/// Differs from the options the game is hosted with, so the test covers the host changing them.
+/// As many colors as pegs without duplicates means the password is a permutation of the colors.
+fn final_options() -> ProtoMmOptions {
+    ProtoMmOptions {
+        num_colors: 5,
+        num_pegs: 5,
+        no_duplicate_colors: true,
+        allow_blanks: false,
+        max_rows: 0,
+        num_rounds: 2,
+        cooperative: false,
+    }
+}
+
+pub async fn run_mm_pregame(config: Config) -> (
+    LoggingBiStream<ProtoMastermindDataIn, ProtoMastermindDataOut>,
+    LoggingBiStream<ProtoMastermindDataIn, ProtoMastermindDataOut>,
+) {
+    // -- setup --
+    let game_id = config.game_id.clone();
+    let game_type = ProtoGameType::Mastermind as i32;
+    let p1 = config.players[0].to_owned();
+    let p2 = config.players[1].to_owned();
+
+    // -- connect --
+    let mut client1 = LoggingGameClient::new(&p1).await.expect("connect1");
+    let mut client2 = LoggingGameClient::new(&p2).await.expect("connect2");
+
+    // -- pre game --
+    let host_req = |num_colors: u32| ProtoHostGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        rng_seed: 0,
+        game_options: encode_options(colors_only(num_colors)),
+    };
+    let status = client1.host_game(host_req(20)).await.expect_err("host_game with too many colors");
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    let mut p1_stream = client1.host_game(host_req(6)).await.expect("host_game");
+    p1_stream.recv_data("p1_stream joinack").await;
+
+    let mut p2_stream = client2.join_game(ProtoJoinGameReq {
+        player_id: p2.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect("join_game p2");
+    match p2_stream.recv_data("p2_stream joinack").await.inner {
+        Some(Inner::JoinGameAck(ack)) => assert_eq!(ack.game_options, encode_options(colors_only(6))),
+        other => panic!("Received unexpected message: {:?}", other),
+    }
+    p1_stream.recv_data("p1_stream p2join").await;
+
+    client1.update_game_options(ProtoUpdateGameOptionsReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        game_options: encode_options(final_options()),
+    }).await.expect("update_game_options p1");
+
+    for (stream, stream_name) in [(&mut p1_stream, "p1_stream options"), (&mut p2_stream, "p2_stream options")] {
+        match stream.recv_data(stream_name).await.inner {
+            Some(Inner::GameOptionsMsg(msg)) => assert_eq!(msg.game_options, encode_options(final_options())),
+            other => panic!("Received unexpected message: {:?}", other),
+        }
+    }
+
+    client1.start_game(ProtoStartGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect("start_game p1");
+
+    for (stream, stream_name) in [(&mut p1_stream, "p1_stream end"), (&mut p2_stream, "p2_stream end")] {
+        match stream.recv_data(stream_name).await.inner {
+            Some(Inner::GameStartMsg(_)) => { /* it worked! */ },
+            other => panic!("Received unexpected message: {:?}", other),
+        }
+    }
+
+    // -- data stream connect --
+    let bi_stream_1 = client1.open_mastermind_stream(game_id.clone()).await.expect("p1 data_stream");
+    let bi_stream_2 = client2.open_mastermind_stream(game_id.clone()).await.expect("p2 data_stream");
+
+    (bi_stream_1, bi_stream_2)
+}
+
+fn colors_only(num_colors: u32) -> ProtoMmOptions {
+    ProtoMmOptions {
+        num_colors,
+        ..ProtoMmOptions::default()
+    }
+}
+
+fn encode_options(options: ProtoMmOptions) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(options.encoded_len());
+    options.encode(&mut encoded).expect("Vec has enough capacity for the encoded message");
+    encoded
+}
@@ -0,0 +1,178 @@
+use crate::client::{LoggingBiStream, LoggingStreamRecv, LoggingStreamSender};
+use client_engine::wire_api::proto_frj_ngn::{ProtoMastermindDataIn, ProtoMastermindDataOut, ProtoMmGameState, ProtoMmPutPegReq, ProtoMmCommitRowReq, ProtoMmSubmitPasswordReq, ProtoMmCompletedRow};
+use client_engine::wire_api::proto_frj_ngn::proto_mastermind_data_in::ProtoMmIn;
+use client_engine::wire_api::proto_frj_ngn::proto_mastermind_data_out::ProtoMmOut;
+use client_engine::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
+use rand::seq::SliceRandom;
+
+/// This AI has simple rules:
+/// 1. Pick a random password
+/// 2. Always guess the first possible password that is consistent with all previous results
+/// 3. Repeat for each round
+/// 4. Disconnect once the match is complete
+///
+/// Returns the number of guesses it took to guess the opponent's passwords, over all rounds.
+pub async fn run_simple_game_ai(bi_stream: LoggingBiStream<ProtoMastermindDataIn, ProtoMastermindDataOut>) -> usize {
+    let sender = bi_stream.sender;
+    let mut game_state_receiver = GameStateReceiver(bi_stream.receiver);
+    let my_player_id = bi_stream.my_player_id;
+
+    let mut round = 0;
+    let mut password_submitted = false;
+    let mut num_guesses_sent = 0;
+
+    loop {
+        let game_state = game_state_receiver.recv().await;
+        let my_board = game_state.my_board.clone().expect("my_board missing");
+        assert_eq!(my_board.player_id, my_player_id);
+
+        if game_state.round != round {
+            round = game_state.round;
+            password_submitted = false;
+            num_guesses_sent = 0;
+        }
+
+        match ProtoMmStage::from_i32(game_state.stage).expect("unknown stage") {
+            ProtoMmStage::PasswordSelection => {
+                if !password_submitted {
+                    let password_pegs = my_board.password.expect("my password missing").pegs;
+                    assert_eq!(password_pegs.len(), game_state.num_pegs as usize);
+
+                    let password = random_row(&game_state);
+                    put_row(&sender, &password);
+                    sender.send_mm(ProtoMmIn::SubmitPassword(ProtoMmSubmitPasswordReq {}));
+                    password_submitted = true;
+                }
+            },
+            ProtoMmStage::Guessing => {
+                // Wait until our most recent guess has been scored before making the next one.
+                if my_board.solved || my_board.failed || my_board.completed_rows.len() < num_guesses_sent {
+                    continue;
+                }
+
+                let guess = next_guess(&game_state, &my_board.completed_rows);
+                put_row(&sender, &guess);
+                sender.send_mm(ProtoMmIn::CommitRow(ProtoMmCommitRowReq {}));
+                num_guesses_sent += 1;
+            },
+            ProtoMmStage::Complete => {
+                assert_eq!(game_state.round_results.len(), game_state.num_rounds as usize);
+                let num_guesses: u32 = game_state.round_results
+                    .iter()
+                    .flat_map(|round_result| round_result.players.iter())
+                    .filter(|player| player.player_id == my_player_id)
+                    .map(|player| player.rows_used)
+                    .sum();
+                println!(
+                    "-- ({}) Match complete after {} guesses, winner: '{}'",
+                    &my_player_id,
+                    num_guesses,
+                    game_state.match_winner_player_id,
+                );
+                return num_guesses as usize;
+            },
+            ProtoMmStage::UnspecifiedStage => panic!("UnspecifiedStage"),
+        }
+    }
+}
+
+fn put_row(sender: &LoggingStreamSender<ProtoMastermindDataIn>, row: &[u32]) {
+    for (peg_index, color) in row.iter().enumerate() {
+        sender.send_mm(ProtoMmIn::PutPeg(ProtoMmPutPegReq {
+            peg_index: peg_index as u32,
+            color: *color,
+        }));
+    }
+}
+
+fn random_row(game_state: &ProtoMmGameState) -> Vec<u32> {
+    let peg_values = peg_values(game_state);
+    let mut rng = rand::thread_rng();
+    if game_state.no_duplicate_colors {
+        peg_values
+            .choose_multiple(&mut rng, game_state.num_pegs as usize)
+            .cloned()
+            .collect()
+    } else {
+        (0..game_state.num_pegs)
+            .map(|_| *peg_values.choose(&mut rng).expect("at least 1 color"))
+            .collect()
+    }
+}
+
+/// Colors, and 0 if blanks are allowed.
+fn peg_values(game_state: &ProtoMmGameState) -> Vec<u32> {
+    let first_color = if game_state.allow_blanks { 0 } else { 1 };
+    (first_color..=game_state.num_colors).collect()
+}
+
+/// Brute force over all `c^p` possible passwords.
+fn next_guess(game_state: &ProtoMmGameState, completed_rows: &[ProtoMmCompletedRow]) -> Vec<u32> {
+    let peg_values = peg_values(game_state);
+    let num_pegs = game_state.num_pegs as usize;
+    let num_possible_passwords = peg_values.len().pow(num_pegs as u32);
+    for i in 0..num_possible_passwords {
+        let candidate = nth_row(&peg_values, num_pegs, i);
+        if game_state.no_duplicate_colors && has_duplicates(&candidate) {
+            continue;
+        }
+
+        let is_consistent = completed_rows.iter().all(|row| {
+            let guess = &row.guess.as_ref().expect("guess missing").pegs;
+            score(guess, &candidate) == (row.correct, row.correct_color_wrong_slot)
+        });
+        if is_consistent {
+            return candidate;
+        }
+    }
+
+    panic!("No password is consistent with the server's results: {:?}", completed_rows);
+}
+
+fn nth_row(peg_values: &[u32], num_pegs: usize, mut n: usize) -> Vec<u32> {
+    let mut row = Vec::with_capacity(num_pegs);
+    for _ in 0..num_pegs {
+        row.push(peg_values[n % peg_values.len()]);
+        n /= peg_values.len();
+    }
+    row
+}
+
+fn has_duplicates(row: &[u32]) -> bool {
+    row.iter()
+        .enumerate()
+        .any(|(i, color)| row[i + 1..].contains(color))
+}
+
+fn score(guess: &[u32], password: &[u32]) -> (u32, u32) {
+    let correct = guess.iter()
+        .zip(password.iter())
+        .filter(|(g, p)| g == p)
+        .count() as u32;
+
+    let mut unmatched_password = password.to_vec();
+    let mut color_matches = 0;
+    for color in guess.iter() {
+        if let Some(pos) = unmatched_password.iter().position(|p| p == color) {
+            unmatched_password.remove(pos);
+            color_matches += 1;
+        }
+    }
+
+    (correct, color_matches - correct)
+}
+
+struct GameStateReceiver(LoggingStreamRecv<ProtoMastermindDataOut>);
+impl GameStateReceiver {
+    pub async fn recv(&mut self) -> ProtoMmGameState {
+        let msg = self.0
+            .recv_data("AI stream")
+            .await
+            .proto_mm_out
+            .expect("payload missing");
+
+        match msg {
+            ProtoMmOut::GameState(game_state) => game_state,
+        }
+    }
+}
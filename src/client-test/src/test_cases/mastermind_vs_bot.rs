@@ -0,0 +1,65 @@
+use crate::client::LoggingGameClient;
+use crate::test_cases::mastermind_happy_path::simple_ai::run_simple_game_ai;
+use client_engine::wire_api::proto_frj_ngn::{ProtoAddBotReq, ProtoGameType, ProtoHostGameReq, ProtoJoinGameReq, ProtoStartGameReq};
+use client_engine::wire_api::proto_frj_ngn::proto_pre_game_message::Inner;
+use std::error::Error;
+use tonic::Code;
+
+pub struct Config {
+    pub game_id: String,
+    pub player: String,
+}
+
+/// Host adds a bot and plays a whole match against it. Clients can't use a bot's player ID.
+pub async fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    // -- setup --
+    let game_id = config.game_id;
+    let game_type = ProtoGameType::Mastermind as i32;
+    let p1 = config.player;
+
+    let mut client1 = LoggingGameClient::new(&p1).await.expect("connect1");
+
+    // -- pre game --
+    let mut p1_stream = client1.host_game(ProtoHostGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        rng_seed: 0,
+        game_options: Vec::new(),
+    }).await.expect("host_game");
+    p1_stream.recv_data("p1_stream joinack").await;
+
+    let reply = client1.add_bot(ProtoAddBotReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect("add_bot p1");
+    match p1_stream.recv_data("p1_stream botjoin").await.inner {
+        Some(Inner::PlayerJoinMsg(msg)) => assert_eq!(msg.player_id, reply.bot_player_id),
+        other => panic!("Received unexpected message: {:?}", other),
+    }
+
+    let status = client1.join_game(ProtoJoinGameReq {
+        player_id: reply.bot_player_id.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect_err("join_game as bot");
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    client1.start_game(ProtoStartGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    }).await.expect("start_game p1");
+    match p1_stream.recv_data("p1_stream end").await.inner {
+        Some(Inner::GameStartMsg(_)) => { /* it worked! */ },
+        other => panic!("Received unexpected message: {:?}", other),
+    }
+
+    // -- game --
+    let bi_stream = client1.open_mastermind_stream(game_id.clone()).await.expect("p1 data_stream");
+    let num_guesses = run_simple_game_ai(bi_stream).await;
+    println!("==== DONE after {} guesses", num_guesses);
+
+    Ok(())
+}
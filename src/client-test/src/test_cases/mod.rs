@@ -1,2 +1,6 @@
 pub mod pre_game_stream;
+pub mod pre_game_leave;
 pub mod love_letter_happy_path;
+pub mod lost_cities_happy_path;
+pub mod mastermind_happy_path;
+pub mod mastermind_vs_bot;
@@ -0,0 +1,111 @@
+use crate::client::{LoggingGameClient, LoggingStreamRecv};
+use client_engine::wire_api::proto_frj_ngn::{ProtoHostGameReq, ProtoGameType, ProtoPreGameMessage, ProtoJoinGameReq, ProtoKickPlayerReq, ProtoLeaveGameReq};
+use client_engine::wire_api::proto_frj_ngn::proto_pre_game_message::{Inner, ProtoPlayerLeaveMsg};
+use std::error::Error;
+use tonic::Code;
+
+pub struct Config {
+    pub game_id: String,
+    pub players: [String; 3],
+}
+
+/// Host kicks a player, then leaves, which makes the last player host. Once the last player
+/// leaves too, the game no longer exists.
+pub async fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    // -- setup --
+    let game_id = config.game_id;
+    let game_type = ProtoGameType::LoveLetter as i32;
+    let [p1, p2, p3] = config.players;
+
+    let mut client1 = LoggingGameClient::new(&p1).await.expect("connect1");
+    let mut client2 = LoggingGameClient::new(&p2).await.expect("connect2");
+    let mut client3 = LoggingGameClient::new(&p3).await.expect("connect3");
+
+    // -- everyone joins --
+    let mut p1_stream = client1.host_game(ProtoHostGameReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        rng_seed: 0,
+        game_options: Vec::new(),
+    }).await.expect("host_game");
+    p1_stream.recv_data("p1_stream joinack").await;
+
+    let join_req = |player_id: &String| ProtoJoinGameReq {
+        player_id: player_id.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    };
+    let mut p2_stream = client2.join_game(join_req(&p2)).await.expect("join_game p2");
+    p2_stream.recv_data("p2_stream joinack").await;
+    p1_stream.recv_data("p1_stream p2join").await;
+
+    let mut p3_stream = client3.join_game(join_req(&p3)).await.expect("join_game p3");
+    p3_stream.recv_data("p3_stream joinack").await;
+    p1_stream.recv_data("p1_stream p3join").await;
+    p2_stream.recv_data("p2_stream p3join").await;
+
+    // -- only the host can kick --
+    let status = client2.kick_player(ProtoKickPlayerReq {
+        player_id: p2.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        kicked_player_id: p3.clone(),
+    }).await.expect_err("kick_player p2");
+    assert_eq!(status.code(), Code::FailedPrecondition);
+
+    client1.kick_player(ProtoKickPlayerReq {
+        player_id: p1.clone(),
+        game_id: game_id.clone(),
+        game_type,
+        kicked_player_id: p3.clone(),
+    }).await.expect("kick_player p1");
+
+    let p3_kicked = leave_msg(&p3, true, &p1);
+    assert_eq!(get_leave_message(&mut p1_stream, "p1_stream p3kick").await, p3_kicked);
+    assert_eq!(get_leave_message(&mut p2_stream, "p2_stream p3kick").await, p3_kicked);
+    assert_eq!(get_leave_message(&mut p3_stream, "p3_stream p3kick").await, p3_kicked);
+    p3_stream.recv_closed("p3_stream").await;
+
+    // -- host leaves --
+    let leave_req = |player_id: &String| ProtoLeaveGameReq {
+        player_id: player_id.clone(),
+        game_id: game_id.clone(),
+        game_type,
+    };
+    client1.leave_game(leave_req(&p1)).await.expect("leave_game p1");
+
+    let p1_left = leave_msg(&p1, false, &p2);
+    assert_eq!(get_leave_message(&mut p1_stream, "p1_stream p1leave").await, p1_left);
+    assert_eq!(get_leave_message(&mut p2_stream, "p2_stream p1leave").await, p1_left);
+    p1_stream.recv_closed("p1_stream").await;
+
+    // -- last player leaves --
+    client2.leave_game(leave_req(&p2)).await.expect("leave_game p2");
+    assert_eq!(get_leave_message(&mut p2_stream, "p2_stream p2leave").await, leave_msg(&p2, false, ""));
+    p2_stream.recv_closed("p2_stream").await;
+
+    let mut p3_stream = client3.join_game(join_req(&p3)).await.expect("join_game p3 again");
+    let status = p3_stream.recv_err("p3_stream rejoin").await;
+    assert_eq!(status.code(), Code::NotFound);
+
+    Ok(())
+}
+
+fn leave_msg(player_id: &str, kicked: bool, host_player_id: &str) -> ProtoPlayerLeaveMsg {
+    ProtoPlayerLeaveMsg {
+        player_id: player_id.to_string(),
+        kicked,
+        host_player_id: host_player_id.to_string(),
+    }
+}
+
+async fn get_leave_message(
+    stream: &mut LoggingStreamRecv<ProtoPreGameMessage>,
+    stream_name: &'static str
+) -> ProtoPlayerLeaveMsg {
+    match stream.recv_data(stream_name).await.inner {
+        Some(Inner::PlayerLeaveMsg(msg)) => msg,
+        other => panic!("Received unexpected message on {}: {:?}", stream_name, other),
+    }
+}
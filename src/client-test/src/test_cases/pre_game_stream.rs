@@ -40,6 +40,8 @@ pub async fn run(mut config: Config) -> Result<(), Box<dyn Error>> {
         player_id: p1.clone(),
         game_id: game_id.clone(),
         game_type,
+        rng_seed: 0,
+        game_options: Vec::new(),
     }).await.expect("host_game");
 
     if let Inner::JoinGameAck(msg) = get_next_message(&mut p1_stream, "p1_stream joinack").await {
@@ -82,6 +84,8 @@ pub async fn run(mut config: Config) -> Result<(), Box<dyn Error>> {
         assert_eq!(msg.game_type, game_type);
         assert_eq!(msg.host_player_id, p1.clone());
         assert_eq!(msg.other_player_ids, vec![p2.clone(), p3.clone()]);
+        assert!(msg.presence.iter().all(|player| player.connected));
+        assert_eq!(msg.presence.len(), 3);
     } else {
         panic!("Received unexpected message.");
     }
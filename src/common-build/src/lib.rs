@@ -13,9 +13,10 @@ pub enum BuildMode {
 }
 
 pub fn do_it(config: BuildConfig) -> io::Result<()> {
-    let proto_file_path_str = config.proto_file_path.to_str().expect("Non unicode file path");
+    let proto_dir = config.proto_file_path.parent().expect("file must be within a directory wtf");
+    let proto_dir_str = proto_dir.to_str().expect("Non unicode file path");
     let hash_file_path_str = config.hash_file_path.to_str().expect("Non unicode file path");
-    cached_code_generation::generate_code(proto_file_path_str, hash_file_path_str, || {
+    cached_code_generation::generate_code(proto_dir_str, hash_file_path_str, || {
         grpc_compiler::build_proto(config.mode, config.proto_file_path)
     })
 }
@@ -24,9 +25,14 @@ pub mod grpc_compiler {
     use std::io;
     use std::path::Path;
     use crate::BuildMode;
+    use crate::cached_code_generation::list_proto_files;
 
+    /// Every .proto file next to `proto_path` is compiled, so a game's messages get generated
+    /// without the service definition having to import them.
     pub fn build_proto(mode: BuildMode, proto_path: &Path) -> io::Result<()> {
         let proto_dir = proto_path.parent().expect("file must be within a directory wtf");
+        let proto_dir_str = proto_dir.to_str().expect("Non unicode file path");
+        let proto_file_paths = list_proto_files(proto_dir_str)?;
 
         let builder = match mode {
             BuildMode::Client => tonic_build::configure().build_server(false).build_client(true),
@@ -36,8 +42,8 @@ pub mod grpc_compiler {
         builder
             .out_dir("./src/wire_api/")
             .compile(
-                &[proto_path],
-                &[proto_dir],
+                &proto_file_paths,
+                &[proto_dir.to_path_buf()],
             )
     }
 }
@@ -46,27 +52,33 @@ pub mod cached_code_generation {
     use std::io;
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
-    use std::fs::{File, OpenOptions};
+    use std::fs::{self, File, OpenOptions};
     use std::io::{Read, Write, SeekFrom, Seek};
+    use std::path::PathBuf;
 
+    /// Since .proto files import each other, we hash every .proto file in the
+    /// source directory, not just the file that is passed to the compiler.
     pub fn generate_code<F>(
-        source_file_path: &str,
+        source_dir_path: &str,
         hash_file_path: &str,
         generator: F
     ) -> io::Result<()>
         where
             F: FnOnce() -> io::Result<()>
     {
-        // 1. Get the hash of the .proto file contents
-        println!("Opening {}", source_file_path);
-        let mut source_file = File::open(source_file_path)?;
-
-        println!("Reading contents of {}", source_file_path);
-        let source_file_contents = read_file_contents(&mut source_file)?;
-        // TODO:3 also hash entire directory of `source_file_path`
+        // 1. Get the hash of all .proto file contents
+        println!("Listing .proto files in {}", source_dir_path);
+        let source_file_paths = list_proto_files(source_dir_path)?;
+
+        let mut source_files_contents = Vec::with_capacity(source_file_paths.len());
+        for source_file_path in source_file_paths.iter() {
+            println!("Reading contents of {:?}", source_file_path);
+            let mut source_file = File::open(source_file_path)?;
+            source_files_contents.push(read_file_contents(&mut source_file)?);
+        }
 
-        println!("Hashing contents of {}", source_file_path);
-        let computed_hashed = hash(&source_file_contents);
+        println!("Hashing contents of {} files", source_files_contents.len());
+        let computed_hashed = hash(&source_files_contents);
 
         // 2. Get existing hash value
         println!("Opening {}", hash_file_path);
@@ -74,6 +86,7 @@ pub mod cached_code_generation {
             .read(true)
             .write(true)
             .create(true)
+            .truncate(false)
             .open(hash_file_path)?;
 
         println!("Reading contents of {}", hash_file_path);
@@ -92,12 +105,26 @@ pub mod cached_code_generation {
             hash_file.set_len(0)?;
 
             println!("Saving new hash to file.");
-            hash_file.write(&computed_hashed)?;
+            hash_file.write_all(&computed_hashed)?;
         }
 
         Ok(())
     }
 
+    /// Sorted, so the hash doesn't depend on the order the OS lists files in.
+    pub(crate) fn list_proto_files(dir_path: &str) -> io::Result<Vec<PathBuf>> {
+        let mut proto_file_paths = Vec::new();
+        for entry in fs::read_dir(dir_path)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("proto") {
+                proto_file_paths.push(path);
+            }
+        }
+        proto_file_paths.sort();
+
+        Ok(proto_file_paths)
+    }
+
     fn read_file_contents(file: &mut File) -> io::Result<Vec<u8>> {
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)?;
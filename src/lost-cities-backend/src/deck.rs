@@ -0,0 +1,23 @@
+use crate::events::{Card, CardValue, Color};
+use backend_framework::prng::PrngRand;
+use backend_framework::shuffler;
+
+pub fn new_shuffled_deck(rng: &mut PrngRand) -> Vec<Card> {
+    shuffler::shuffle_with(new_unshuffled_deck(), rng)
+}
+
+/// 60 cards: for each of the 5 colors, 3x Wager and 1x of each number 2-10.
+fn new_unshuffled_deck() -> Vec<Card> {
+    let mut deck = Vec::with_capacity(60);
+
+    for color in Color::ALL.iter() {
+        for _ in 0..3 {
+            deck.push(Card::new(*color, CardValue::Wager));
+        }
+        for n in 2..=10 {
+            deck.push(Card::new(*color, CardValue::Number(n)));
+        }
+    }
+
+    deck
+}
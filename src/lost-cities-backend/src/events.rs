@@ -0,0 +1,81 @@
+use backend_framework::common_types::ClientInfo;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::ProtoLostCitiesDataOut;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub struct LostCitiesEvent {
+    // TODO:3 this unnecessarily leaks `game_id` into individual instance managers
+    pub client_info: ClientInfo,
+    pub payload: LostCitiesEventType,
+}
+
+#[derive(Debug)]
+pub enum LostCitiesEventType {
+    // Common
+    RegisterDataStream(StreamSender<ProtoLostCitiesDataOut>),
+    GetGameState,
+    ReadyUp,
+
+    // Game-specific
+    PlayCard(Card, PlayTarget),
+    DrawCard(DrawSource),
+}
+
+/// Where a card from the player's hand is placed during the first half of their turn.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PlayTarget {
+    Expedition,
+    DiscardPile,
+}
+
+/// Where a card is drawn from during the second half of a player's turn.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DrawSource {
+    DrawPile,
+    DiscardPile(Color),
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum Color {
+    Yellow,
+    Blue,
+    White,
+    Green,
+    Red,
+}
+
+/// Ordering is significant: wagers must be played to an expedition before any number cards.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum CardValue {
+    /// Multiplies the score of an expedition. Also referred to as a "handshake" or "investment" card.
+    Wager,
+
+    /// 2-10
+    Number(u8),
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct Card {
+    pub color: Color,
+    pub value: CardValue,
+}
+
+impl Color {
+    pub const ALL: [Color; 5] = [
+        Color::Yellow,
+        Color::Blue,
+        Color::White,
+        Color::Green,
+        Color::Red,
+    ];
+}
+
+impl Card {
+    pub fn new(color: Color, value: CardValue) -> Self {
+        Card {
+            color,
+            value,
+        }
+    }
+}
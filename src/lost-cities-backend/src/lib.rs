@@ -0,0 +1,139 @@
+pub mod events;
+pub mod plugin;
+
+mod deck;
+mod state_machine;
+mod types;
+mod type_converters;
+
+#[cfg(test)]
+mod types_test;
+
+use crate::events::{LostCitiesEvent, LostCitiesEventType};
+use crate::state_machine::{LostCitiesState, LostCitiesStateMachine};
+use crate::types::GameData;
+use backend_framework::activity_timer::ActivityTracker;
+use backend_framework::holder::Holder;
+use backend_framework::game_instance_manager::GameInstanceManager;
+use backend_framework::prng::PrngRand;
+use std::time::Duration;
+
+/// This is the top level class for managing a single game of Lost Cities.
+///
+/// State is encapsulated here, separately from the StateMachine, so that we can enforce the
+/// invariant that state is present at the end of handling the event. StateMachine implementation
+/// cannot interact with the `Holder`, it directly operates on the owned state and returns the new
+/// state.
+pub struct LostCitiesInstanceManager {
+    state: Holder<LostCitiesState>,
+    state_machine: LostCitiesStateMachine,
+    activity_tracker: ActivityTracker,
+}
+
+impl LostCitiesInstanceManager {
+
+    fn new(player_ids: Vec<String>, rng: PrngRand) -> Self {
+        let mut state_machine = LostCitiesStateMachine::new(player_ids, rng);
+        let first_round = state_machine.new_round();
+
+        LostCitiesInstanceManager {
+            state: Holder::new(LostCitiesState::PlayPending(first_round)),
+            state_machine,
+            activity_tracker: ActivityTracker::new(),
+        }
+    }
+
+    /// State machine logic:
+    ///
+    /// Move from FROM_STATE to TO_STATE and mutate internal data as needed.
+    fn route_event_to_state_machine(
+        &mut self,
+        from_state: LostCitiesState,
+        event: LostCitiesEvent,
+    ) -> LostCitiesState {
+        let player_id = event.client_info.player_id;
+
+        match event.payload {
+            LostCitiesEventType::GetGameState => {
+                self.state_machine.send_game_state(&from_state, &player_id);
+                from_state
+            },
+            LostCitiesEventType::RegisterDataStream(stream_out) => {
+                self.state_machine.add_stream(player_id.clone(), stream_out);
+                self.state_machine.send_game_state(&from_state, &player_id);
+                from_state
+            },
+            LostCitiesEventType::PlayCard(card, target) => {
+                self.state_machine.play_card(from_state, player_id, card, target)
+            },
+            LostCitiesEventType::DrawCard(source) => {
+                self.state_machine.draw_card(from_state, player_id, source)
+            },
+            LostCitiesEventType::ReadyUp => {
+                self.state_machine.ready_up(from_state, player_id)
+            },
+        }
+    }
+}
+
+impl GameInstanceManager<LostCitiesEvent> for LostCitiesInstanceManager {
+    type Options = ();
+
+    fn create_new_game(player_ids: Vec<String>, _: (), rng: PrngRand) -> Self {
+        LostCitiesInstanceManager::new(player_ids, rng)
+    }
+
+    /// This is the single entry point for manipulating the state of the game.
+    ///
+    /// Logic:
+    /// 1. Take ownership of current state from game instance
+    /// 2. Unwrap the incoming event (i.e. request)
+    /// 3. Route event payload to the correct state machine method
+    /// 4. Put current state back into game instance
+    ///
+    /// Rejected moves aren't reported back here, so any move counts as a change.
+    fn handle_event(&mut self, event: LostCitiesEvent) -> bool {
+        let is_read_only = matches!(event.payload, LostCitiesEventType::GetGameState | LostCitiesEventType::RegisterDataStream(_));
+
+        let from_state = self.state.take();
+        let to_state = self.route_event_to_state_machine(from_state, event);
+        self.state.put(to_state);
+
+        self.activity_tracker.ping();
+        !is_read_only
+    }
+
+    fn player_ids(&self) -> &Vec<String> {
+        self.state_machine.all_player_ids()
+    }
+
+    fn is_game_stale(&self, expiry_duration: Duration) -> bool {
+        self.activity_tracker.has_inactivity_elapsed(expiry_duration)
+    }
+
+    fn is_game_complete(&self) -> bool {
+        matches!(self.state.get(), LostCitiesState::GameComplete(_))
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        let snapshot: (&LostCitiesState, &GameData) = (self.state.get(), self.state_machine.game_data());
+        match serde_json::to_vec(&snapshot) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                println!("ERROR: Failed to snapshot Lost Cities game: {}", e);
+                None
+            },
+        }
+    }
+
+    fn restore_game(snapshot: &[u8]) -> Result<Self, String> {
+        let (state, game_data): (LostCitiesState, GameData) = serde_json::from_slice(snapshot)
+            .map_err(|e| format!("Invalid Lost Cities snapshot: {}", e))?;
+
+        Ok(LostCitiesInstanceManager {
+            state: Holder::new(state),
+            state_machine: LostCitiesStateMachine::from_game_data(game_data),
+            activity_tracker: ActivityTracker::new(),
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use crate::LostCitiesInstanceManager;
+use crate::events::{LostCitiesEvent, LostCitiesEventType, Card, PlayTarget, DrawSource, Color};
+use backend_framework::common_types::ClientInfo;
+use backend_framework::game_plugin::GamePlugin;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoLostCitiesDataIn, ProtoLostCitiesDataOut, ProtoGameType, ProtoLoCiColor, proto_lo_ci_draw_card_req};
+use backend_framework::wire_api::proto_frj_ngn::proto_lost_cities_data_in::ProtoLoCiIn;
+use backend_framework::wire_api::proto_frj_ngn::proto_lo_ci_play_card_req::ProtoLoCiPlayTarget;
+use std::convert::TryFrom;
+use tonic::Status;
+
+/// Describes Lost Cities to the engine.
+pub struct LostCitiesPlugin;
+
+impl GamePlugin for LostCitiesPlugin {
+    type DataIn = ProtoLostCitiesDataIn;
+    type DataOut = ProtoLostCitiesDataOut;
+    type Event = LostCitiesEvent;
+    type Instance = LostCitiesInstanceManager;
+
+    fn game_type(&self) -> ProtoGameType {
+        ProtoGameType::LostCities
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Lost Cities"
+    }
+
+    fn player_count_min_max(&self) -> (usize, usize) {
+        (2, 2)
+    }
+
+    fn decode_event(&self, client_info: ClientInfo, message: ProtoLostCitiesDataIn) -> Result<LostCitiesEvent, Status> {
+        let payload = match message.proto_lo_ci_in {
+            None => return Err(Status::invalid_argument("Missing proto_lo_ci_in field.")),
+            Some(payload) => convert_payload(payload)?,
+        };
+
+        Ok(LostCitiesEvent {
+            client_info,
+            payload,
+        })
+    }
+
+    fn register_data_stream_event(&self, client_info: ClientInfo, stream_out: StreamSender<ProtoLostCitiesDataOut>) -> LostCitiesEvent {
+        LostCitiesEvent {
+            client_info,
+            payload: LostCitiesEventType::RegisterDataStream(stream_out),
+        }
+    }
+
+    fn reject_event(&self, event: LostCitiesEvent, status: Status) {
+        if let LostCitiesEventType::RegisterDataStream(stream) = event.payload {
+            let _ = stream.send_error_message(status);
+        }
+    }
+}
+
+fn convert_payload(payload: ProtoLoCiIn) -> Result<LostCitiesEventType, Status> {
+    match payload {
+        ProtoLoCiIn::GameState(_) => Ok(LostCitiesEventType::GetGameState),
+        ProtoLoCiIn::PlayCard(req) => {
+            let card = req.card
+                .ok_or_else(|| Status::invalid_argument("Missing ProtoLoCiCard"))
+                .and_then(|proto_card| Card::try_from(proto_card)
+                    .map_err(|_| Status::invalid_argument("Invalid ProtoLoCiCard")))?;
+            let proto_target = ProtoLoCiPlayTarget::try_from(req.target)?;
+            let target = PlayTarget::try_from(proto_target)
+                .map_err(|_| Status::invalid_argument("Unspecified ProtoLoCiPlayTarget"))?;
+            Ok(LostCitiesEventType::PlayCard(card, target))
+        },
+        ProtoLoCiIn::DrawCard(req) => {
+            let source = match req.source {
+                None => return Err(Status::invalid_argument("Missing ProtoLoCiDrawCardReq source")),
+                Some(proto_lo_ci_draw_card_req::Source::DrawPile(_)) => DrawSource::DrawPile,
+                Some(proto_lo_ci_draw_card_req::Source::DiscardPile(proto_color)) => {
+                    let proto_color = ProtoLoCiColor::try_from(proto_color)?;
+                    let color = Color::try_from(proto_color)
+                        .map_err(|_| Status::invalid_argument("Unspecified ProtoLoCiColor"))?;
+                    DrawSource::DiscardPile(color)
+                },
+            };
+            Ok(LostCitiesEventType::DrawCard(source))
+        },
+        ProtoLoCiIn::ReadyUp(_) => Ok(LostCitiesEventType::ReadyUp),
+    }
+}
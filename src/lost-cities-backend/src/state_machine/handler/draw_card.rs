@@ -0,0 +1,90 @@
+use crate::events::{Color, DrawSource};
+use crate::state_machine::{LostCitiesStateMachine, LostCitiesState};
+use crate::types::{RoundData, UnreadyPlayers};
+use tonic::Status;
+
+impl LostCitiesStateMachine {
+
+    pub fn draw_card(
+        &mut self,
+        from_state: LostCitiesState,
+        client_player_id: String,
+        source: DrawSource,
+    ) -> LostCitiesState {
+        match from_state {
+            LostCitiesState::DrawPending(round_data, discarded_color) => {
+                self.handle_draw(&client_player_id, source, round_data, discarded_color)
+            },
+            _ => {
+                self.streams.send_err(&client_player_id, Status::failed_precondition("Can't draw card while in current state"));
+                from_state
+            },
+        }
+    }
+
+    /// 1. Ensure it's the caller's turn and the source has a card they're allowed to draw.
+    /// 2. Move card into the player's hand and pass the turn to the opponent.
+    /// 3. If the draw pile is now empty, the round is over.
+    fn handle_draw(
+        &mut self,
+        client_player_id: &String,
+        source: DrawSource,
+        mut round_data: RoundData,
+        discarded_color: Option<Color>,
+    ) -> LostCitiesState {
+        // Check: Is my turn
+        if client_player_id != round_data.current_turn_player_id() {
+            self.streams.send_err(client_player_id, Status::failed_precondition("Can't draw card, not your turn"));
+            return LostCitiesState::DrawPending(round_data, discarded_color);
+        }
+
+        let drawn_card = match source {
+            DrawSource::DrawPile => round_data.draw_pile
+                .pop()
+                .expect("Illegal game state: We're in 'DrawPending' state with no cards in draw pile"),
+            DrawSource::DiscardPile(color) => {
+                // Check: Didn't just discard to this pile
+                if discarded_color == Some(color) {
+                    self.streams.send_err(client_player_id, Status::invalid_argument("Can't draw the card you just discarded"));
+                    return LostCitiesState::DrawPending(round_data, discarded_color);
+                }
+
+                // Check: Pile isn't empty
+                match round_data.discard_piles.draw(color) {
+                    Some(card) => card,
+                    None => {
+                        self.streams.send_err(client_player_id, Status::invalid_argument("Can't draw card, discard pile is empty"));
+                        return LostCitiesState::DrawPending(round_data, discarded_color);
+                    },
+                }
+            },
+        };
+
+        round_data.boards
+            .get_mut(client_player_id)
+            .expect("Illegal game state: current turn player has no board")
+            .add_to_hand(drawn_card);
+        round_data.increment_turn();
+
+        let to_state = if round_data.is_round_over() {
+            self.end_round(round_data)
+        } else {
+            LostCitiesState::PlayPending(round_data)
+        };
+
+        self.send_game_state_to_all(&to_state);
+        to_state
+    }
+
+    fn end_round(&mut self, round_data: RoundData) -> LostCitiesState {
+        let round_result = round_data.into_result();
+        self.game_data.scores_per_round.push(round_result.score_by_player_id.clone());
+
+        if self.game_data.is_complete() {
+            LostCitiesState::GameComplete(round_result)
+        } else {
+            let unready_players = UnreadyPlayers::new(self.game_data.player_ids.clone());
+            LostCitiesState::RoundIntermission(round_result, unready_players)
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! These are the top-level API handlers for the StateMachine. There is 1 file for each API.
+mod draw_card;
+mod play_card;
+mod ready_up;
+mod send_game_state;
@@ -0,0 +1,67 @@
+use crate::events::{Card, PlayTarget};
+use crate::state_machine::{LostCitiesStateMachine, LostCitiesState};
+use crate::types::RoundData;
+use tonic::Status;
+
+impl LostCitiesStateMachine {
+
+    pub fn play_card(
+        &self,
+        from_state: LostCitiesState,
+        client_player_id: String,
+        card: Card,
+        target: PlayTarget,
+    ) -> LostCitiesState {
+        match from_state {
+            LostCitiesState::PlayPending(round_data) => self.handle_play(&client_player_id, card, target, round_data),
+            _ => {
+                self.streams.send_err(&client_player_id, Status::failed_precondition("Can't play card while in current state"));
+                from_state
+            },
+        }
+    }
+
+    /// 1. Ensure it's the caller's turn and they have the card.
+    /// 2. Ensure the card can legally be added to the target.
+    /// 3. Move card from hand to the target, and wait for the player to draw.
+    fn handle_play(&self, client_player_id: &String, card: Card, target: PlayTarget, mut round_data: RoundData) -> LostCitiesState {
+        // Check: Is my turn
+        if client_player_id != round_data.current_turn_player_id() {
+            self.streams.send_err(client_player_id, Status::failed_precondition("Can't play card, not your turn"));
+            return LostCitiesState::PlayPending(round_data);
+        }
+
+        let board = round_data.boards
+            .get_mut(client_player_id)
+            .expect("Illegal game state: current turn player has no board");
+
+        // Check: Play is legal
+        if target == PlayTarget::Expedition && !board.can_play(card) {
+            self.streams.send_err(client_player_id, Status::invalid_argument(
+                "Can't play card, expedition cards must be played in increasing order and wagers must be played first"
+            ));
+            return LostCitiesState::PlayPending(round_data);
+        }
+
+        // Check: Card is in my hand
+        if !board.remove_from_hand(card) {
+            self.streams.send_err(client_player_id, Status::invalid_argument("Can't play card, it's not in your hand"));
+            return LostCitiesState::PlayPending(round_data);
+        }
+
+        let discarded_color = match target {
+            PlayTarget::Expedition => {
+                board.expedition_mut(card.color).play(card.value);
+                None
+            },
+            PlayTarget::DiscardPile => {
+                round_data.discard_piles.discard(card);
+                Some(card.color)
+            },
+        };
+
+        let to_state = LostCitiesState::DrawPending(round_data, discarded_color);
+        self.send_game_state_to_all(&to_state);
+        to_state
+    }
+}
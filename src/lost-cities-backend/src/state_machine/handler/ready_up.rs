@@ -0,0 +1,30 @@
+use crate::state_machine::{LostCitiesStateMachine, LostCitiesState};
+use crate::types::RoundData;
+
+impl LostCitiesStateMachine {
+    pub fn ready_up(&mut self, from_state: LostCitiesState, client_player_id: String) -> LostCitiesState {
+        match from_state {
+            LostCitiesState::RoundIntermission(round_result, mut unready_players) => {
+                unready_players.ready_up(&client_player_id);
+
+                let to_state = if unready_players.all_ready() {
+                    LostCitiesState::PlayPending(self.new_round())
+                } else {
+                    LostCitiesState::RoundIntermission(round_result, unready_players)
+                };
+
+                self.send_game_state_to_all(&to_state);
+                to_state
+            },
+            _ => {
+                // Do nothing and drop message
+                from_state
+            },
+        }
+    }
+
+    pub fn new_round(&mut self) -> RoundData {
+        let round_index = self.game_data.num_completed_rounds();
+        RoundData::new(&self.game_data.player_ids, round_index, &mut self.game_data.rng)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::events::Color;
+use crate::state_machine::{LostCitiesStateMachine, LostCitiesState};
+use crate::types::{GameData, RoundData, RoundResult, PlayerBoard, NUM_ROUNDS};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoLoCiGameState, ProtoLoCiCard, ProtoLoCiColor, ProtoLoCiExpedition, ProtoLoCiDiscardPile};
+use backend_framework::wire_api::proto_frj_ngn::proto_lo_ci_game_state::{ProtoLoCiPlayer, ProtoLoCiRoundState, ProtoLoCiResultState, ProtoLoCiTurnPhase, Stage};
+
+impl LostCitiesStateMachine {
+    pub fn send_game_state(&self, state: &LostCitiesState, player_id: &String) {
+        let proto_all_players = get_proto_all_players(&self.game_data);
+
+        self.send_game_state_to_player(player_id, state, proto_all_players);
+    }
+
+    pub fn send_game_state_to_all(&self, state: &LostCitiesState) {
+        let proto_all_players = get_proto_all_players(&self.game_data);
+
+        for player_id in self.game_data.player_ids.iter() {
+            self.send_game_state_to_player(player_id, state, proto_all_players.clone());
+        }
+    }
+
+    fn send_game_state_to_player(
+        &self,
+        player_id: &String,
+        state: &LostCitiesState,
+        proto_all_players: Vec<ProtoLoCiPlayer>
+    ) {
+        // Rounds are 1-indexed for display purposes. A round in progress hasn't been completed yet.
+        let completed_rounds = self.game_data.num_completed_rounds();
+        let round_number = match state {
+            LostCitiesState::PlayPending(_) | LostCitiesState::DrawPending(_, _) => completed_rounds + 1,
+            LostCitiesState::RoundIntermission(_, _) | LostCitiesState::GameComplete(_) => completed_rounds,
+        };
+
+        let proto_state = ProtoLoCiGameState {
+            players: proto_all_players,
+            round_number: round_number as u32,
+            total_rounds: NUM_ROUNDS as u32,
+            stage: Some(into_proto_stage(state, player_id)),
+            presence: self.streams.presence(),
+        };
+        self.streams.send_msg(player_id, proto_state);
+    }
+}
+
+fn get_proto_all_players(game_data: &GameData) -> Vec<ProtoLoCiPlayer> {
+    game_data.player_ids
+        .iter()
+        .map(|player_id| ProtoLoCiPlayer {
+            player_id: player_id.to_string(),
+            total_score: game_data.total_score(player_id),
+        })
+        .collect()
+}
+
+fn into_proto_stage(state: &LostCitiesState, player_id: &str) -> Stage {
+    match state {
+        LostCitiesState::PlayPending(round_data) => Stage::RoundInProgress(
+            into_proto_round_state(round_data, player_id, ProtoLoCiTurnPhase::PlayCard)
+        ),
+        LostCitiesState::DrawPending(round_data, _) => Stage::RoundInProgress(
+            into_proto_round_state(round_data, player_id, ProtoLoCiTurnPhase::DrawCard)
+        ),
+        LostCitiesState::RoundIntermission(round_result, unready_players) => Stage::RoundIntermission(
+            into_proto_result_state(round_result, unready_players.clone().into_inner())
+        ),
+        LostCitiesState::GameComplete(round_result) => Stage::GameComplete(
+            into_proto_result_state(round_result, Vec::new())
+        ),
+    }
+}
+
+fn into_proto_round_state(
+    round_data: &RoundData,
+    my_player_id: &str,
+    turn_phase: ProtoLoCiTurnPhase,
+) -> ProtoLoCiRoundState {
+    let my_board = round_data.boards.get(my_player_id);
+    let opponent_board = round_data.opponent_player_id(my_player_id)
+        .and_then(|opponent_player_id| round_data.boards.get(opponent_player_id));
+
+    let my_hand = my_board
+        .map(|board| board.hand
+            .iter()
+            .map(|card| ProtoLoCiCard::from(*card))
+            .collect())
+        .unwrap_or_default();
+
+    let discard_piles = Color::ALL
+        .iter()
+        .map(|color| ProtoLoCiDiscardPile {
+            color: ProtoLoCiColor::from(*color) as i32,
+            values: round_data.discard_piles
+                .pile(*color)
+                .iter()
+                .map(|value| u32::from(*value))
+                .collect(),
+        })
+        .collect();
+
+    ProtoLoCiRoundState {
+        my_hand,
+        my_expeditions: into_proto_expeditions(my_board),
+        opponent_expeditions: into_proto_expeditions(opponent_board),
+        discard_piles,
+        draw_pile_size: round_data.draw_pile.len() as u32,
+        current_turn_player_id: round_data.current_turn_player_id().to_string(),
+        turn_phase: turn_phase as i32,
+    }
+}
+
+/// Always includes all 5 colors, even for expeditions which haven't been started.
+fn into_proto_expeditions(opt_board: Option<&PlayerBoard>) -> Vec<ProtoLoCiExpedition> {
+    Color::ALL
+        .iter()
+        .map(|color| {
+            let expedition = opt_board.and_then(|board| board.expedition(*color));
+            ProtoLoCiExpedition {
+                color: ProtoLoCiColor::from(*color) as i32,
+                values: expedition
+                    .map(|e| e.values().iter().map(|value| u32::from(*value)).collect())
+                    .unwrap_or_default(),
+                score: expedition.map(|e| e.score()).unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+fn into_proto_result_state(
+    round_result: &RoundResult,
+    unready_player_ids: Vec<String>
+) -> ProtoLoCiResultState {
+    ProtoLoCiResultState {
+        round_scores: round_result.score_by_player_id.clone(),
+        unready_player_ids,
+    }
+}
@@ -0,0 +1,78 @@
+//! There are two "data holders" introduced here:
+//! 1. `LostCitiesStateMachine` - holds data that is present for the entirety of the game.
+//! 2. `LostCitiesState` - holds data that is optionally present depending on the current game state.
+mod handler;
+
+use crate::events::Color;
+use crate::types::{GameData, RoundData, RoundResult, UnreadyPlayers};
+use backend_framework::data_stream::PlayerDataStreams;
+use backend_framework::prng::PrngRand;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::ProtoLostCitiesDataOut;
+use serde::{Deserialize, Serialize};
+
+/// The possible states of an instance of the game.
+///
+/// ```text
+/// +-------------------+    +-------------------+
+/// |      (start)      |    |                   |
+/// |    PlayPending    |--->|    DrawPending    |
+/// |                   |    |                   |
+/// +-------------------+    +-------------------+
+///      ^    ^                   |    |    |
+///      |    +----<----<----<----+    |    |
+///      |                             |    |
+///      |   +-------------------+     |    |
+///      |   |                   |     |    |
+///      +---| RoundIntermission |<----+    |
+///          |                   |          V
+///          +-------------------+    +-------------------+
+///                                   |       (fin)       |
+///                                   |    GameComplete   |
+///                                   |                   |
+///                                   +-------------------+
+/// ```
+#[derive(Serialize, Deserialize)]
+pub enum LostCitiesState {
+    PlayPending(RoundData),
+    /// The color is of the discard pile that the current player just discarded to, if any. A
+    /// player can't draw the card they discarded in the same turn.
+    DrawPending(RoundData, Option<Color>),
+    RoundIntermission(RoundResult, UnreadyPlayers),
+    GameComplete(RoundResult),
+}
+
+/// A state machine executor. It operates on states as inputs/outputs, not owned data.
+/// Although it does own some data specific to a game instance.
+pub struct LostCitiesStateMachine {
+    streams: PlayerDataStreams<ProtoLostCitiesDataOut>,
+    game_data: GameData,
+}
+
+impl LostCitiesStateMachine {
+    pub fn new(player_ids: Vec<String>, rng: PrngRand) -> Self {
+        LostCitiesStateMachine::from_game_data(GameData::new(player_ids, rng))
+    }
+
+    /// Used when restoring a game, players re-register their streams when they reconnect.
+    pub fn from_game_data(game_data: GameData) -> Self {
+        let streams = PlayerDataStreams::new(game_data.player_ids.clone());
+
+        LostCitiesStateMachine {
+            streams,
+            game_data,
+        }
+    }
+
+    pub fn game_data(&self) -> &GameData {
+        &self.game_data
+    }
+
+    pub fn add_stream(&mut self, player_id: String, stream: StreamSender<ProtoLostCitiesDataOut>) {
+        self.streams.add_stream(player_id, stream);
+    }
+
+    pub fn all_player_ids(&self) -> &Vec<String> {
+        &self.game_data.player_ids
+    }
+}
@@ -0,0 +1,87 @@
+use crate::events::{Card, CardValue, Color, PlayTarget};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoLoCiCard, ProtoLoCiColor};
+use backend_framework::wire_api::proto_frj_ngn::proto_lo_ci_play_card_req::ProtoLoCiPlayTarget;
+use std::convert::TryFrom;
+
+impl TryFrom<ProtoLoCiPlayTarget> for PlayTarget {
+    type Error = ();
+
+    fn try_from(proto: ProtoLoCiPlayTarget) -> Result<Self, Self::Error> {
+        match proto {
+            ProtoLoCiPlayTarget::UnspecifiedPlayTarget => Err(()),
+            ProtoLoCiPlayTarget::Expedition => Ok(PlayTarget::Expedition),
+            ProtoLoCiPlayTarget::DiscardPile => Ok(PlayTarget::DiscardPile),
+        }
+    }
+}
+
+impl TryFrom<ProtoLoCiColor> for Color {
+    type Error = ();
+
+    fn try_from(proto: ProtoLoCiColor) -> Result<Self, Self::Error> {
+        match proto {
+            ProtoLoCiColor::UnspecifiedColor => Err(()),
+            ProtoLoCiColor::Yellow => Ok(Color::Yellow),
+            ProtoLoCiColor::Blue => Ok(Color::Blue),
+            ProtoLoCiColor::White => Ok(Color::White),
+            ProtoLoCiColor::Green => Ok(Color::Green),
+            ProtoLoCiColor::Red => Ok(Color::Red),
+        }
+    }
+}
+
+impl From<Color> for ProtoLoCiColor {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Yellow => ProtoLoCiColor::Yellow,
+            Color::Blue => ProtoLoCiColor::Blue,
+            Color::White => ProtoLoCiColor::White,
+            Color::Green => ProtoLoCiColor::Green,
+            Color::Red => ProtoLoCiColor::Red,
+        }
+    }
+}
+
+/// 0 => Wager, 2-10 => Number
+impl TryFrom<u32> for CardValue {
+    type Error = ();
+
+    fn try_from(proto: u32) -> Result<Self, Self::Error> {
+        match proto {
+            0 => Ok(CardValue::Wager),
+            2..=10 => Ok(CardValue::Number(proto as u8)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<CardValue> for u32 {
+    fn from(value: CardValue) -> Self {
+        match value {
+            CardValue::Wager => 0,
+            CardValue::Number(n) => n as u32,
+        }
+    }
+}
+
+impl TryFrom<ProtoLoCiCard> for Card {
+    type Error = ();
+
+    fn try_from(proto: ProtoLoCiCard) -> Result<Self, Self::Error> {
+        let proto_color = ProtoLoCiColor::try_from(proto.color).map_err(|_| ())?;
+
+        Ok(Card {
+            color: Color::try_from(proto_color)?,
+            value: CardValue::try_from(proto.value)?,
+        })
+    }
+}
+
+impl From<Card> for ProtoLoCiCard {
+    fn from(card: Card) -> Self {
+        ProtoLoCiCard {
+            color: ProtoLoCiColor::from(card.color) as i32,
+            value: u32::from(card.value),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use crate::deck;
+use crate::events::{Card, CardValue, Color};
+use backend_framework::prng::PrngRand;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const NUM_ROUNDS: usize = 3;
+const HAND_SIZE: usize = 8;
+
+/// Expedition score is offset by this value. An expedition that has been started
+/// but has low value cards will result in negative points.
+const EXPEDITION_COST: i32 = 20;
+const LONG_EXPEDITION_LENGTH: usize = 8;
+const LONG_EXPEDITION_BONUS: i32 = 20;
+
+// ---------------- struct defs --------------------
+
+#[derive(Serialize, Deserialize)]
+pub struct GameData {
+    /// Always exactly 2 players. The first player starts the first round.
+    pub player_ids: Vec<String>,
+    /// Head => first round, Tail => most recently completed round
+    pub scores_per_round: Vec<HashMap<String, i32>>,
+    /// Shuffles every round's deck.
+    pub rng: PrngRand,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RoundData {
+    pub draw_pile: Vec<Card>,
+    pub discard_piles: DiscardPiles,
+    pub boards: HashMap<String, PlayerBoard>,
+    turn_order: Vec<String>,
+    turn_cursor: usize,
+}
+
+/// The cards that belong to a single player during a round.
+#[derive(Serialize, Deserialize)]
+pub struct PlayerBoard {
+    /// Kept sorted, for convenience of the player.
+    pub hand: Vec<Card>,
+    pub expeditions: HashMap<Color, Expedition>,
+}
+
+/// The cards a player has played in a single color. A started expedition costs points, which
+/// must be made up by playing cards of increasing value.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Expedition {
+    values: Vec<CardValue>,
+}
+
+/// One face-up pile per color. Only the top card of each pile can be drawn.
+#[derive(Default, Serialize, Deserialize)]
+pub struct DiscardPiles {
+    piles: HashMap<Color, Vec<CardValue>>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RoundResult {
+    pub score_by_player_id: HashMap<String, i32>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct UnreadyPlayers {
+    player_ids: Vec<String>,
+}
+
+// ---------------- impl blocks --------------------
+
+impl GameData {
+    pub fn new(player_ids: Vec<String>, rng: PrngRand) -> Self {
+        GameData {
+            player_ids,
+            scores_per_round: Vec::with_capacity(NUM_ROUNDS),
+            rng,
+        }
+    }
+
+    pub fn total_score(&self, player_id: &str) -> i32 {
+        self.scores_per_round
+            .iter()
+            .filter_map(|scores| scores.get(player_id))
+            .sum()
+    }
+
+    pub fn num_completed_rounds(&self) -> usize {
+        self.scores_per_round.len()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.num_completed_rounds() >= NUM_ROUNDS
+    }
+}
+
+impl RoundData {
+    /// Players alternate who goes first each round.
+    pub fn new(player_ids: &[String], round_index: usize, rng: &mut PrngRand) -> Self {
+        let mut draw_pile = deck::new_shuffled_deck(rng);
+        let mut boards = HashMap::with_capacity(player_ids.len());
+
+        for player_id in player_ids.iter() {
+            let mut hand = Vec::with_capacity(HAND_SIZE + 1);
+            for _ in 0..HAND_SIZE {
+                hand.push(draw_pile.pop().expect("deck out of cards before game start"));
+            }
+            hand.sort();
+
+            boards.insert(player_id.clone(), PlayerBoard {
+                hand,
+                expeditions: HashMap::new(),
+            });
+        }
+
+        RoundData {
+            draw_pile,
+            discard_piles: DiscardPiles::default(),
+            boards,
+            turn_order: player_ids.to_vec(),
+            turn_cursor: round_index % player_ids.len(),
+        }
+    }
+
+    pub fn current_turn_player_id(&self) -> &String {
+        self.turn_order
+            .get(self.turn_cursor)
+            .expect("Cursor should always ensure valid access")
+    }
+
+    pub fn increment_turn(&mut self) {
+        self.turn_cursor = (self.turn_cursor + 1) % self.turn_order.len();
+    }
+
+    pub fn opponent_player_id(&self, player_id: &str) -> Option<&String> {
+        self.turn_order
+            .iter()
+            .find(|p| *p != player_id)
+    }
+
+    /// The round ends as soon as the last card of the draw pile is drawn.
+    pub fn is_round_over(&self) -> bool {
+        self.draw_pile.is_empty()
+    }
+
+    pub fn into_result(self) -> RoundResult {
+        let score_by_player_id = self.boards
+            .into_iter()
+            .map(|(player_id, board)| (player_id, board.score()))
+            .collect();
+
+        RoundResult {
+            score_by_player_id,
+        }
+    }
+}
+
+impl PlayerBoard {
+    /// Removes the card from the player's hand, returning false if the player doesn't have it.
+    pub fn remove_from_hand(&mut self, card: Card) -> bool {
+        match self.hand.iter().position(|c| *c == card) {
+            Some(pos) => {
+                self.hand.remove(pos);
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub fn add_to_hand(&mut self, card: Card) {
+        self.hand.push(card);
+        self.hand.sort();
+    }
+
+    pub fn can_play(&self, card: Card) -> bool {
+        self.expeditions
+            .get(&card.color)
+            .map(|expedition| expedition.can_play(card.value))
+            .unwrap_or(true)
+    }
+
+    pub fn expedition(&self, color: Color) -> Option<&Expedition> {
+        self.expeditions.get(&color)
+    }
+
+    pub fn expedition_mut(&mut self, color: Color) -> &mut Expedition {
+        self.expeditions
+            .entry(color)
+            .or_default()
+    }
+
+    pub fn score(&self) -> i32 {
+        self.expeditions
+            .values()
+            .map(|expedition| expedition.score())
+            .sum()
+    }
+}
+
+impl Expedition {
+    /// Wagers can only be played before any number cards. Number cards must be played
+    /// in strictly increasing order.
+    pub fn can_play(&self, value: CardValue) -> bool {
+        match (self.values.last(), value) {
+            (None, _) => true,
+            (Some(CardValue::Wager), _) => true,
+            (Some(CardValue::Number(_)), CardValue::Wager) => false,
+            (Some(CardValue::Number(top)), CardValue::Number(n)) => n > *top,
+        }
+    }
+
+    /// Caller must check `can_play()` first.
+    pub fn play(&mut self, value: CardValue) {
+        assert!(self.can_play(value), "Illegal play to expedition");
+        self.values.push(value);
+    }
+
+    pub fn values(&self) -> &Vec<CardValue> {
+        &self.values
+    }
+
+    /// `(sum - 20) * (1 + wagers)`, plus a bonus of 20 if the expedition has at least 8 cards.
+    /// An expedition without any cards is worth 0.
+    pub fn score(&self) -> i32 {
+        if self.values.is_empty() {
+            return 0;
+        }
+
+        let mut sum = 0;
+        let mut num_wagers = 0;
+        for value in self.values.iter() {
+            match value {
+                CardValue::Wager => num_wagers += 1,
+                CardValue::Number(n) => sum += *n as i32,
+            }
+        }
+
+        let mut score = (sum - EXPEDITION_COST) * (1 + num_wagers);
+        if self.values.len() >= LONG_EXPEDITION_LENGTH {
+            score += LONG_EXPEDITION_BONUS;
+        }
+
+        score
+    }
+}
+
+impl DiscardPiles {
+    pub fn discard(&mut self, card: Card) {
+        self.piles
+            .entry(card.color)
+            .or_default()
+            .push(card.value);
+    }
+
+    pub fn draw(&mut self, color: Color) -> Option<Card> {
+        self.piles
+            .get_mut(&color)
+            .and_then(|pile| pile.pop())
+            .map(|value| Card::new(color, value))
+    }
+
+    /// Head => bottom of pile, Tail => top of pile
+    pub fn pile(&self, color: Color) -> &[CardValue] {
+        self.piles
+            .get(&color)
+            .map(|pile| pile.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+impl UnreadyPlayers {
+    pub fn new(player_ids: Vec<String>) -> Self {
+        UnreadyPlayers {
+            player_ids,
+        }
+    }
+
+    pub fn ready_up(&mut self, player_id: &str) {
+        if let Some(pos) = self.player_ids.iter().position(|x| x == player_id) {
+            self.player_ids.remove(pos);
+        }
+    }
+
+    pub fn all_ready(&self) -> bool {
+        self.player_ids.is_empty()
+    }
+
+    pub fn into_inner(self) -> Vec<String> {
+        self.player_ids
+    }
+}
@@ -0,0 +1,159 @@
+mod expedition_tests {
+    use crate::events::CardValue;
+    use crate::types::Expedition;
+
+    fn expedition_of(values: &[CardValue]) -> Expedition {
+        let mut expedition = Expedition::default();
+        for value in values {
+            expedition.play(*value);
+        }
+
+        expedition
+    }
+
+    #[test]
+    fn can_play_wagers_before_numbers_only() {
+        let expedition = expedition_of(&[CardValue::Wager, CardValue::Wager]);
+        assert!(expedition.can_play(CardValue::Wager));
+        assert!(expedition.can_play(CardValue::Number(2)));
+
+        let expedition = expedition_of(&[CardValue::Wager, CardValue::Number(4)]);
+        assert!(!expedition.can_play(CardValue::Wager));
+    }
+
+    #[test]
+    fn can_play_numbers_in_strictly_increasing_order() {
+        let expedition = expedition_of(&[CardValue::Number(5)]);
+        assert!(!expedition.can_play(CardValue::Number(4)));
+        assert!(!expedition.can_play(CardValue::Number(5)));
+        assert!(expedition.can_play(CardValue::Number(6)));
+        assert!(expedition.can_play(CardValue::Number(10)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn play_illegal_card_panics() {
+        expedition_of(&[CardValue::Number(5), CardValue::Number(3)]);
+    }
+
+    #[test]
+    fn score_empty_expedition() {
+        assert_eq!(0, Expedition::default().score());
+    }
+
+    #[test]
+    fn score_without_wagers() {
+        // 2 + 3 + 4 - 20
+        let expedition = expedition_of(&[CardValue::Number(2), CardValue::Number(3), CardValue::Number(4)]);
+        assert_eq!(-11, expedition.score());
+
+        // 7 + 8 + 9 - 20
+        let expedition = expedition_of(&[CardValue::Number(7), CardValue::Number(8), CardValue::Number(9)]);
+        assert_eq!(4, expedition.score());
+    }
+
+    #[test]
+    fn score_with_wagers() {
+        // Wagers multiply losses too: (0 - 20) * 3
+        let expedition = expedition_of(&[CardValue::Wager, CardValue::Wager]);
+        assert_eq!(-60, expedition.score());
+
+        // (9 + 10 - 20) * 2
+        let expedition = expedition_of(&[CardValue::Wager, CardValue::Number(9), CardValue::Number(10)]);
+        assert_eq!(-2, expedition.score());
+
+        // (6 + 7 + 8 + 9 - 20) * 2
+        let expedition = expedition_of(&[
+            CardValue::Wager,
+            CardValue::Number(6),
+            CardValue::Number(7),
+            CardValue::Number(8),
+            CardValue::Number(9),
+        ]);
+        assert_eq!(20, expedition.score());
+    }
+
+    #[test]
+    fn score_with_long_expedition_bonus() {
+        // 7 cards: (2 + 3 + 4 + 5 + 6 + 7 + 8 - 20) * 1
+        let values: Vec<CardValue> = (2..=8).map(CardValue::Number).collect();
+        assert_eq!(15, expedition_of(&values).score());
+
+        // 8 cards: (2 + 3 + 4 + 5 + 6 + 7 + 8 - 20) * 2 + 20
+        let mut values = vec![CardValue::Wager];
+        values.extend((2..=8).map(CardValue::Number));
+        assert_eq!(50, expedition_of(&values).score());
+    }
+}
+
+mod discard_piles_tests {
+    use crate::events::{Card, CardValue, Color};
+    use crate::types::DiscardPiles;
+
+    #[test]
+    fn draw_returns_top_of_pile() {
+        let mut piles = DiscardPiles::default();
+        piles.discard(Card::new(Color::Red, CardValue::Number(3)));
+        piles.discard(Card::new(Color::Red, CardValue::Wager));
+        piles.discard(Card::new(Color::Blue, CardValue::Number(7)));
+
+        assert_eq!(&[CardValue::Number(3), CardValue::Wager], piles.pile(Color::Red));
+        assert_eq!(Some(Card::new(Color::Red, CardValue::Wager)), piles.draw(Color::Red));
+        assert_eq!(Some(Card::new(Color::Red, CardValue::Number(3))), piles.draw(Color::Red));
+        assert_eq!(None, piles.draw(Color::Red));
+        assert_eq!(None, piles.draw(Color::Green));
+        assert_eq!(&[CardValue::Number(7)], piles.pile(Color::Blue));
+    }
+}
+
+mod snapshot_tests {
+    use crate::LostCitiesInstanceManager;
+    use crate::events::{Card, CardValue, Color};
+    use crate::types::DiscardPiles;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::prng::PrngRand;
+
+    #[test]
+    fn discard_piles_round_trip() {
+        let mut piles = DiscardPiles::default();
+        piles.discard(Card::new(Color::Red, CardValue::Number(3)));
+        piles.discard(Card::new(Color::Red, CardValue::Wager));
+
+        let json = serde_json::to_vec(&piles).unwrap();
+        let mut restored: DiscardPiles = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(Some(Card::new(Color::Red, CardValue::Wager)), restored.draw(Color::Red));
+        assert_eq!(&[CardValue::Number(3)], restored.pile(Color::Red));
+    }
+
+    #[test]
+    fn restored_game_has_same_snapshot() {
+        let player_ids = vec!["p1".to_string(), "p2".to_string()];
+        let game = LostCitiesInstanceManager::create_new_game(player_ids.clone(), (), PrngRand::new(42));
+
+        let snapshot = game.snapshot().unwrap();
+        let restored = LostCitiesInstanceManager::restore_game(&snapshot).unwrap();
+
+        assert_eq!(&player_ids, restored.player_ids());
+        // Compare as JSON values, since hash map iteration order isn't stable across instances.
+        let snapshot: serde_json::Value = serde_json::from_slice(&snapshot).unwrap();
+        let restored_snapshot: serde_json::Value = serde_json::from_slice(&restored.snapshot().unwrap()).unwrap();
+        assert_eq!(snapshot, restored_snapshot);
+    }
+
+    #[test]
+    fn same_rng_deals_same_game() {
+        let player_ids = vec!["p1".to_string(), "p2".to_string()];
+        let game = LostCitiesInstanceManager::create_new_game(player_ids.clone(), (), PrngRand::new(42));
+        let same_rng_game = LostCitiesInstanceManager::create_new_game(player_ids, (), PrngRand::new(42));
+
+        let snapshot: serde_json::Value = serde_json::from_slice(&game.snapshot().unwrap()).unwrap();
+        let same_rng_snapshot: serde_json::Value = serde_json::from_slice(&same_rng_game.snapshot().unwrap()).unwrap();
+        assert_eq!(snapshot, same_rng_snapshot);
+    }
+
+    #[test]
+    fn restore_rejects_invalid_snapshot() {
+        assert!(LostCitiesInstanceManager::restore_game(b"not a snapshot").is_err());
+    }
+}
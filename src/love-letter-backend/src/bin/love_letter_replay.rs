@@ -0,0 +1,86 @@
+use love_letter_backend::game_log::GameLog;
+use love_letter_backend::replay;
+use std::{fs, process};
+
+/// Prints the state of a Love Letter game after replaying its event log. The input file is either
+/// a game log, or a saved game from the backend server's game store (which includes its log).
+fn main() {
+    let cli_args = cli::CliArgs::parse();
+
+    let game_log = read_game_log(&cli_args.file_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read game log from '{}': {}", cli_args.file_path, e);
+        process::exit(1);
+    });
+
+    let num_events = cli_args.num_events.unwrap_or(game_log.events.len());
+    let game = replay::replay(&game_log, num_events).unwrap_or_else(|e| {
+        eprintln!("Failed to replay game: {}", e);
+        process::exit(1);
+    });
+
+    println!("Replayed {} of {} events. RNG seed: {}. Players: {:?}", num_events, game_log.events.len(), game_log.rng_seed, game_log.player_ids);
+    if let Some(last_event) = num_events.checked_sub(1).and_then(|i| game_log.events.get(i)) {
+        println!("Last event: {:?}", last_event);
+    }
+    println!("Wins: {:?}", game.wins_per_player());
+    println!("{}", serde_json::to_string_pretty(game.state()).expect("Game state is always serializable"));
+}
+
+fn read_game_log(file_path: &str) -> Result<GameLog, String> {
+    let bytes = fs::read(file_path).map_err(|e| e.to_string())?;
+    let mut json: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+
+    // Saved games wrap the log with the rest of the game's state.
+    let json = match json.get_mut("game_log") {
+        Some(game_log) => game_log.take(),
+        None => json,
+    };
+
+    serde_json::from_value(json).map_err(|e| e.to_string())
+}
+
+mod cli {
+    use std::{env, process};
+
+    pub struct CliArgs {
+        pub file_path: String,
+        pub num_events: Option<usize>,
+    }
+
+    impl CliArgs {
+        pub fn parse() -> Self {
+            let mut cli_args = env::args();
+
+            // Arg 0
+            let program_name = cli_args.next().unwrap_or_else(|| {
+                eprintln!("Program name is somehow missing? You should never see this.");
+                process::exit(1);
+            });
+
+            // Arg 1
+            let file_path = cli_args.next().unwrap_or_else(|| {
+                CliArgs::print_usage_exit(&program_name);
+            });
+
+            // Arg 2
+            let num_events = cli_args.next()
+                .map(|num_str| num_str.parse().unwrap_or_else(|_| {
+                    CliArgs::print_usage_exit(&program_name);
+                }));
+
+            CliArgs {
+                file_path,
+                num_events,
+            }
+        }
+
+        fn print_usage_exit(program_name: &str) -> ! {
+            eprintln!();
+            eprintln!("Usage:  \t{} <game log or saved game file> [number of events to replay]", program_name);
+            eprintln!("Example:\t{} ./saved-games/1-67616d65.game", program_name);
+            eprintln!("Example:\t{} ./game-log.json 12", program_name);
+            eprintln!();
+            process::exit(1);
+        }
+    }
+}
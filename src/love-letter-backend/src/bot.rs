@@ -0,0 +1,252 @@
+use crate::deck;
+use crate::events::Card;
+use crate::options::DeckVariant;
+use crate::state_machine::PlayerTargeting;
+use backend_framework::bot::Bot;
+use backend_framework::prng::PrngRand;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoGameDataReadyUpClick, ProtoGameDataStateReq, ProtoLoveLetterDataIn, ProtoLoveLetterDataOut, ProtoLvLeCard, ProtoLvLeCommitSelectionReq, ProtoLvLeGameState, ProtoLvLePlayCardReq, ProtoLvLeSelectTargetCard, ProtoLvLeSelectTargetPlayer};
+use backend_framework::wire_api::proto_frj_ngn::proto_love_letter_data_in::ProtoLvLeIn;
+use backend_framework::wire_api::proto_frj_ngn::proto_love_letter_data_out::ProtoLvLeOut;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_card_selection::Inner;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::proto_lv_le_round_state::Turn;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::{ProtoLvLeRoundState, Stage};
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_options::ProtoLvLeDeckVariant;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_play_card_req::ProtoLvLeCardSource;
+use rand::seq::SliceRandom;
+use std::convert::TryFrom;
+
+/// Plays by the rules, with a simple strategy:
+/// * Keeps the higher card, and never plays the Princess.
+/// * Targets a random other player, and never makes itself discard the Princess with the Prince.
+/// * Counts the cards it has seen, so the Guard and Bishop guess the most likely unseen card.
+/// * Readies up right away.
+pub struct LoveLetterBot {
+    player_id: String,
+    rng: PrngRand,
+    game_state: Option<ProtoLvLeGameState>,
+}
+
+impl LoveLetterBot {
+    pub fn new(player_id: String, rng: PrngRand) -> Self {
+        LoveLetterBot {
+            player_id,
+            rng,
+            game_state: None,
+        }
+    }
+
+    fn round_moves(&mut self, round: &ProtoLvLeRoundState, deck_variant: DeckVariant) -> Vec<ProtoLvLeIn> {
+        let is_my_turn = match &round.turn {
+            Some(Turn::TurnIntermission(turn_intermission)) => {
+                return self.ready_up_if_unready(&turn_intermission.unready_player_ids);
+            },
+            Some(Turn::MyDrawnCard(_)) => true,
+            Some(Turn::CurrentTurnPlayerId(player_id)) => player_id == &self.player_id,
+            None => false,
+        };
+        if !is_my_turn {
+            return Vec::new();
+        }
+
+        match (&round.staged_play, &round.turn) {
+            (None, Some(Turn::MyDrawnCard(drawn_card))) => {
+                match (to_card(round.my_hand), to_card(*drawn_card)) {
+                    // Staging a card doesn't send a new game state, so the bot asks for it.
+                    (Some(hand_card), Some(drawn_card)) => vec![
+                        play_card(card_source_to_play(hand_card, drawn_card, deck_variant)),
+                        ProtoLvLeIn::GameState(ProtoGameDataStateReq {}),
+                    ],
+                    _ => Vec::new(),
+                }
+            },
+            (Some(_), _) => self.selections_and_commit(round, deck_variant),
+            // The drawn card is always sent while a play is pending.
+            (None, _) => Vec::new(),
+        }
+    }
+
+    fn ready_up_if_unready(&self, unready_player_ids: &[String]) -> Vec<ProtoLvLeIn> {
+        if unready_player_ids.contains(&self.player_id) {
+            vec![ProtoLvLeIn::ReadyUp(ProtoGameDataReadyUpClick {})]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Same rules as `LoveLetterStateMachine::default_selections()`, with better choices.
+    fn selections_and_commit(&mut self, round: &ProtoLvLeRoundState, deck_variant: DeckVariant) -> Vec<ProtoLvLeIn> {
+        let played_card = match round.discards.get(&self.player_id).and_then(|discards| discards.cards.last()) {
+            Some(played_card) => to_card(*played_card),
+            None => None,
+        };
+        let (played_card, kept_card) = match (played_card, to_card(round.my_hand)) {
+            (Some(played_card), Some(kept_card)) => (played_card, kept_card),
+            _ => return Vec::new(),
+        };
+
+        let targetable_player_ids: Vec<&String> = round.remaining_player_ids
+            .iter()
+            .filter(|player_id| !round.handmaid_player_ids.contains(player_id))
+            .collect();
+
+        let mut moves = Vec::new();
+        if let Some(targeting) = PlayerTargeting::of(played_card) {
+            let others: Vec<&String> = targetable_player_ids
+                .iter()
+                .copied()
+                .filter(|player_id| *player_id != &self.player_id)
+                .collect();
+            let has_valid_target = match targeting {
+                PlayerTargeting::OneAny => true,
+                PlayerTargeting::OneOther | PlayerTargeting::UpToTwoOthers => !others.is_empty(),
+                PlayerTargeting::TwoAny => targetable_player_ids.len() >= 2,
+            };
+            // Nobody can be targeted, so the card is committed as is and has no effect.
+            if !has_valid_target {
+                return vec![commit()];
+            }
+
+            let mut selected_player_ids: Vec<&String> = Vec::new();
+            if !round.sycophant_player_id.is_empty() {
+                selected_player_ids.push(&round.sycophant_player_id);
+            }
+
+            let num_targets_needed: usize = if let PlayerTargeting::TwoAny = targeting { 2 } else { 1 };
+            let mut candidate_player_ids: Vec<&String> = others
+                .into_iter()
+                .filter(|player_id| !selected_player_ids.contains(player_id))
+                .collect();
+            candidate_player_ids.shuffle(&mut self.rng);
+
+            // Self is the last resort, and only if the bot doesn't discard its Princess (unless
+            // there's nobody else to pick).
+            let num_missing = num_targets_needed.saturating_sub(selected_player_ids.len());
+            if targeting.can_target_self() && !selected_player_ids.contains(&&self.player_id) {
+                let discards_princess = played_card == Card::Prince && kept_card == Card::Princess;
+                if !discards_princess || candidate_player_ids.len() < num_missing {
+                    candidate_player_ids.push(&self.player_id);
+                }
+            }
+
+            selected_player_ids.extend(candidate_player_ids.into_iter().take(num_missing));
+            moves.extend(selected_player_ids
+                .into_iter()
+                .map(|player_id| ProtoLvLeIn::SelectTargetPlayer(ProtoLvLeSelectTargetPlayer {
+                    target_player_id: player_id.clone(),
+                })));
+        }
+
+        let staged_selection = round.staged_play.as_ref().and_then(|staged_play| staged_play.inner.as_ref());
+        let target_card = match (played_card, staged_selection) {
+            (Card::Guard, _) | (Card::Bishop, _) => Some(most_likely_unseen_card(round, kept_card, deck_variant)),
+            (Card::Chancellor, Some(Inner::Chancellor(chancellor))) if !chancellor.opt_drawn_cards.is_empty() => {
+                chancellor.opt_drawn_cards
+                    .iter()
+                    .filter_map(|card| to_card(*card))
+                    .chain(std::iter::once(kept_card))
+                    .max_by_key(|card| card.value(deck_variant))
+            },
+            _ => None,
+        };
+        moves.extend(target_card.map(|card| ProtoLvLeIn::SelectTargetCard(ProtoLvLeSelectTargetCard {
+            target_card: ProtoLvLeCard::from(card) as i32,
+        })));
+
+        moves.push(commit());
+        moves
+    }
+}
+
+impl Bot for LoveLetterBot {
+    type DataIn = ProtoLoveLetterDataIn;
+    type DataOut = ProtoLoveLetterDataOut;
+
+    fn observe(&mut self, message: ProtoLoveLetterDataOut) {
+        if let Some(ProtoLvLeOut::GameState(game_state)) = message.proto_lv_le_out {
+            self.game_state = Some(game_state);
+        }
+    }
+
+    fn next_moves(&mut self) -> Vec<ProtoLoveLetterDataIn> {
+        let game_state = match self.game_state.take() {
+            Some(game_state) => game_state,
+            None => return Vec::new(),
+        };
+        let deck_variant = ProtoLvLeDeckVariant::try_from(game_state.deck_variant)
+            .map(DeckVariant::from)
+            .unwrap_or_default();
+
+        let moves = match &game_state.stage {
+            Some(Stage::RoundInProgress(round)) => self.round_moves(round, deck_variant),
+            Some(Stage::RoundIntermission(result)) => self.ready_up_if_unready(&result.unready_player_ids),
+            Some(Stage::GameComplete(_)) | None => Vec::new(),
+        };
+        self.game_state = Some(game_state);
+
+        moves.into_iter()
+            .map(|proto_lv_le_in| ProtoLoveLetterDataIn {
+                clock: 0,
+                proto_lv_le_in: Some(proto_lv_le_in),
+            })
+            .collect()
+    }
+}
+
+fn to_card(proto_card: i32) -> Option<Card> {
+    ProtoLvLeCard::try_from(proto_card)
+        .ok()
+        .and_then(|proto_card| Card::try_from(proto_card).ok())
+}
+
+fn play_card(card_source: ProtoLvLeCardSource) -> ProtoLvLeIn {
+    ProtoLvLeIn::PlayCard(ProtoLvLePlayCardReq {
+        card_source: card_source as i32,
+    })
+}
+
+fn commit() -> ProtoLvLeIn {
+    ProtoLvLeIn::CommitSelection(ProtoLvLeCommitSelectionReq {})
+}
+
+/// The Countess is played when it must be, and the Princess never is. Otherwise, the higher card
+/// is kept.
+pub(crate) fn card_source_to_play(hand_card: Card, drawn_card: Card, deck_variant: DeckVariant) -> ProtoLvLeCardSource {
+    let must_play_countess = |card: Card, other_card: Card| {
+        card == Card::Countess && (other_card == Card::King || other_card == Card::Prince)
+    };
+
+    if must_play_countess(hand_card, drawn_card) || drawn_card == Card::Princess {
+        ProtoLvLeCardSource::Hand
+    } else if must_play_countess(drawn_card, hand_card)
+        || hand_card == Card::Princess
+        || drawn_card.value(deck_variant) < hand_card.value(deck_variant) {
+        ProtoLvLeCardSource::TopDeck
+    } else {
+        ProtoLvLeCardSource::Hand
+    }
+}
+
+/// The card that other players are most likely to hold, based on every card the bot has seen:
+/// its own card, all discards, and the face up cards. Ties go to the higher card. Guards can't be
+/// guessed.
+pub(crate) fn most_likely_unseen_card(round: &ProtoLvLeRoundState, kept_card: Card, deck_variant: DeckVariant) -> Card {
+    let deck = deck::new_unshuffled_deck(deck_variant);
+    let seen_cards: Vec<Card> = round.discards
+        .values()
+        .flat_map(|discards| discards.cards.iter())
+        .chain(round.face_up_cards.iter())
+        .filter_map(|card| to_card(*card))
+        .chain(std::iter::once(kept_card))
+        .collect();
+    let unseen_count = |card: &Card| {
+        let count = |cards: &Vec<Card>| cards.iter().filter(|c| *c == card).count();
+        count(&deck).saturating_sub(count(&seen_cards))
+    };
+
+    let guard_value = Card::Guard.value(deck_variant);
+    deck::distinct_cards(deck_variant)
+        .into_iter()
+        .filter(|card| card.value(deck_variant) != guard_value)
+        .max_by_key(|card| (unseen_count(card), card.value(deck_variant)))
+        .expect("Every deck has cards other than the Guard")
+}
@@ -0,0 +1,112 @@
+mod bot_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::bot::{self, LoveLetterBot};
+    use crate::events::{Card, LoveLetterEventType};
+    use crate::options::{DeckVariant, LoveLetterOptions};
+    use crate::plugin::LoveLetterPlugin;
+    use crate::test_util::{client_info, event};
+    use backend_framework::bot::Bot;
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::game_plugin::GamePlugin;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoLoveLetterDataOut, ProtoLvLeCard};
+    use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::{ProtoLvLeDiscards, ProtoLvLeRoundState};
+    use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_play_card_req::ProtoLvLeCardSource;
+
+    /// Bots play a whole game against each other. Any rejected move fails the test, since it
+    /// would leave the bot waiting forever.
+    fn play_bots_only_game(num_players: usize, deck_variant: DeckVariant, seed: u64) {
+        let player_ids: Vec<String> = (1..=num_players).map(|i| format!("bot:{}", i)).collect();
+        let options = LoveLetterOptions {
+            deck_variant,
+            ..LoveLetterOptions::default()
+        };
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids.clone(), options, seed);
+
+        let mut bots: Vec<(String, LoveLetterBot, StreamReceiver<ProtoLoveLetterDataOut>)> = Vec::new();
+        for player_id in player_ids {
+            let (stream_out, stream_rcv) = streaming::stream_channel(OverflowPolicy::DropOldest);
+            game.handle_event(event(&player_id, LoveLetterEventType::RegisterDataStream(stream_out)));
+            let bot = LoveLetterBot::new(player_id.clone(), PrngRand::new(seed));
+            bots.push((player_id, bot, stream_rcv));
+        }
+
+        for _ in 0..10_000 {
+            if game.is_game_complete() {
+                return;
+            }
+
+            for (player_id, bot, stream_rcv) in bots.iter_mut() {
+                let mut has_observed = false;
+                while let Some(message) = stream_rcv.try_next() {
+                    let message = message.unwrap_or_else(|status| panic!("Move by '{}' was rejected: {:?}", player_id, status));
+                    bot.observe(message);
+                    has_observed = true;
+                }
+                if !has_observed {
+                    continue;
+                }
+
+                for message in bot.next_moves() {
+                    let event = LoveLetterPlugin.decode_event(client_info(player_id), message).expect("Bot sends valid messages");
+                    game.handle_event(event);
+                }
+            }
+        }
+
+        panic!("Bots didn't finish the game: {}", serde_json::to_string(game.state()).unwrap());
+    }
+
+    #[test]
+    fn bots_play_a_classic_game() {
+        for seed in 1..=10 {
+            play_bots_only_game(4, DeckVariant::Classic, seed);
+        }
+    }
+
+    #[test]
+    fn bots_play_a_two_player_game() {
+        for seed in 1..=10 {
+            play_bots_only_game(2, DeckVariant::Revised2019, seed);
+        }
+    }
+
+    #[test]
+    fn bots_play_a_premium_game() {
+        for seed in 1..=10 {
+            play_bots_only_game(8, DeckVariant::Premium, seed);
+        }
+    }
+
+    fn round(my_discards: Vec<ProtoLvLeCard>) -> ProtoLvLeRoundState {
+        let cards = my_discards.into_iter().map(|card| card as i32).collect();
+        let mut round = ProtoLvLeRoundState::default();
+        round.discards.insert("bot:1".to_string(), ProtoLvLeDiscards { cards });
+        round
+    }
+
+    #[test]
+    fn guard_guesses_the_most_likely_unseen_card() {
+        // 2 of each of the Priest, Baron, Handmaid and Prince, so the highest is guessed.
+        let guess = bot::most_likely_unseen_card(&round(Vec::new()), Card::King, DeckVariant::Classic);
+        assert_eq!(Card::Prince, guess);
+
+        // Only the Handmaids are all unseen.
+        let seen = round(vec![ProtoLvLeCard::Priest, ProtoLvLeCard::Priest, ProtoLvLeCard::Baron, ProtoLvLeCard::Prince]);
+        let guess = bot::most_likely_unseen_card(&seen, Card::Prince, DeckVariant::Classic);
+        assert_eq!(Card::Handmaid, guess);
+    }
+
+    #[test]
+    fn princess_is_kept_and_countess_is_forced() {
+        let play = |hand_card, drawn_card| bot::card_source_to_play(hand_card, drawn_card, DeckVariant::Classic);
+
+        assert_eq!(ProtoLvLeCardSource::Hand, play(Card::Guard, Card::Baron));
+        assert_eq!(ProtoLvLeCardSource::TopDeck, play(Card::Baron, Card::Guard));
+        assert_eq!(ProtoLvLeCardSource::TopDeck, play(Card::Princess, Card::Guard));
+        assert_eq!(ProtoLvLeCardSource::Hand, play(Card::Countess, Card::King));
+        assert_eq!(ProtoLvLeCardSource::TopDeck, play(Card::Prince, Card::Countess));
+    }
+}
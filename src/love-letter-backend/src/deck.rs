@@ -1,13 +1,56 @@
 use crate::events::Card;
+use crate::options::DeckVariant;
+use backend_framework::prng::PrngRand;
 use backend_framework::shuffler;
 
-pub fn new_shuffled_deck() -> Vec<Card> {
-    let (deck, rng_seed) = shuffler::shuffle(new_unshuffled_deck());
-    println!("INFO: Deck created with RNG seed '{}'", rng_seed);
+pub fn new_shuffled_deck(deck_variant: DeckVariant, rng: &mut PrngRand) -> Vec<Card> {
+    shuffler::shuffle_with(new_unshuffled_deck(deck_variant), rng)
+}
+
+pub(crate) fn new_unshuffled_deck(deck_variant: DeckVariant) -> Vec<Card> {
+    let mut deck = new_classic_deck();
+
+    match deck_variant {
+        DeckVariant::Classic => {},
+        DeckVariant::Revised2019 => deck.extend(vec![
+            // 6x Guard in total
+            Card::Guard,
+
+            // 2x of each
+            Card::Spy,
+            Card::Spy,
+            Card::Chancellor,
+            Card::Chancellor,
+        ]),
+        DeckVariant::Premium => deck.extend(vec![
+            // 8x Guard in total
+            Card::Guard,
+            Card::Guard,
+            Card::Guard,
+
+            // 2x of each
+            Card::Cardinal,
+            Card::Cardinal,
+            Card::Baroness,
+            Card::Baroness,
+            Card::Sycophant,
+            Card::Sycophant,
+            Card::Count,
+            Card::Count,
+
+            // 1x of each
+            Card::Jester,
+            Card::Assassin,
+            Card::Constable,
+            Card::DowagerQueen,
+            Card::Bishop,
+        ]),
+    }
+
     deck
 }
 
-fn new_unshuffled_deck() -> Vec<Card> {
+fn new_classic_deck() -> Vec<Card> {
     vec![
         // 5x Guard
         Card::Guard,
@@ -31,4 +74,21 @@ fn new_unshuffled_deck() -> Vec<Card> {
         Card::Countess,
         Card::Princess,
     ]
-}
\ No newline at end of file
+}
+
+/// True if the card is part of the deck, and can therefore be guessed.
+pub fn contains(deck_variant: DeckVariant, card: Card) -> bool {
+    new_unshuffled_deck(deck_variant).contains(&card)
+}
+
+/// Each card in the deck once.
+pub fn distinct_cards(deck_variant: DeckVariant) -> Vec<Card> {
+    let mut distinct_cards = Vec::new();
+    for card in new_unshuffled_deck(deck_variant) {
+        if !distinct_cards.contains(&card) {
+            distinct_cards.push(card);
+        }
+    }
+
+    distinct_cards
+}
@@ -1,6 +1,8 @@
+use crate::options::DeckVariant;
 use backend_framework::common_types::ClientInfo;
 use backend_framework::streaming::StreamSender;
 use backend_framework::wire_api::proto_frj_ngn::ProtoLoveLetterDataOut;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
 pub struct LoveLetterEvent {
@@ -13,6 +15,10 @@ pub struct LoveLetterEvent {
 pub enum LoveLetterEventType {
     // Common
     RegisterDataStream(StreamSender<ProtoLoveLetterDataOut>),
+    RegisterSpectatorStream {
+        god_view: bool,
+        stream: StreamSender<ProtoLoveLetterDataOut>,
+    },
     GetGameState,
     ReadyUp,
 
@@ -23,32 +29,63 @@ pub enum LoveLetterEventType {
     PlayCardCommit,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PlayCardSource {
     Hand,
     TopDeck,
 }
 
 /// Input:
-/// 1 - Guard    : `(String, Card)` - the player+card that is guessed
-/// 2 - Priest   : `(String)` - player to view card
-/// 3 - Baron    : `(String)` - player to compare with
-/// 4 - Handmaid : `()`
-/// 5 - Prince   : `(String)` - player to discard/replace their card
-/// 6 - King     : `(String)` - player to swap with
-/// 7 - Countess : `()`
-/// 8 - Princess : `()`
+/// 1 - Guard         : `(String, Card)` - the player+card that is guessed
+/// 2 - Priest        : `(String)` - player to view card
+/// 3 - Baron         : `(String)` - player to compare with
+/// 4 - Handmaid      : `()`
+/// 5 - Prince        : `(String)` - player to discard/replace their card
+/// 6 - King          : `(String)` - player to swap with
+/// 7 - Countess      : `()`
+/// 8 - Princess      : `()`
+/// 2019 edition:
+/// 0 - Spy           : `()`
+/// 6 - Chancellor    : `(Card)` - the card to keep, out of your hand and the 2 drawn cards
+/// Premium edition:
+/// 0 - Jester        : `(String)` - player who you think will win the round
+/// 0 - Assassin      : `()`
+/// 2 - Cardinal      : `(String, String)` - players to swap cards, you view the first one's new card
+/// 3 - Baroness      : `(String, Option<String>)` - 1 or 2 players to view cards
+/// 4 - Sycophant     : `(String)` - player who the next played card must target
+/// 5 - Count         : `()`
+/// 6 - Constable     : `()`
+/// 7 - Dowager Queen : `(String)` - player to compare with
+/// 9 - Bishop        : `(String, Card)` - the player+card that is guessed
 ///
 /// Outcome:
-/// 1 - Guard    : `(bool)` - was guess correct
-/// 2 - Priest   : `()`
-/// 3 - Baron    : `(String, Card)` - the player+card that was knocked out
-/// 4 - Handmaid : `()`
-/// 5 - Prince   : `(Card)` - the discarded card
-/// 6 - King     : `()`
-/// 7 - Countess : `()`
-/// 8 - Princess : `()`
-#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+/// 1 - Guard         : `(bool)` - was guess correct, or `(String)` - Guard player was knocked out by an Assassin
+/// 2 - Priest        : `()`
+/// 3 - Baron         : `(String, Card)` - the player+card that was knocked out
+/// 4 - Handmaid      : `()`
+/// 5 - Prince        : `(Card)` - the discarded card
+/// 6 - King          : `()`
+/// 7 - Countess      : `()`
+/// 8 - Princess      : `()`
+/// 2019 edition:
+/// 0 - Spy           : `()`
+/// 6 - Chancellor    : `()`
+/// Premium edition:
+/// 0 - Jester        : `()`
+/// 0 - Assassin      : `()`
+/// 2 - Cardinal      : `()`
+/// 3 - Baroness      : `()`
+/// 4 - Sycophant     : `()`
+/// 5 - Count         : `()`
+/// 6 - Constable     : `()`
+/// 7 - Dowager Queen : `(String, Card)` - the player+card that was knocked out
+/// 9 - Bishop        : `(bool, Card)` - was guess correct, and the card the target discarded
+///
+/// A card which targets players has no selection and no outcome if every player it could target
+/// has Handmaid protection.
+///
+/// The number of each card depends on the edition, see `Card::value()`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum Card {
     /// 1 - Guesses another player's card, if correct, other player is out. Can't guess Guard(1).
     Guard,
@@ -73,4 +110,75 @@ pub enum Card {
 
     /// 8 - If this card is ever discarded, that player is out.
     Princess,
+
+    // ---- 2019 edition ----
+
+    /// 0 - At the end of the round, if you're the only player left in the round who has
+    /// discarded a Spy, you gain a token.
+    Spy,
+
+    /// 6 - Draw 2 cards. Keep 1 of the 3 cards you have, and put the other 2 on the bottom of
+    /// the deck.
+    Chancellor,
+
+    // ---- Premium edition ----
+
+    /// 0 - Choose another player. If they win the round, you gain a token.
+    Jester,
+
+    /// 0 - If another player's Guard targets you, they're out instead. You discard the Assassin
+    /// and draw a new card.
+    Assassin,
+
+    /// 2 - Choose 2 players (including self) to trade hands. See the first player's new card.
+    Cardinal,
+
+    /// 3 - See 1 or 2 other players' cards.
+    Baroness,
+
+    /// 4 - Choose any player (including self). The next card that's played must target them.
+    Sycophant,
+
+    /// 5 - At the end of the round, adds 1 to the value of your card.
+    Count,
+
+    /// 6 - If you're knocked out of the round while the Constable is in your discards, you gain
+    /// a token.
+    Constable,
+
+    /// 7 - Privately compare card with another player. Higher card is out.
+    DowagerQueen,
+
+    /// 9 - Guesses another player's card, if correct, you gain a token. The other player
+    /// discards their card and draws a new one. Can't guess Guard(1). Loses to the Princess at
+    /// the end of the round.
+    Bishop,
+}
+
+impl Card {
+    /// The number printed on the card. The 2019 edition renumbered the King, Countess and
+    /// Princess, to make room for the Chancellor.
+    pub fn value(self, deck_variant: DeckVariant) -> u8 {
+        match (self, deck_variant) {
+            (Card::King, DeckVariant::Revised2019) => 7,
+            (Card::Countess, DeckVariant::Revised2019) => 8,
+            (Card::Princess, DeckVariant::Revised2019) => 9,
+            _ => self.classic_value(),
+        }
+    }
+
+    fn classic_value(self) -> u8 {
+        match self {
+            Card::Spy | Card::Jester | Card::Assassin => 0,
+            Card::Guard => 1,
+            Card::Priest | Card::Cardinal => 2,
+            Card::Baron | Card::Baroness => 3,
+            Card::Handmaid | Card::Sycophant => 4,
+            Card::Prince | Card::Count => 5,
+            Card::King | Card::Chancellor | Card::Constable => 6,
+            Card::Countess | Card::DowagerQueen => 7,
+            Card::Princess => 8,
+            Card::Bishop => 9,
+        }
+    }
 }
@@ -0,0 +1,70 @@
+use crate::events::{Card, LoveLetterEventType, PlayCardSource};
+use crate::options::LoveLetterOptions;
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to deterministically reproduce a game: its initial conditions, and every
+/// event that the game accepted, in order. See `replay` for rebuilding a game from its log.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameLog {
+    pub rng_seed: u64,
+    pub player_ids: Vec<String>,
+    /// Logs from before options existed were played with the defaults.
+    #[serde(default)]
+    pub options: LoveLetterOptions,
+    pub events: Vec<LoggedEvent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoggedEvent {
+    pub player_id: String,
+    pub event: LoggedEventType,
+}
+
+/// The subset of `LoveLetterEventType` which can change the game's state. Streams and state
+/// requests are left out, they only affect what is sent to players.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LoggedEventType {
+    ReadyUp,
+    PlayCardStaged(PlayCardSource),
+    SelectTargetPlayer(String),
+    SelectTargetCard(Card),
+    PlayCardCommit,
+}
+
+impl GameLog {
+    pub fn new(rng_seed: u64, player_ids: Vec<String>, options: LoveLetterOptions) -> Self {
+        GameLog {
+            rng_seed,
+            player_ids,
+            options,
+            events: Vec::new(),
+        }
+    }
+}
+
+impl LoggedEventType {
+    pub fn from_event(event: &LoveLetterEventType) -> Option<Self> {
+        match event {
+            LoveLetterEventType::RegisterDataStream(_) => None,
+            LoveLetterEventType::RegisterSpectatorStream { .. } => None,
+            LoveLetterEventType::GetGameState => None,
+            LoveLetterEventType::ReadyUp => Some(LoggedEventType::ReadyUp),
+            LoveLetterEventType::PlayCardStaged(card_source) => Some(LoggedEventType::PlayCardStaged(*card_source)),
+            LoveLetterEventType::SelectTargetPlayer(player_id) => Some(LoggedEventType::SelectTargetPlayer(player_id.clone())),
+            LoveLetterEventType::SelectTargetCard(card) => Some(LoggedEventType::SelectTargetCard(*card)),
+            LoveLetterEventType::PlayCardCommit => Some(LoggedEventType::PlayCardCommit),
+        }
+    }
+}
+
+impl From<LoggedEventType> for LoveLetterEventType {
+    fn from(event: LoggedEventType) -> Self {
+        match event {
+            LoggedEventType::ReadyUp => LoveLetterEventType::ReadyUp,
+            LoggedEventType::PlayCardStaged(card_source) => LoveLetterEventType::PlayCardStaged(card_source),
+            LoggedEventType::SelectTargetPlayer(player_id) => LoveLetterEventType::SelectTargetPlayer(player_id),
+            LoggedEventType::SelectTargetCard(card) => LoveLetterEventType::SelectTargetCard(card),
+            LoggedEventType::PlayCardCommit => LoveLetterEventType::PlayCardCommit,
+        }
+    }
+}
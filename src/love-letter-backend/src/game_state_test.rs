@@ -0,0 +1,46 @@
+mod game_state_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::{LoveLetterEventType, PlayCardSource};
+    use crate::options::LoveLetterOptions;
+    use crate::state_machine::LoveLetterState;
+    use crate::test_util::{event, open_stream, player_ids};
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::streaming::StreamReceiver;
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoLoveLetterDataOut, ProtoLvLeCard};
+    use backend_framework::wire_api::proto_frj_ngn::proto_love_letter_data_out::ProtoLvLeOut;
+    use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::{Stage, proto_lv_le_round_state::Turn};
+    use tokio::stream::StreamExt;
+
+    async fn recv_turn(stream_rcv: &mut StreamReceiver<ProtoLoveLetterDataOut>) -> Option<Turn> {
+        let message = stream_rcv.next().await
+            .expect("Stream should have a message")
+            .expect("Message should not be an error");
+        match message.proto_lv_le_out {
+            Some(ProtoLvLeOut::GameState(game_state)) => match game_state.stage {
+                Some(Stage::RoundInProgress(round_state)) => round_state.turn,
+                other => panic!("Expected round in progress, got {:?}", other),
+            },
+            other => panic!("Expected game state, got {:?}", other),
+        }
+    }
+
+    /// Once a play is staged, the drawn card has left the deck, so the top of the deck is the
+    /// next player's card.
+    #[tokio::test]
+    async fn drawn_card_is_only_sent_until_a_play_is_staged() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        let (player_id, drawn_card) = match game.state() {
+            LoveLetterState::PlayPending(round_data) => (
+                round_data.players.current_turn_player_id().clone(),
+                *round_data.deck.last().expect("Deck should have cards left"),
+            ),
+            _ => panic!("Game should start with a play pending"),
+        };
+        let mut stream_rcv = open_stream(&mut game, &player_id);
+        assert_eq!(Some(Turn::MyDrawnCard(ProtoLvLeCard::from(drawn_card) as i32)), recv_turn(&mut stream_rcv).await);
+
+        game.handle_event(event(&player_id, LoveLetterEventType::PlayCardStaged(PlayCardSource::TopDeck)));
+        game.handle_event(event(&player_id, LoveLetterEventType::GetGameState));
+        assert_eq!(Some(Turn::CurrentTurnPlayerId(player_id)), recv_turn(&mut stream_rcv).await);
+    }
+}
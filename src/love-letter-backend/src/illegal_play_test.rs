@@ -0,0 +1,109 @@
+mod illegal_play_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::{Card, LoveLetterEventType, PlayCardSource};
+    use crate::options::LoveLetterOptions;
+    use crate::state_machine::LoveLetterState;
+    use crate::test_util::event;
+    use crate::types::{RoundData, StagedPlay};
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoLoveLetterDataOut, ProtoLvLeIllegalPlay};
+    use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_illegal_play::ProtoLvLeIllegalPlayReason;
+    use prost::Message;
+    use tokio::stream::StreamExt;
+    use tonic::Code;
+
+    /// Returns the game, with the current player's stream already past its initial game state.
+    async fn setup(
+        update_round: impl FnOnce(&mut RoundData, &str),
+    ) -> (LoveLetterInstanceManager, String, StreamReceiver<ProtoLoveLetterDataOut>) {
+        let player_ids = vec!["p1".to_string(), "p2".to_string(), "p3".to_string()];
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids, LoveLetterOptions::default(), 42);
+
+        let mut round_data = match game.state.take() {
+            LoveLetterState::PlayPending(round_data) => round_data,
+            _ => panic!("Game should start with a play pending"),
+        };
+        let player_id = round_data.players.current_turn_player_id().clone();
+        update_round(&mut round_data, &player_id);
+        game.state.put(LoveLetterState::PlayPending(round_data));
+
+        let (stream_out, mut stream_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+        game.handle_event(event(&player_id, LoveLetterEventType::RegisterDataStream(stream_out)));
+        stream_rcv.next().await.unwrap().expect("Initial game state");
+
+        (game, player_id, stream_rcv)
+    }
+
+    async fn recv_illegal_play_reason(stream_rcv: &mut StreamReceiver<ProtoLoveLetterDataOut>) -> ProtoLvLeIllegalPlayReason {
+        let status = stream_rcv.next().await
+            .expect("Stream should have a message")
+            .expect_err("Play should be rejected");
+        assert_eq!(Code::FailedPrecondition, status.code());
+
+        let details = base64::decode(status.details()).expect("Status details are base64 encoded");
+        let illegal_play = ProtoLvLeIllegalPlay::decode(details.as_slice()).expect("Status details are a ProtoLvLeIllegalPlay");
+        ProtoLvLeIllegalPlayReason::from_i32(illegal_play.reason).expect("Reason is a known enum value")
+    }
+
+    #[tokio::test]
+    async fn countess_must_be_played_with_king() {
+        let (mut game, player_id, mut stream_rcv) = setup(|round_data, player_id| {
+            round_data.players.replace_card(player_id.to_string(), Card::King);
+            round_data.deck.push(Card::Countess);
+        }).await;
+
+        game.handle_event(event(&player_id, LoveLetterEventType::PlayCardStaged(PlayCardSource::Hand)));
+        assert_eq!(ProtoLvLeIllegalPlayReason::CountessMustBePlayed, recv_illegal_play_reason(&mut stream_rcv).await);
+        assert!(matches!(game.state(), LoveLetterState::PlayPending(_)));
+        assert!(game.game_log().events.is_empty());
+
+        game.handle_event(event(&player_id, LoveLetterEventType::PlayCardStaged(PlayCardSource::TopDeck)));
+        match game.state() {
+            LoveLetterState::PlayStaging(_, staged_play) => assert_eq!(Card::Countess, staged_play.played_card),
+            _ => panic!("Countess should be staged"),
+        }
+    }
+
+    #[tokio::test]
+    async fn countess_can_be_kept_with_other_cards() {
+        let (mut game, player_id, _stream_rcv) = setup(|round_data, player_id| {
+            round_data.players.replace_card(player_id.to_string(), Card::Countess);
+            round_data.deck.push(Card::Guard);
+        }).await;
+
+        game.handle_event(event(&player_id, LoveLetterEventType::PlayCardStaged(PlayCardSource::TopDeck)));
+        assert!(matches!(game.state(), LoveLetterState::PlayStaging(_, _)));
+    }
+
+    #[tokio::test]
+    async fn cant_target_handmaid_player() {
+        let (mut game, player_id, mut stream_rcv) = setup(|round_data, _| {
+            round_data.handmaid_immunity_player_ids.insert("p2".to_string());
+            round_data.handmaid_immunity_player_ids.insert("p3".to_string());
+        }).await;
+        let round_data = match game.state.take() {
+            LoveLetterState::PlayPending(round_data) => round_data,
+            _ => panic!("Game should start with a play pending"),
+        };
+        game.state.put(LoveLetterState::PlayStaging(round_data, StagedPlay::new(Card::Priest)));
+
+        let target_player_id = if player_id == "p2" { "p3" } else { "p2" };
+        game.handle_event(event(&player_id, LoveLetterEventType::SelectTargetPlayer(target_player_id.to_string())));
+        assert_eq!(ProtoLvLeIllegalPlayReason::TargetHasHandmaid, recv_illegal_play_reason(&mut stream_rcv).await);
+    }
+
+    #[tokio::test]
+    async fn guard_cant_guess_guard() {
+        let (mut game, player_id, mut stream_rcv) = setup(|_, _| {}).await;
+        let round_data = match game.state.take() {
+            LoveLetterState::PlayPending(round_data) => round_data,
+            _ => panic!("Game should start with a play pending"),
+        };
+        game.state.put(LoveLetterState::PlayStaging(round_data, StagedPlay::new(Card::Guard)));
+
+        game.handle_event(event(&player_id, LoveLetterEventType::SelectTargetCard(Card::Guard)));
+        assert_eq!(ProtoLvLeIllegalPlayReason::CantGuessGuard, recv_illegal_play_reason(&mut stream_rcv).await);
+    }
+}
@@ -1,20 +1,48 @@
 pub mod events;
+pub mod game_log;
+pub mod options;
+pub mod plugin;
+pub mod replay;
 
+mod bot;
 mod deck;
 mod state_machine;
 mod types;
 mod type_converters;
 
+#[cfg(test)]
+mod bot_test;
+#[cfg(test)]
+mod game_state_test;
+#[cfg(test)]
+mod illegal_play_test;
+#[cfg(test)]
+mod no_effect_test;
+#[cfg(test)]
+mod replay_test;
+#[cfg(test)]
+mod spectator_test;
+#[cfg(test)]
+pub(crate) mod test_util;
+#[cfg(test)]
+mod turn_timer_test;
 #[cfg(test)]
 mod types_test;
 
+pub use crate::state_machine::LoveLetterState;
+
 use crate::events::{LoveLetterEvent, LoveLetterEventType};
-use crate::state_machine::{LoveLetterState, LoveLetterStateMachine};
-use crate::types::RoundData;
+use crate::game_log::{GameLog, LoggedEvent, LoggedEventType};
+use crate::options::LoveLetterOptions;
+use crate::state_machine::LoveLetterStateMachine;
+use crate::types::GameData;
 use backend_framework::activity_timer::ActivityTracker;
 use backend_framework::holder::Holder;
 use backend_framework::game_instance_manager::GameInstanceManager;
-use std::time::Duration;
+use backend_framework::prng::PrngRand;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 
 /// This is the top level class for managing a single game of LoveLetter.
 ///
@@ -25,32 +53,82 @@ use std::time::Duration;
 pub struct LoveLetterInstanceManager {
     state: Holder<LoveLetterState>,
     state_machine: LoveLetterStateMachine,
+    game_log: GameLog,
+    /// Picks for players who ran out of time. Separate from the game's random numbers, since
+    /// replaying the log doesn't run out the clock.
+    timer_rng: PrngRand,
     activity_tracker: ActivityTracker,
 }
 
+/// What's saved by `snapshot()`. Streams and activity aren't part of it.
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    state: &'a LoveLetterState,
+    game_data: &'a GameData,
+    game_log: &'a GameLog,
+    timer_rng: &'a PrngRand,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    state: LoveLetterState,
+    game_data: GameData,
+    game_log: GameLog,
+    /// Snapshots from before this existed start over from the game's seed.
+    #[serde(default)]
+    timer_rng: Option<PrngRand>,
+}
+
 impl LoveLetterInstanceManager {
 
-    fn new(player_ids: Vec<String>) -> Self {
+    fn new(player_ids: Vec<String>, options: LoveLetterOptions, mut rng: PrngRand) -> Self {
+        // The game's own seed is drawn from `rng`, so it can be written to the game log.
+        // PRNG can't be seeded with 0.
+        let rng_seed = rng.next().max(1);
+        LoveLetterInstanceManager::from_seed(player_ids, options, rng_seed)
+    }
+
+    /// All randomness in the game comes from `rng_seed`, so the same seed, options, and events
+    /// always result in the same game.
+    pub(crate) fn from_seed(player_ids: Vec<String>, options: LoveLetterOptions, rng_seed: u64) -> Self {
+        let mut state_machine = LoveLetterStateMachine::new(player_ids.clone(), &options, rng_seed);
+        let first_round = state_machine.new_round();
+        let state = LoveLetterState::PlayPending(first_round);
+        state_machine.restart_turn_timer(&state);
+
         LoveLetterInstanceManager {
-            state: Holder::new(LoveLetterState::PlayPending(RoundData::new(&player_ids))),
-            state_machine: LoveLetterStateMachine::new(player_ids),
+            state: Holder::new(state),
+            state_machine,
+            game_log: GameLog::new(rng_seed, player_ids, options),
+            timer_rng: new_timer_rng(rng_seed),
             activity_tracker: ActivityTracker::new(),
         }
     }
 
+    pub fn state(&self) -> &LoveLetterState {
+        self.state.get()
+    }
+
+    pub fn wins_per_player(&self) -> &HashMap<String, u8> {
+        &self.state_machine.game_data().wins_per_player
+    }
+
+    pub fn game_log(&self) -> &GameLog {
+        &self.game_log
+    }
+
     /// State machine logic:
     ///
     /// Move from FROM_STATE to TO_STATE and mutate internal data as needed.
     fn route_event_to_state_machine(
         &mut self,
         from_state: LoveLetterState,
-        event: LoveLetterEvent,
+        player_id: String,
+        payload: LoveLetterEventType,
     ) -> LoveLetterState {
-        let player_id = event.client_info.player_id;
-
         // This will be a PITA to add Result<> to. Unless Err means game is in corrupt state
         // and we drop the game instance.
-        match event.payload {
+        match payload {
             LoveLetterEventType::GetGameState => {
                 self.state_machine.send_game_state(&from_state, &player_id);
                 from_state
@@ -60,6 +138,11 @@ impl LoveLetterInstanceManager {
                 self.state_machine.send_game_state(&from_state, &player_id);
                 from_state
             },
+            LoveLetterEventType::RegisterSpectatorStream { god_view, stream } => {
+                self.state_machine.add_spectator_stream(player_id.clone(), god_view, stream);
+                self.state_machine.send_game_state_to_spectator(&from_state, &player_id);
+                from_state
+            },
             LoveLetterEventType::PlayCardStaged(card_source) => {
                 self.state_machine.play_card_staged(from_state, player_id, card_source)
             },
@@ -77,11 +160,47 @@ impl LoveLetterInstanceManager {
             },
         }
     }
+
+    /// See `LoveLetterStateMachine::default_events()`.
+    fn handle_default_events(&mut self) {
+        let default_events = self.state_machine.default_events(self.state.get(), &mut self.timer_rng);
+        for (player_id, payload) in default_events {
+            self.handle_player_event(player_id, payload);
+        }
+    }
+
+    /// See `handle_event()`. Events sent on behalf of players who timed out don't come from a
+    /// client, so they're routed from here.
+    fn handle_player_event(&mut self, player_id: String, payload: LoveLetterEventType) -> bool {
+        let logged_event = LoggedEventType::from_event(&payload)
+            .map(|logged_event_type| LoggedEvent {
+                player_id: player_id.clone(),
+                event: logged_event_type,
+            });
+
+        let from_state = self.state.take();
+        let to_state = self.route_event_to_state_machine(from_state, player_id, payload);
+        self.state.put(to_state);
+
+        let event_rejected = self.state_machine.take_event_rejected();
+        self.activity_tracker.ping();
+
+        // Only events which change the game are logged.
+        match logged_event.filter(|_| !event_rejected) {
+            Some(logged_event) => {
+                self.game_log.events.push(logged_event);
+                true
+            },
+            None => false,
+        }
+    }
 }
 
 impl GameInstanceManager<LoveLetterEvent> for LoveLetterInstanceManager {
-    fn create_new_game(player_ids: Vec<String>) -> Self {
-        LoveLetterInstanceManager::new(player_ids)
+    type Options = LoveLetterOptions;
+
+    fn create_new_game(player_ids: Vec<String>, options: LoveLetterOptions, rng: PrngRand) -> Self {
+        LoveLetterInstanceManager::new(player_ids, options, rng)
     }
 
     /// This is the single entry point for manipulating the state of the game.
@@ -91,12 +210,9 @@ impl GameInstanceManager<LoveLetterEvent> for LoveLetterInstanceManager {
     /// 2. Unwrap the incoming event (i.e. request)
     /// 3. Route event payload to the correct state machine method
     /// 4. Put current state back into game instance
-    fn handle_event(&mut self, event: LoveLetterEvent) {
-        let from_state = self.state.take();
-        let to_state = self.route_event_to_state_machine(from_state, event);
-        self.state.put(to_state);
-
-        self.activity_tracker.ping();
+    /// 5. Append the event to the game log, if the state machine accepted it
+    fn handle_event(&mut self, event: LoveLetterEvent) -> bool {
+        self.handle_player_event(event.client_info.player_id, event.payload)
     }
 
     fn player_ids(&self) -> &Vec<String> {
@@ -106,4 +222,68 @@ impl GameInstanceManager<LoveLetterEvent> for LoveLetterInstanceManager {
     fn is_game_stale(&self, expiry_duration: Duration) -> bool {
         self.activity_tracker.has_inactivity_elapsed(expiry_duration)
     }
-}
\ No newline at end of file
+
+    /// Plays for the current player, or readies up players, and starts the next deadline.
+    fn handle_expired_deadlines(&mut self, now: SystemTime) -> bool {
+        if !self.state_machine.is_deadline_expired(now) {
+            return false;
+        }
+
+        self.handle_default_events();
+        // A staged card's selections are only known once it's staged.
+        if let LoveLetterState::PlayStaging(_, _) = self.state.get() {
+            self.handle_default_events();
+        }
+
+        // The next deadline has already started if the state moved on. This is in case it didn't,
+        // so the default action isn't retried every time deadlines are checked.
+        self.state_machine.restart_turn_timer(self.state.get());
+        true
+    }
+
+    fn is_game_complete(&self) -> bool {
+        matches!(self.state.get(), LoveLetterState::GameComplete(_))
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        let snapshot = SnapshotRef {
+            state: self.state.get(),
+            game_data: self.state_machine.game_data(),
+            game_log: &self.game_log,
+            timer_rng: &self.timer_rng,
+        };
+        match serde_json::to_vec(&snapshot) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                println!("ERROR: Failed to snapshot Love Letter game: {}", e);
+                None
+            },
+        }
+    }
+
+    fn restore_game(snapshot: &[u8]) -> Result<Self, String> {
+        let snapshot: Snapshot = serde_json::from_slice(snapshot)
+            .map_err(|e| format!("Invalid Love Letter snapshot: {}", e))?;
+
+        let rng_seed = snapshot.game_log.rng_seed;
+        let timer_rng = snapshot.timer_rng.unwrap_or_else(|| new_timer_rng(rng_seed));
+
+        // Players get the full time limit again, since the server was down for part of it.
+        let state_machine = LoveLetterStateMachine::from_game_data(snapshot.game_data);
+        state_machine.restart_turn_timer(&snapshot.state);
+
+        Ok(LoveLetterInstanceManager {
+            state: Holder::new(snapshot.state),
+            state_machine,
+            game_log: snapshot.game_log,
+            timer_rng,
+            activity_tracker: ActivityTracker::new(),
+        })
+    }
+}
+
+/// Seeded from the game's seed, so the same game times out the same way, but doesn't repeat the
+/// game's own random numbers.
+fn new_timer_rng(rng_seed: u64) -> PrngRand {
+    PrngRand::new(rng_seed.rotate_left(32))
+}
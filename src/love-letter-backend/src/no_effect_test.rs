@@ -0,0 +1,59 @@
+mod no_effect_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::{Card, LoveLetterEventType};
+    use crate::options::LoveLetterOptions;
+    use crate::state_machine::LoveLetterState;
+    use crate::test_util::event;
+    use crate::types::{CommittedPlayOutcome, StagedPlay};
+    use backend_framework::game_instance_manager::GameInstanceManager;
+
+    /// Returns the game with the card staged by the current player, while every other player has
+    /// Handmaid protection.
+    fn stage_with_all_others_protected(card: Card) -> (LoveLetterInstanceManager, String) {
+        let player_ids = vec!["p1".to_string(), "p2".to_string(), "p3".to_string()];
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids.clone(), LoveLetterOptions::default(), 42);
+
+        let mut round_data = match game.state.take() {
+            LoveLetterState::PlayPending(round_data) => round_data,
+            _ => panic!("Game should start with a play pending"),
+        };
+        let player_id = round_data.players.current_turn_player_id().clone();
+        for other_player_id in player_ids.into_iter().filter(|p| p != &player_id) {
+            round_data.handmaid_immunity_player_ids.insert(other_player_id);
+        }
+        game.state.put(LoveLetterState::PlayStaging(round_data, StagedPlay::new(card)));
+
+        (game, player_id)
+    }
+
+    #[test]
+    fn commit_without_target_has_no_effect() {
+        let (mut game, player_id) = stage_with_all_others_protected(Card::Guard);
+
+        game.handle_event(event(&player_id, LoveLetterEventType::PlayCardCommit));
+        match game.state() {
+            LoveLetterState::TurnIntermission(round_data, _) => {
+                let committed_play = round_data.most_recent_play_details.as_ref().expect("Play should be committed");
+                assert_eq!(&player_id, &committed_play.committer_player_id);
+                assert!(matches!(committed_play.outcome, CommittedPlayOutcome::NoEffect { played_card: Card::Guard }));
+                assert_eq!(3, round_data.players.remaining_player_ids().len());
+            },
+            _ => panic!("Play should be committed"),
+        }
+    }
+
+    #[test]
+    fn prince_can_still_target_self() {
+        let (mut game, player_id) = stage_with_all_others_protected(Card::Prince);
+
+        game.handle_event(event(&player_id, LoveLetterEventType::SelectTargetPlayer(player_id.clone())));
+        game.handle_event(event(&player_id, LoveLetterEventType::PlayCardCommit));
+        match game.state() {
+            LoveLetterState::TurnIntermission(round_data, _) => {
+                let committed_play = round_data.most_recent_play_details.as_ref().expect("Play should be committed");
+                assert!(matches!(&committed_play.outcome, CommittedPlayOutcome::Prince { target_player_id, .. } if target_player_id == &player_id));
+            },
+            _ => panic!("Play should be committed"),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// Chosen by the host when creating a game. Unset options follow the official rules.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoveLetterOptions {
+    /// Number of round wins needed to win the game.
+    pub win_threshold: Option<u8>,
+    pub deck_variant: DeckVariant,
+    /// Seconds the current player has to play, before a card is played for them.
+    pub turn_timeout_secs: Option<u32>,
+    /// Seconds players have to ready up, before they're readied up automatically.
+    pub ready_up_timeout_secs: Option<u32>,
+}
+
+/// The published editions of the game. Each has its own deck, and supports a different number
+/// of players.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeckVariant {
+    /// The original 16 card deck.
+    #[default]
+    Classic,
+    /// 21 cards: the classic deck plus a Guard, 2 Spies and 2 Chancellors.
+    Revised2019,
+    /// 32 cards: the classic deck plus 3 Guards, and the Jester, Assassin, Cardinal, Baroness,
+    /// Sycophant, Count, Constable, Dowager Queen and Bishop.
+    Premium,
+}
+
+impl LoveLetterOptions {
+    pub fn win_threshold(&self, num_players: usize) -> u8 {
+        self.win_threshold.unwrap_or_else(|| self.deck_variant.official_win_threshold(num_players))
+    }
+}
+
+impl DeckVariant {
+    /// Inclusive bounds on the number of players the deck has enough cards for.
+    pub fn player_count_min_max(self) -> (usize, usize) {
+        match self {
+            DeckVariant::Classic => (2, 4),
+            DeckVariant::Revised2019 => (2, 6),
+            DeckVariant::Premium => (2, 8),
+        }
+    }
+
+    /// Tokens of affection needed to win the game, per the edition's rule book.
+    fn official_win_threshold(self, num_players: usize) -> u8 {
+        match (self, num_players) {
+            (DeckVariant::Revised2019, 2) => 6,
+            (_, 2) => 7,
+            (_, 3) => 5,
+            (_, 4) => 4,
+            (_, _) => 3,
+        }
+    }
+}
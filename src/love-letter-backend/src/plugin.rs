@@ -0,0 +1,158 @@
+use crate::LoveLetterInstanceManager;
+use crate::bot::LoveLetterBot;
+use crate::events::{LoveLetterEvent, LoveLetterEventType, PlayCardSource, Card};
+use crate::options::{DeckVariant, LoveLetterOptions};
+use backend_framework::common_types::ClientInfo;
+use backend_framework::game_instance_manager::GameInstanceManager;
+use backend_framework::game_plugin::{BoxedBot, GamePlugin};
+use backend_framework::prng::PrngRand;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoLoveLetterDataIn, ProtoLoveLetterDataOut, ProtoGameType, ProtoLvLeCard, ProtoLvLeOptions};
+use backend_framework::wire_api::proto_frj_ngn::proto_love_letter_data_in::ProtoLvLeIn;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_options::ProtoLvLeDeckVariant;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_play_card_req::ProtoLvLeCardSource;
+use prost::Message;
+use std::convert::TryFrom;
+use tonic::Status;
+
+/// Describes Love Letter to the engine.
+pub struct LoveLetterPlugin;
+
+impl GamePlugin for LoveLetterPlugin {
+    type DataIn = ProtoLoveLetterDataIn;
+    type DataOut = ProtoLoveLetterDataOut;
+    type Event = LoveLetterEvent;
+    type Instance = LoveLetterInstanceManager;
+
+    fn game_type(&self) -> ProtoGameType {
+        ProtoGameType::LoveLetter
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Love Letter"
+    }
+
+    /// The Premium deck has enough cards for 8 players. The deck chosen by the host is checked in
+    /// `create_instance()`.
+    fn player_count_min_max(&self) -> (usize, usize) {
+        (2, 8)
+    }
+
+    fn decode_event(&self, client_info: ClientInfo, message: ProtoLoveLetterDataIn) -> Result<LoveLetterEvent, Status> {
+        let payload = match message.proto_lv_le_in {
+            None => return Err(Status::invalid_argument("Missing proto_lv_le_in field.")),
+            Some(payload) => convert_payload(payload)?,
+        };
+
+        Ok(LoveLetterEvent {
+            client_info,
+            payload,
+        })
+    }
+
+    fn register_data_stream_event(&self, client_info: ClientInfo, stream_out: StreamSender<ProtoLoveLetterDataOut>) -> LoveLetterEvent {
+        LoveLetterEvent {
+            client_info,
+            payload: LoveLetterEventType::RegisterDataStream(stream_out),
+        }
+    }
+
+    fn register_spectator_stream_event(&self, client_info: ClientInfo, god_view: bool, stream_out: StreamSender<ProtoLoveLetterDataOut>) -> Result<LoveLetterEvent, Status> {
+        Ok(LoveLetterEvent {
+            client_info,
+            payload: LoveLetterEventType::RegisterSpectatorStream {
+                god_view,
+                stream: stream_out,
+            },
+        })
+    }
+
+    fn reject_event(&self, event: LoveLetterEvent, status: Status) {
+        // Can't notify client of other rejected events (because of how I modeled the code).
+        // Should probably fix this at some point...
+        match event.payload {
+            LoveLetterEventType::RegisterDataStream(stream)
+            | LoveLetterEventType::RegisterSpectatorStream { stream, .. } => {
+                let _ = stream.send_error_message(status);
+            },
+            _ => {},
+        }
+    }
+
+    fn decode_options(&self, game_options: &[u8]) -> Result<LoveLetterOptions, Status> {
+        let proto_options = ProtoLvLeOptions::decode(game_options)
+            .map_err(|e| Status::invalid_argument(format!("Game options are not a valid ProtoLvLeOptions message: {}", e)))?;
+        convert_options(proto_options)
+    }
+
+    /// The deck chosen by the host has to have enough cards for the players.
+    fn create_instance(&self, player_ids: Vec<String>, game_options: &[u8], rng: PrngRand) -> Result<LoveLetterInstanceManager, Status> {
+        let options = self.decode_options(game_options)?;
+
+        let (min, max) = options.deck_variant.player_count_min_max();
+        if player_ids.len() < min || player_ids.len() > max {
+            return Err(Status::invalid_argument(format!(
+                "The {:?} deck is for {}-{} players, but there are {}.",
+                options.deck_variant,
+                min,
+                max,
+                player_ids.len(),
+            )));
+        }
+
+        Ok(LoveLetterInstanceManager::create_new_game(player_ids, options, rng))
+    }
+
+    fn supports_bots(&self) -> bool {
+        true
+    }
+
+    fn create_bot(&self, player_id: String, rng: PrngRand) -> Option<BoxedBot<Self>> {
+        Some(Box::new(LoveLetterBot::new(player_id, rng)))
+    }
+}
+
+fn convert_options(proto_options: ProtoLvLeOptions) -> Result<LoveLetterOptions, Status> {
+    let win_threshold = match proto_options.win_threshold {
+        0 => None,
+        win_threshold => Some(u8::try_from(win_threshold)
+            .map_err(|_| Status::invalid_argument(format!("Win threshold {} is too high.", win_threshold)))?),
+    };
+
+    let deck_variant = ProtoLvLeDeckVariant::try_from(proto_options.deck_variant)?;
+    let timeout_secs = |secs: u32| Some(secs).filter(|secs| *secs > 0);
+
+    Ok(LoveLetterOptions {
+        win_threshold,
+        deck_variant: DeckVariant::from(deck_variant),
+        turn_timeout_secs: timeout_secs(proto_options.turn_timeout_secs),
+        ready_up_timeout_secs: timeout_secs(proto_options.ready_up_timeout_secs),
+    })
+}
+
+fn convert_payload(payload: ProtoLvLeIn) -> Result<LoveLetterEventType, Status> {
+    match payload {
+        ProtoLvLeIn::GameState(_) => Ok(LoveLetterEventType::GetGameState),
+        ProtoLvLeIn::PlayCard(req) => {
+            let proto_card_source = ProtoLvLeCardSource::try_from(req.card_source)?;
+            let card_source = PlayCardSource::try_from(proto_card_source)
+                .map_err(|_| Status::invalid_argument("Unspecified ProtoLvLeCardSource"))?;
+            Ok(LoveLetterEventType::PlayCardStaged(card_source))
+        },
+        ProtoLvLeIn::SelectTargetPlayer(req) => {
+            Ok(LoveLetterEventType::SelectTargetPlayer(req.target_player_id))
+        },
+        ProtoLvLeIn::SelectTargetCard(req) => {
+            let proto_card = ProtoLvLeCard::try_from(req.target_card)?;
+            let card = Card::try_from(proto_card)
+                .map_err(|_| Status::invalid_argument("Unspecified ProtoLvLeCard"))?;
+            Ok(LoveLetterEventType::SelectTargetCard(card))
+        },
+        ProtoLvLeIn::CommitSelection(_) => {
+            Ok(LoveLetterEventType::PlayCardCommit)
+        },
+        ProtoLvLeIn::ReadyUp(_) => {
+            Ok(LoveLetterEventType::ReadyUp)
+        }
+    }
+}
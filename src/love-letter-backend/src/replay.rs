@@ -0,0 +1,33 @@
+use crate::LoveLetterInstanceManager;
+use crate::events::LoveLetterEvent;
+use crate::game_log::GameLog;
+use backend_framework::common_types::ClientInfo;
+use backend_framework::game_instance_manager::GameInstanceManager;
+
+/// Rebuild a game from its log, as it was right after the first `num_events` events were
+/// handled. A replayed game has no data streams, so nothing is sent to players.
+///
+/// Fails if the log is shorter than `num_events`, or if the game doesn't accept the logged
+/// events, which means the log was recorded with different game rules.
+pub fn replay(log: &GameLog, num_events: usize) -> Result<LoveLetterInstanceManager, String> {
+    if num_events > log.events.len() {
+        return Err(format!("Can't replay {} events, game log only has {} events.", num_events, log.events.len()));
+    }
+
+    let mut game = LoveLetterInstanceManager::from_seed(log.player_ids.clone(), log.options.clone(), log.rng_seed);
+    for (i, logged_event) in log.events.iter().take(num_events).enumerate() {
+        game.handle_event(LoveLetterEvent {
+            client_info: ClientInfo {
+                player_id: logged_event.player_id.clone(),
+                game_id: String::new(),
+            },
+            payload: logged_event.event.clone().into(),
+        });
+
+        if game.game_log().events.len() != i + 1 {
+            return Err(format!("Game rejected event {} during replay: {:?}", i, logged_event));
+        }
+    }
+
+    Ok(game)
+}
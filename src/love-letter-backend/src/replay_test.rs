@@ -0,0 +1,176 @@
+mod replay_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::{Card, LoveLetterEventType, PlayCardSource};
+    use crate::game_log::{LoggedEvent, LoggedEventType};
+    use crate::options::{DeckVariant, LoveLetterOptions};
+    use crate::replay;
+    use crate::state_machine::LoveLetterState;
+    use crate::test_util::{event, player_ids};
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::prng::PrngRand;
+
+    /// Compare as JSON values, since hash map iteration order isn't stable across instances.
+    fn snapshot(game: &LoveLetterInstanceManager) -> serde_json::Value {
+        serde_json::from_slice(&game.snapshot().unwrap()).unwrap()
+    }
+
+    /// Every player sends the same script of events at each step, regardless of whose turn it
+    /// is, so plenty of the events are rejected. Returns a snapshot after each accepted event.
+    fn play_scripted_game(game: &mut LoveLetterInstanceManager, num_steps: usize) -> Vec<serde_json::Value> {
+        let player_ids = player_ids(3);
+        let mut snapshots = vec![snapshot(game)];
+
+        for step in 0..num_steps {
+            for (i, player_id) in player_ids.iter().enumerate() {
+                let card_source = if step % 2 == 0 { PlayCardSource::Hand } else { PlayCardSource::TopDeck };
+                let target_player_id = player_ids[(i + 1 + step) % player_ids.len()].clone();
+                let script = vec![
+                    LoveLetterEventType::PlayCardStaged(card_source),
+                    LoveLetterEventType::SelectTargetPlayer(target_player_id),
+                    LoveLetterEventType::SelectTargetCard(Card::Priest),
+                    LoveLetterEventType::PlayCardCommit,
+                    LoveLetterEventType::ReadyUp,
+                ];
+
+                for payload in script {
+                    let num_logged_events = game.game_log().events.len();
+                    game.handle_event(event(player_id, payload));
+                    if game.game_log().events.len() > num_logged_events {
+                        snapshots.push(snapshot(game));
+                    }
+                }
+            }
+        }
+
+        snapshots
+    }
+
+    #[test]
+    fn same_seed_deals_same_game() {
+        let game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        let same_seed_game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+
+        assert_eq!(snapshot(&game), snapshot(&same_seed_game));
+    }
+
+    #[test]
+    fn same_rng_deals_same_game() {
+        let game = LoveLetterInstanceManager::create_new_game(player_ids(3), LoveLetterOptions::default(), PrngRand::new(42));
+        let same_rng_game = LoveLetterInstanceManager::create_new_game(player_ids(3), LoveLetterOptions::default(), PrngRand::new(42));
+
+        assert_eq!(snapshot(&game), snapshot(&same_rng_game));
+        assert_eq!(game.game_log().rng_seed, same_rng_game.game_log().rng_seed);
+    }
+
+    #[test]
+    fn rejected_events_are_not_logged() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+
+        // Can't ready up or commit before playing a card.
+        game.handle_event(event("p1", LoveLetterEventType::ReadyUp));
+        game.handle_event(event("p1", LoveLetterEventType::PlayCardCommit));
+        // Read-only events
+        game.handle_event(event("p1", LoveLetterEventType::GetGameState));
+
+        assert!(game.game_log().events.is_empty());
+    }
+
+    #[test]
+    fn selections_outside_play_staging_are_not_logged() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        assert!(matches!(game.state(), LoveLetterState::PlayPending(_)));
+
+        // No card is staged, so there's nothing to select a target for.
+        assert!(!game.handle_event(event("p1", LoveLetterEventType::SelectTargetPlayer("p2".to_string()))));
+        assert!(!game.handle_event(event("p1", LoveLetterEventType::SelectTargetCard(Card::Priest))));
+
+        assert!(game.game_log().events.is_empty());
+    }
+
+    #[test]
+    fn accepted_events_are_logged_in_order() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        let snapshots = play_scripted_game(&mut game, 20);
+
+        let game_log = game.game_log();
+        assert_eq!(42, game_log.rng_seed);
+        assert_eq!(player_ids(3), game_log.player_ids);
+        assert_eq!(snapshots.len() - 1, game_log.events.len());
+        assert!(game_log.events.contains(&LoggedEvent {
+            player_id: "p1".to_string(),
+            event: LoggedEventType::ReadyUp,
+        }));
+    }
+
+    #[test]
+    fn replay_rebuilds_game_at_every_step() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 1234);
+        let snapshots = play_scripted_game(&mut game, 20);
+        let game_log = game.game_log().clone();
+        assert!(game_log.events.len() > 10, "Script should make progress, only {} events were accepted", game_log.events.len());
+
+        for (num_events, expected_snapshot) in snapshots.iter().enumerate() {
+            let replayed_game = replay::replay(&game_log, num_events).unwrap();
+            assert_eq!(expected_snapshot, &snapshot(&replayed_game), "Replay diverged after {} events", num_events);
+        }
+    }
+
+    #[test]
+    fn game_completes_when_player_reaches_win_threshold() {
+        let options = LoveLetterOptions {
+            win_threshold: Some(1),
+            ..LoveLetterOptions::default()
+        };
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), options.clone(), 42);
+        play_scripted_game(&mut game, 20);
+
+        assert!(game.is_game_complete());
+        assert!(matches!(game.state(), LoveLetterState::GameComplete(_)));
+        assert!(game.wins_per_player().values().any(|wins| *wins >= 1));
+
+        // Nothing changes the game after it's complete.
+        let num_logged_events = game.game_log().events.len();
+        game.handle_event(event("p1", LoveLetterEventType::ReadyUp));
+        assert_eq!(num_logged_events, game.game_log().events.len());
+
+        // The options are logged, so the replayed game also completes.
+        let game_log = game.game_log().clone();
+        assert_eq!(options, game_log.options);
+        let replayed_game = replay::replay(&game_log, game_log.events.len()).unwrap();
+        assert!(replayed_game.is_game_complete());
+    }
+
+    #[test]
+    fn premium_deck_game_replays() {
+        let options = LoveLetterOptions {
+            deck_variant: DeckVariant::Premium,
+            ..LoveLetterOptions::default()
+        };
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), options, 7);
+        let snapshots = play_scripted_game(&mut game, 20);
+        let game_log = game.game_log().clone();
+        assert!(game_log.events.len() > 10, "Script should make progress, only {} events were accepted", game_log.events.len());
+
+        let replayed_game = replay::replay(&game_log, game_log.events.len()).unwrap();
+        assert_eq!(snapshots.last().unwrap(), &snapshot(&replayed_game));
+    }
+
+    #[test]
+    fn replay_fails_if_log_is_too_short() {
+        let game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+
+        assert!(replay::replay(game.game_log(), 1).is_err());
+    }
+
+    #[test]
+    fn replay_fails_if_game_rejects_logged_event() {
+        let game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        let mut game_log = game.game_log().clone();
+        game_log.events.push(LoggedEvent {
+            player_id: "p1".to_string(),
+            event: LoggedEventType::PlayCardCommit,
+        });
+
+        assert!(replay::replay(&game_log, 1).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+mod spectator_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::{Card, LoveLetterEventType, PlayCardSource};
+    use crate::options::LoveLetterOptions;
+    use crate::test_util::{event, open_stream, player_ids};
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoLoveLetterDataOut, ProtoLvLeGameState};
+    use backend_framework::wire_api::proto_frj_ngn::proto_love_letter_data_out::ProtoLvLeOut;
+    use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::{ProtoLvLeRoundState, Stage, proto_lv_le_round_state};
+    use tokio::stream::StreamExt;
+    use tonic::Code;
+
+    fn open_spectator_stream(game: &mut LoveLetterInstanceManager, spectator_id: &str, god_view: bool) -> StreamReceiver<ProtoLoveLetterDataOut> {
+        let (stream, stream_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+        game.handle_event(event(spectator_id, LoveLetterEventType::RegisterSpectatorStream {
+            god_view,
+            stream,
+        }));
+        stream_rcv
+    }
+
+    /// Every player sends the same script of events until the first round is over. With a win
+    /// threshold of 1, that's also the end of the game.
+    fn play_first_round(game: &mut LoveLetterInstanceManager) {
+        let player_ids = player_ids(3);
+        for step in 0..20 {
+            for (i, player_id) in player_ids.iter().enumerate() {
+                let target_player_id = player_ids[(i + 1 + step) % player_ids.len()].clone();
+                let script = vec![
+                    LoveLetterEventType::PlayCardStaged(PlayCardSource::Hand),
+                    LoveLetterEventType::SelectTargetPlayer(target_player_id),
+                    LoveLetterEventType::SelectTargetCard(Card::Priest),
+                    LoveLetterEventType::PlayCardCommit,
+                    LoveLetterEventType::ReadyUp,
+                ];
+                for payload in script {
+                    game.handle_event(event(player_id, payload));
+                }
+            }
+        }
+        assert!(game.is_game_complete());
+    }
+
+    /// Only call once the game is dropped, so the stream is closed.
+    async fn recv_game_states(stream_rcv: StreamReceiver<ProtoLoveLetterDataOut>) -> Vec<ProtoLvLeGameState> {
+        stream_rcv
+            .map(|message| match message.expect("Stream should only have game states").proto_lv_le_out {
+                Some(ProtoLvLeOut::GameState(game_state)) => game_state,
+                other => panic!("Expected game state, got {:?}", other),
+            })
+            .collect()
+            .await
+    }
+
+    fn round_state(game_state: &ProtoLvLeGameState) -> &ProtoLvLeRoundState {
+        match game_state.stage.as_ref() {
+            Some(Stage::RoundInProgress(round_state)) => round_state,
+            other => panic!("Expected round in progress, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn spectator_does_not_see_player_hand() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        let player_stream = open_stream(&mut game, "p1");
+        // Spectating with a player's ID doesn't reveal that player's hand.
+        let spectator_stream = open_spectator_stream(&mut game, "p1", false);
+        drop(game);
+
+        let player_states = recv_game_states(player_stream).await;
+        let spectator_states = recv_game_states(spectator_stream).await;
+        assert_eq!(1, player_states.len());
+        assert_eq!(1, spectator_states.len());
+
+        // Only p1 has opened a stream.
+        let presence: Vec<bool> = player_states[0].presence.iter().map(|player| player.connected).collect();
+        assert_eq!(vec![true, false, false], presence);
+
+        let player_round = round_state(&player_states[0]);
+        assert_ne!(0, player_round.my_hand);
+        assert!(matches!(player_round.turn, Some(proto_lv_le_round_state::Turn::MyDrawnCard(_))));
+
+        let spectator_round = round_state(&spectator_states[0]);
+        assert_eq!(0, spectator_round.my_hand);
+        assert_eq!(Some(proto_lv_le_round_state::Turn::CurrentTurnPlayerId("p1".to_string())), spectator_round.turn);
+    }
+
+    #[tokio::test]
+    async fn only_host_can_god_view() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+        let mut spectator_stream = open_spectator_stream(&mut game, "p2", true);
+
+        let status = spectator_stream.next().await
+            .expect("Stream should have a message")
+            .expect_err("Stream should be rejected");
+        assert_eq!(Code::PermissionDenied, status.code());
+        assert!(spectator_stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn spectators_follow_game_and_god_view_sees_hidden_cards() {
+        let options = LoveLetterOptions {
+            win_threshold: Some(1),
+            ..LoveLetterOptions::default()
+        };
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), options, 42);
+        let spectator_stream = open_spectator_stream(&mut game, "watcher", false);
+        let god_view_stream = open_spectator_stream(&mut game, "p1", true);
+        play_first_round(&mut game);
+        drop(game);
+
+        let spectator_states = recv_game_states(spectator_stream).await;
+        let god_view_states = recv_game_states(god_view_stream).await;
+        assert!(spectator_states.len() > 1);
+        assert_eq!(spectator_states.len(), god_view_states.len());
+
+        for game_state in spectator_states.iter().chain(god_view_states.iter()) {
+            if let Some(Stage::RoundInProgress(round_state)) = game_state.stage.as_ref() {
+                assert_eq!(0, round_state.my_hand);
+            }
+        }
+
+        let final_round = |game_state: &ProtoLvLeGameState| match game_state.stage.clone() {
+            Some(Stage::GameComplete(complete)) => complete.final_round.expect("Final round is always set"),
+            other => panic!("Expected game complete, got {:?}", other),
+        };
+        assert_eq!(None, final_round(spectator_states.last().unwrap()).opt_hidden_cards);
+        let hidden_cards = final_round(god_view_states.last().unwrap())
+            .opt_hidden_cards
+            .expect("God view should see hidden cards");
+        assert_ne!(0, hidden_cards.opt_burn_card);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::deck;
+use crate::events::{Card, LoveLetterEventType, PlayCardSource};
+use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
+use crate::state_machine::handler::select_target_player::{has_valid_target, PlayerTargeting};
+use crate::types::{RoundData, StagedPlay};
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+impl LoveLetterStateMachine {
+
+    /// The events to send for players who ran out of time, each with the player who sends it:
+    /// * The current player stages their lowest legal card. Once it's staged, they make random
+    ///   legal selections and commit. Selections can't be made until then, since they depend on
+    ///   the staged play (i.e. the cards the Chancellor draws).
+    /// * Players who haven't readied up are readied up.
+    ///
+    /// They're sent like any other event, so they end up in the game log. `rng` must not be the
+    /// game's, since replaying the log doesn't call this.
+    pub fn default_events(&self, state: &LoveLetterState, rng: &mut impl Rng) -> Vec<(String, LoveLetterEventType)> {
+        let (player_id, events) = match state {
+            LoveLetterState::PlayPending(round_data) => {
+                let player_id = round_data.players.current_turn_player_id();
+                let card_source = self.lowest_legal_card_source(round_data, player_id);
+                (player_id, vec![LoveLetterEventType::PlayCardStaged(card_source)])
+            },
+            LoveLetterState::PlayStaging(round_data, staged_play) => {
+                let player_id = round_data.players.current_turn_player_id();
+
+                let mut events = self.default_selections(round_data, staged_play, player_id, rng);
+                events.push(LoveLetterEventType::PlayCardCommit);
+                (player_id, events)
+            },
+            LoveLetterState::TurnIntermission(_, unready_players)
+            | LoveLetterState::RoundIntermission(_, unready_players) => {
+                return unready_players.player_ids()
+                    .iter()
+                    .map(|player_id| (player_id.clone(), LoveLetterEventType::ReadyUp))
+                    .collect();
+            },
+            LoveLetterState::GameComplete(_) => return Vec::new(),
+        };
+
+        events.into_iter()
+            .map(|event| (player_id.clone(), event))
+            .collect()
+    }
+
+    /// The Countess is played if the other card is the King or Prince, like `play_card_staged()`
+    /// requires. Otherwise it's the card with the lowest value.
+    fn lowest_legal_card_source(&self, round_data: &RoundData, player_id: &String) -> PlayCardSource {
+        let hand_card = round_data.players.get_card(player_id)
+            .expect("Illegal game state: Current turn player has no card");
+        let top_deck = *round_data.deck.last()
+            .expect("Illegal game state: We're in 'PlayPending' state with no cards in deck");
+
+        let must_play_countess = |card: Card, other_card: Card| {
+            card == Card::Countess && (other_card == Card::King || other_card == Card::Prince)
+        };
+        let deck_variant = self.game_data.deck_variant;
+        if must_play_countess(hand_card, top_deck) {
+            PlayCardSource::Hand
+        } else if must_play_countess(top_deck, hand_card) || top_deck.value(deck_variant) < hand_card.value(deck_variant) {
+            PlayCardSource::TopDeck
+        } else {
+            PlayCardSource::Hand
+        }
+    }
+
+    /// Selections which the staged play still needs before it can be committed.
+    fn default_selections(
+        &self,
+        round_data: &RoundData,
+        staged_play: &StagedPlay,
+        player_id: &String,
+        rng: &mut impl Rng,
+    ) -> Vec<LoveLetterEventType> {
+        let played_card = staged_play.played_card;
+
+        // Nobody can be targeted, so the card is committed as is and has no effect.
+        if !has_valid_target(round_data, played_card, player_id) {
+            return Vec::new();
+        }
+
+        let mut events = Vec::new();
+        if let Some(targeting) = PlayerTargeting::of(played_card) {
+            let mut selected_player_ids = staged_play.target_player_ids();
+
+            // The Sycophant's choice must be one of the targets. For cards with 1 target, selecting
+            // replaces the current selection. If 2 players are selected, one is un-selected first.
+            let sycophant_player_id = round_data.sycophant_player_id.as_ref()
+                .filter(|sycophant_player_id| !selected_player_ids.contains(sycophant_player_id));
+            if let Some(sycophant_player_id) = sycophant_player_id {
+                if !targeting.is_two_players() {
+                    selected_player_ids.clear();
+                } else if let (2, Some(unselected_player_id)) = (selected_player_ids.len(), selected_player_ids.pop()) {
+                    events.push(LoveLetterEventType::SelectTargetPlayer(unselected_player_id.clone()));
+                }
+                selected_player_ids.push(sycophant_player_id);
+                events.push(LoveLetterEventType::SelectTargetPlayer(sycophant_player_id.clone()));
+            }
+
+            let num_targets_needed: usize = match targeting {
+                PlayerTargeting::TwoAny => 2,
+                PlayerTargeting::OneOther
+                | PlayerTargeting::OneAny
+                | PlayerTargeting::UpToTwoOthers => 1,
+            };
+            let mut candidate_player_ids: Vec<&String> = round_data.targetable_player_ids()
+                .into_iter()
+                .filter(|p| targeting.can_target_self() || *p != player_id)
+                .filter(|p| !selected_player_ids.contains(p))
+                .collect();
+            candidate_player_ids.shuffle(rng);
+            events.extend(candidate_player_ids.into_iter()
+                .take(num_targets_needed.saturating_sub(selected_player_ids.len()))
+                .map(|p| LoveLetterEventType::SelectTargetPlayer(p.clone())));
+        }
+
+        if staged_play.target_card.is_none() {
+            let deck_variant = self.game_data.deck_variant;
+            let target_card = match played_card {
+                Card::Guard | Card::Bishop => deck::distinct_cards(deck_variant)
+                    .into_iter()
+                    .filter(|card| card.value(deck_variant) != Card::Guard.value(deck_variant))
+                    .collect::<Vec<Card>>()
+                    .choose(rng)
+                    .copied(),
+                // Keep the card in hand
+                Card::Chancellor if !staged_play.drawn_cards.is_empty() => round_data.players.get_card(player_id),
+                _ => None,
+            };
+            events.extend(target_card.map(LoveLetterEventType::SelectTargetCard));
+        }
+
+        events
+    }
+}
@@ -5,3 +5,6 @@ mod play_card_staged;
 mod ready_up;
 mod select_target_player;
 mod select_target_card;
+mod expire_deadline;
+
+pub(crate) use select_target_player::PlayerTargeting;
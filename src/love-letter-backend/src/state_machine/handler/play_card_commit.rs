@@ -1,7 +1,8 @@
 use crate::events::Card;
+use crate::options::DeckVariant;
+use crate::state_machine::handler::select_target_player::has_valid_target;
 use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
-use crate::types::{RoundResult, RoundData, StagedPlay, CommittedPlay, CommittedPlayOutcome, UnreadyPlayers};
-use tonic::Status;
+use crate::types::{RoundResult, RoundData, StagedPlay, CommittedPlay, CommittedPlayOutcome, UnreadyPlayers, IllegalPlay};
 
 impl LoveLetterStateMachine {
 
@@ -15,6 +16,7 @@ impl LoveLetterStateMachine {
                 self.handle_commit(round_data, staged_play, client_player_id)
             },
             _ => {
+                self.ignore_event();
                 self.send_game_state(&from_state, &client_player_id);
                 from_state
             },
@@ -32,21 +34,21 @@ impl LoveLetterStateMachine {
     /// 3. Create and set most recent play details
     /// 4. Eliminate player
     /// 5. Increment turn counter
-    /// 6: State transition: TurnIntermission OR RoundIntermission
+    /// 6: State transition: TurnIntermission OR RoundIntermission OR GameComplete
     fn handle_commit(
         &mut self,
         mut round_data: RoundData,
         staged_play: StagedPlay,
         client_player_id: String,
     ) -> LoveLetterState {
-        let failed_precondition = |message, round_data, staged_play| {
-            self.streams.send_err(&client_player_id, Status::failed_precondition(message));
+        let illegal_play = |reason, message, round_data, staged_play| {
+            self.reject_illegal_play(&client_player_id, reason, message);
             LoveLetterState::PlayStaging(round_data, staged_play)
         };
 
         // Check: Is my turn
         if &client_player_id != round_data.players.current_turn_player_id() {
-            return failed_precondition("Can't commit play, not your turn", round_data, staged_play);
+            return illegal_play(IllegalPlay::NotYourTurn, "Can't commit play, not your turn", round_data, staged_play);
         }
 
         // Do the following:
@@ -57,15 +59,22 @@ impl LoveLetterStateMachine {
         // Do NOT do the following:
         // * Eliminate player
         // * increment turn cursor
+        let deck_variant = self.game_data.deck_variant;
         let committed_play_outcome: CommittedPlayOutcome = match staged_play.played_card {
+            // Any selection is ignored, there's nobody it could apply to.
+            played_card if !has_valid_target(&round_data, played_card, &client_player_id) => {
+                CommittedPlayOutcome::NoEffect {
+                    played_card,
+                }
+            },
             Card::Guard => {
                 let (target_player_id, guessed_card) = {
                     // A small deficiency (unnecessary clone of 1 string) for a big readability gain
                     let staged_play_clone = staged_play.clone();
                     match (staged_play.target_player, staged_play.target_card) {
                         (Some(target_player_id), Some(target_card)) => (target_player_id, target_card),
-                        (None, _) => return failed_precondition("To play Guard, you must select a target player", round_data, staged_play_clone),
-                        (_, None) => return failed_precondition("To play Guard, you must select a target card", round_data, staged_play_clone),
+                        (None, _) => return illegal_play(IllegalPlay::MissingSelection, "To play Guard, you must select a target player", round_data, staged_play_clone),
+                        (_, None) => return illegal_play(IllegalPlay::MissingSelection, "To play Guard, you must select a target card", round_data, staged_play_clone),
                     }
                 };
 
@@ -73,18 +82,27 @@ impl LoveLetterStateMachine {
                 let actual_card = round_data.players
                     .get_card(&target_player_id)
                     .expect("Game is in unrecoverable, invalid state: Player selected is not in round.");
-                let correct = guessed_card == actual_card;
+                let assassinated = actual_card == Card::Assassin && target_player_id != client_player_id;
+                let correct = !assassinated && guessed_card.value(deck_variant) == actual_card.value(deck_variant);
+
+                // Assassin is revealed: discard it and draw a new card
+                if assassinated {
+                    let new_card = round_data.draw_replacement_card();
+                    let discarded_card = round_data.players.replace_card(target_player_id.clone(), new_card);
+                    round_data.discard(&target_player_id, discarded_card);
+                }
 
                 CommittedPlayOutcome::Guard {
                     target_player_id,
                     guessed_card,
                     correct,
+                    assassinated,
                 }
             },
             Card::Priest => {
                 let target_player_id = match staged_play.target_player {
                     Some(target_player_id) => target_player_id,
-                    None => return failed_precondition("To play Priest, you must select a target player", round_data, staged_play),
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play Priest, you must select a target player", round_data, staged_play),
                 };
 
                 let opponent_card = round_data.players.get_card(&target_player_id)
@@ -98,22 +116,11 @@ impl LoveLetterStateMachine {
             Card::Baron => {
                 let target_player_id = match staged_play.target_player {
                     Some(target_player_id) => target_player_id,
-                    None => return failed_precondition("To play Baron, you must select a target player", round_data, staged_play),
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play Baron, you must select a target player", round_data, staged_play),
                 };
 
-                // Eliminate player
-                let client_card = round_data.players.get_card(&client_player_id)
-                    .expect("Game is in unrecoverable, invalid state: Committing player did not have a card.");
-                let other_card = round_data.players.get_card(&target_player_id)
-                    .expect("Game is in unrecoverable, invalid state: Player targeted another player who isn't in round.");
-
-                let eliminated_player_id_and_card = if client_card > other_card {
-                    Some((target_player_id.clone(), other_card))
-                } else if client_card < other_card {
-                    Some((client_player_id.clone(), client_card))
-                } else {
-                    None
-                };
+                let eliminated_player_id_and_card = compare_cards(&round_data, &client_player_id, &target_player_id, deck_variant)
+                    .map(|(_, loser)| loser);
 
                 CommittedPlayOutcome::Baron {
                     target_player_id,
@@ -127,13 +134,13 @@ impl LoveLetterStateMachine {
             Card::Prince => {
                 let target_player_id = match staged_play.target_player {
                     Some(target_player_id) => target_player_id,
-                    None => return failed_precondition("To play Prince, you must select a target player", round_data, staged_play),
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play Prince, you must select a target player", round_data, staged_play),
                 };
 
                 // Discard and draw new card
-                let new_card = round_data.deck.pop()
-                    .expect("Round was incorrectly not ended when <= 1 card remained in the deck");
+                let new_card = round_data.draw_replacement_card();
                 let discarded_card = round_data.players.replace_card(target_player_id.clone(), new_card);
+                round_data.discard(&target_player_id, discarded_card);
 
                 CommittedPlayOutcome::Prince {
                     target_player_id,
@@ -143,14 +150,169 @@ impl LoveLetterStateMachine {
             Card::King => {
                 let target_player_id = match staged_play.target_player {
                     Some(target_player_id) => target_player_id,
-                    None => return failed_precondition("To play King, you must select a target player", round_data, staged_play),
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play King, you must select a target player", round_data, staged_play),
                 };
+
+                round_data.players.swap_cards(&client_player_id, &target_player_id);
+
                 CommittedPlayOutcome::King {
                     target_player_id,
                 }
             },
             Card::Countess => CommittedPlayOutcome::Countess,
             Card::Princess => CommittedPlayOutcome::Princess,
+            Card::Spy => CommittedPlayOutcome::Spy,
+            Card::Chancellor => {
+                // Drawn cards can be empty if the deck ran low, then there's nothing to choose.
+                if !staged_play.drawn_cards.is_empty() {
+                    let kept_card = match staged_play.target_card {
+                        Some(kept_card) => kept_card,
+                        None => return illegal_play(IllegalPlay::MissingSelection, "To play Chancellor, you must select a card to keep", round_data, staged_play),
+                    };
+
+                    let hand_card = round_data.players.get_card(&client_player_id)
+                        .expect("Game is in unrecoverable, invalid state: Committing player did not have a card.");
+                    let mut returned_cards = staged_play.drawn_cards;
+                    returned_cards.push(hand_card);
+                    let kept_index = returned_cards.iter()
+                        .position(|card| *card == kept_card)
+                        .expect("Kept card is validated when it's selected");
+                    returned_cards.remove(kept_index);
+
+                    round_data.players.replace_card(client_player_id.clone(), kept_card);
+                    for card in returned_cards {
+                        // Bottom of the deck
+                        round_data.deck.insert(0, card);
+                    }
+                }
+
+                CommittedPlayOutcome::Chancellor
+            },
+            Card::Jester => {
+                let target_player_id = match staged_play.target_player {
+                    Some(target_player_id) => target_player_id,
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play Jester, you must select a target player", round_data, staged_play),
+                };
+
+                // Selecting self only happens when the Sycophant forces it, it has no effect.
+                if target_player_id != client_player_id {
+                    round_data.jester_target_by_player_id.insert(client_player_id.clone(), target_player_id.clone());
+                }
+
+                CommittedPlayOutcome::Jester {
+                    target_player_id,
+                }
+            },
+            Card::Assassin => CommittedPlayOutcome::Assassin,
+            Card::Cardinal => {
+                let (target_player_id, second_target_player_id) = match (&staged_play.target_player, &staged_play.second_target_player) {
+                    (Some(target_player_id), Some(second_target_player_id)) => (target_player_id.clone(), second_target_player_id.clone()),
+                    _ => return illegal_play(IllegalPlay::MissingSelection, "To play Cardinal, you must select 2 target players", round_data, staged_play),
+                };
+                if !sycophant_allows(&round_data, &staged_play) {
+                    return illegal_play(IllegalPlay::TargetNotSycophantChoice, "The Sycophant requires you to select the player they chose", round_data, staged_play);
+                }
+
+                round_data.players.swap_cards(&target_player_id, &second_target_player_id);
+                let viewed_card = round_data.players.get_card(&target_player_id)
+                    .expect("Game is in unrecoverable, invalid state: Player selected is not in round.");
+
+                CommittedPlayOutcome::Cardinal {
+                    target_player_id,
+                    second_target_player_id,
+                    viewed_card,
+                }
+            },
+            Card::Baroness => {
+                if staged_play.target_player.is_none() {
+                    return illegal_play(IllegalPlay::MissingSelection, "To play Baroness, you must select 1 or 2 target players", round_data, staged_play);
+                }
+                if !sycophant_allows(&round_data, &staged_play) {
+                    return illegal_play(IllegalPlay::TargetNotSycophantChoice, "The Sycophant requires you to select the player they chose", round_data, staged_play);
+                }
+
+                let viewed_card_by_player_id = staged_play.target_player_ids()
+                    .into_iter()
+                    .map(|player_id| {
+                        let card = round_data.players.get_card(player_id)
+                            .expect("Game is in unrecoverable, invalid state: Player selected is not in round.");
+                        (player_id.clone(), card)
+                    })
+                    .collect();
+
+                CommittedPlayOutcome::Baroness {
+                    viewed_card_by_player_id,
+                }
+            },
+            Card::Sycophant => {
+                let target_player_id = match staged_play.target_player {
+                    Some(target_player_id) => target_player_id,
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play Sycophant, you must select a target player", round_data, staged_play),
+                };
+
+                CommittedPlayOutcome::Sycophant {
+                    target_player_id,
+                }
+            },
+            Card::Count => CommittedPlayOutcome::Count,
+            Card::Constable => CommittedPlayOutcome::Constable,
+            Card::DowagerQueen => {
+                let target_player_id = match staged_play.target_player {
+                    Some(target_player_id) => target_player_id,
+                    None => return illegal_play(IllegalPlay::MissingSelection, "To play Dowager Queen, you must select a target player", round_data, staged_play),
+                };
+
+                let eliminated_player_id_and_card = compare_cards(&round_data, &client_player_id, &target_player_id, deck_variant)
+                    .map(|(winner, _)| winner);
+
+                CommittedPlayOutcome::DowagerQueen {
+                    target_player_id,
+                    eliminated_player_id_and_card,
+                }
+            },
+            Card::Bishop => {
+                let (target_player_id, guessed_card) = {
+                    let staged_play_clone = staged_play.clone();
+                    match (staged_play.target_player, staged_play.target_card) {
+                        (Some(target_player_id), Some(target_card)) => (target_player_id, target_card),
+                        (None, _) => return illegal_play(IllegalPlay::MissingSelection, "To play Bishop, you must select a target player", round_data, staged_play_clone),
+                        (_, None) => return illegal_play(IllegalPlay::MissingSelection, "To play Bishop, you must select a target card", round_data, staged_play_clone),
+                    }
+                };
+
+                // Check guess. Selecting self only happens when the Sycophant forces it, it has
+                // no effect.
+                let actual_card = round_data.players
+                    .get_card(&target_player_id)
+                    .expect("Game is in unrecoverable, invalid state: Player selected is not in round.");
+                let correct = target_player_id != client_player_id
+                    && guessed_card.value(deck_variant) == actual_card.value(deck_variant);
+
+                // Gain a token, and the target discards and draws a new card
+                let discarded_card = if correct {
+                    self.game_data.add_win(&client_player_id);
+
+                    let new_card = round_data.draw_replacement_card();
+                    let discarded_card = round_data.players.replace_card(target_player_id.clone(), new_card);
+                    round_data.discard(&target_player_id, discarded_card);
+                    Some(discarded_card)
+                } else {
+                    None
+                };
+
+                CommittedPlayOutcome::Bishop {
+                    target_player_id,
+                    guessed_card,
+                    correct,
+                    discarded_card,
+                }
+            },
+        };
+
+        // The Sycophant's effect lasts for exactly 1 play
+        round_data.sycophant_player_id = match &committed_play_outcome {
+            CommittedPlayOutcome::Sycophant { target_player_id } => Some(target_player_id.clone()),
+            _ => None,
         };
 
         // Update most recent commit
@@ -163,8 +325,14 @@ impl LoveLetterStateMachine {
         match committed_play.player_id_to_eliminate() {
             None => round_data.players.increment_turn(),
             Some(player_id) => {
+                let player_id = player_id.clone();
                 let discarded = round_data.players.eliminate_and_increment_turn(&player_id);
-                round_data.play_history.push(discarded);
+
+                // Constable only counts if it was discarded before the player was knocked out
+                if round_data.has_discarded(&player_id, Card::Constable) {
+                    self.game_data.add_win(&player_id);
+                }
+                round_data.discard(&player_id, discarded);
             },
         }
         round_data.most_recent_play_details.replace(committed_play);
@@ -172,17 +340,16 @@ impl LoveLetterStateMachine {
         // Clear next turn player from Handmaids (played from previous turn).
         round_data.handmaid_immunity_player_ids.remove(round_data.players.current_turn_player_id());
 
-        // State transition
-        let to_state = if round_data.players.remaining_player_ids().len() < 2 {
-            LoveLetterState::RoundIntermission(
-                self.complete_round(round_data),
-                self.unready_player_list()
-            )
-        } else if round_data.deck.len() < 2 {
-            LoveLetterState::RoundIntermission(
-                self.complete_round(round_data),
-                self.unready_player_list()
-            )
+        // State transition. Bishop and Constable tokens can end the game mid-round.
+        let to_state = if self.game_data.is_complete() {
+            LoveLetterState::GameComplete(round_data.into_interrupted_round_result())
+        } else if round_data.players.remaining_player_ids().len() < 2 || round_data.deck.is_empty() {
+            let round_result = self.complete_round(round_data);
+            if self.game_data.is_complete() {
+                LoveLetterState::GameComplete(round_result)
+            } else {
+                LoveLetterState::RoundIntermission(round_result, self.unready_player_list())
+            }
         } else {
             LoveLetterState::TurnIntermission(
                 round_data,
@@ -201,45 +368,89 @@ impl LoveLetterStateMachine {
         UnreadyPlayers::new(self.game_data.player_id_turn_order.clone())
     }
 
-    fn complete_round(&mut self, round_data: RoundData) -> RoundResult {
-        let final_card_by_player_id = round_data.players.into_player_card_map();
-        let mut player_cards = final_card_by_player_id.clone().into_iter();
-
-        let (winner, mut high_card) = player_cards.next()
-            .expect("LoveLetter round ended with no players remaining");
-        let mut winners: Vec<String> = vec![winner];
-
-        for (player_id, card) in player_cards {
-            if card > high_card {
-                winners.clear();
-                winners.push(player_id);
-                high_card = card;
-            } else if card == high_card {
-                winners.push(player_id);
+    /// Tokens are given to:
+    /// * The round's winners, see `RoundData::into_round_result()`.
+    /// * Jester players, whose chosen player won.
+    /// * The only remaining player who discarded a Spy, if there's exactly one.
+    fn complete_round(&mut self, mut round_data: RoundData) -> RoundResult {
+        let jester_target_by_player_id = std::mem::take(&mut round_data.jester_target_by_player_id);
+        let spy_player_ids: Vec<String> = round_data.players
+            .remaining_player_ids()
+            .iter()
+            .filter(|player_id| round_data.has_discarded(player_id, Card::Spy))
+            .cloned()
+            .collect();
+
+        let round_result = round_data.into_round_result(self.game_data.deck_variant);
+
+        for player_id in round_result.winner_player_ids.iter() {
+            self.game_data.add_win(player_id);
+        }
+
+        for (jester_player_id, target_player_id) in jester_target_by_player_id {
+            if round_result.winner_player_ids.contains(&target_player_id) {
+                self.game_data.add_win(&jester_player_id);
             }
         }
 
-        for player_id in winners {
-            *self.game_data.wins_per_player.entry(player_id).or_insert(0) += 1;
+        if let [spy_player_id] = spy_player_ids.as_slice() {
+            self.game_data.add_win(spy_player_id);
         }
 
-        RoundResult::new(final_card_by_player_id)
+        round_result
+    }
+}
+
+/// Baron and Dowager Queen. Returns the (winner, loser) player+card, or None if it's a tie.
+fn compare_cards(
+    round_data: &RoundData,
+    client_player_id: &String,
+    target_player_id: &String,
+    deck_variant: DeckVariant,
+) -> Option<((String, Card), (String, Card))> {
+    let client_card = round_data.players.get_card(client_player_id)
+        .expect("Game is in unrecoverable, invalid state: Committing player did not have a card.");
+    let other_card = round_data.players.get_card(target_player_id)
+        .expect("Game is in unrecoverable, invalid state: Player targeted another player who isn't in round.");
+
+    let client = (client_player_id.clone(), client_card);
+    let other = (target_player_id.clone(), other_card);
+    if client_card.value(deck_variant) > other_card.value(deck_variant) {
+        Some((client, other))
+    } else if client_card.value(deck_variant) < other_card.value(deck_variant) {
+        Some((other, client))
+    } else {
+        None
+    }
+}
+
+/// Single target cards are checked when the target is selected, this is for cards with 2 targets.
+fn sycophant_allows(round_data: &RoundData, staged_play: &StagedPlay) -> bool {
+    match round_data.sycophant_player_id.as_ref() {
+        None => true,
+        Some(sycophant_player_id) => staged_play.target_player_ids().contains(&sycophant_player_id),
     }
 }
 
 impl CommittedPlay {
     pub fn player_id_to_eliminate(&self) -> Option<&String> {
         match &self.outcome {
-            CommittedPlayOutcome::Guard { target_player_id, correct, .. } => {
-                if *correct {
+            CommittedPlayOutcome::Guard { target_player_id, correct, assassinated, .. } => {
+                if *assassinated {
+                    Some(&self.committer_player_id)
+                } else if *correct {
                     Some(target_player_id)
                 } else {
                     None
                 }
             },
-            CommittedPlayOutcome::Baron { eliminated_player_id_and_card, .. } => {
+            CommittedPlayOutcome::Baron { eliminated_player_id_and_card, .. }
+            | CommittedPlayOutcome::DowagerQueen { eliminated_player_id_and_card, .. } => {
                 eliminated_player_id_and_card.as_ref().map(|x| &x.0)
             },
+            CommittedPlayOutcome::Bishop { target_player_id, discarded_card: Some(Card::Princess), .. } => {
+                Some(target_player_id)
+            },
             CommittedPlayOutcome::Prince { target_player_id, discarded_card: Card::Princess, } => {
                 Some(&target_player_id)
             },
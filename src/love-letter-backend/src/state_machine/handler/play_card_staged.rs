@@ -1,6 +1,6 @@
-use crate::events::PlayCardSource;
+use crate::events::{Card, PlayCardSource};
 use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
-use crate::types::{StagedPlay, RoundData};
+use crate::types::{StagedPlay, RoundData, IllegalPlay};
 use tonic::Status;
 
 impl LoveLetterStateMachine {
@@ -15,7 +15,7 @@ impl LoveLetterStateMachine {
             LoveLetterState::PlayPending(round_data) => self.handle_staging(&client_player_id, card_source, round_data),
             LoveLetterState::PlayStaging(round_data, staged_play) => self.handle_staging_idempotent(&client_player_id, round_data, staged_play),
             _ => {
-                self.streams.send_err(&client_player_id, Status::failed_precondition("Can't play card while in current state"));
+                self.reject_event(&client_player_id, Status::failed_precondition("Can't play card while in current state"));
                 from_state
             },
         }
@@ -24,35 +24,62 @@ impl LoveLetterStateMachine {
     /// We do a lot of mutations and logic very similar to how you'd imagine this being done in the
     /// physical world.
     ///
+    /// 0. Ensure the play is legal.
     /// 1. Remove top card from deck.
     /// 2. Ensure player's hand either has same card or top deck card.
     /// 3. Ensure *other* card is marked as the played card AND added to play_history.
     /// 4. Remove Handmaid effect if we played it previous turn.
-    /// 5. Move to next state based on if there's any action to do or not.
+    /// 5. Chancellor draws its 2 extra cards.
+    /// 6. Move to next state based on if there's any action to do or not.
     fn handle_staging(&self, client_player_id: &String, card_source: PlayCardSource, mut round_data: RoundData) -> LoveLetterState {
         // Check: Is my turn
         if client_player_id != round_data.players.current_turn_player_id() {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("Can't play card, not your turn"));
+            self.reject_illegal_play(client_player_id, IllegalPlay::NotYourTurn, "Can't play card, not your turn");
             return LoveLetterState::PlayPending(round_data);
         }
 
         // Sanity check: Deck is not empty
-        let top_deck = round_data.deck.pop()
+        let top_deck = *round_data.deck.last()
             .expect("Illegal game state: We're in 'PlayPending' state with no cards in deck");
 
-        // Discard current player's card and cycle new card
+        // Check: Countess must be played if the other card is the King or Prince
+        let hand_card = round_data.players.get_card(client_player_id)
+            .expect("Illegal game state: Current turn player has no card");
+        let (played_card, kept_card) = match card_source {
+            PlayCardSource::Hand => (hand_card, top_deck),
+            PlayCardSource::TopDeck => (top_deck, hand_card),
+        };
+        if kept_card == Card::Countess && (played_card == Card::King || played_card == Card::Prince) {
+            let message = format!("You must play the Countess, because your other card is the {:?}", played_card);
+            self.reject_illegal_play(client_player_id, IllegalPlay::CountessMustBePlayed, message);
+            return LoveLetterState::PlayPending(round_data);
+        }
+
+        // Draw the top card, discard current player's card and cycle new card
+        round_data.deck.pop();
         let played_card = match card_source {
             PlayCardSource::Hand => round_data.players.replace_card(client_player_id.clone(), top_deck),
             PlayCardSource::TopDeck => top_deck,
         };
 
         // Append to play history
-        round_data.play_history.push(played_card);
+        round_data.discard(client_player_id, played_card);
+
+        let mut staged_play = StagedPlay::new(played_card);
+
+        // The Chancellor draws up to 2 cards, the player picks which card to keep before commit.
+        if played_card == Card::Chancellor {
+            for _ in 0..2 {
+                if let Some(card) = round_data.deck.pop() {
+                    staged_play.drawn_cards.push(card);
+                }
+            }
+        }
 
         // TODO:3 if selection not-needed, auto-commit.
         // Alternatively, client can be written to immediately send commit for certain cards.
         // This would keep the backend less modal.
-        LoveLetterState::PlayStaging(round_data, StagedPlay::new(played_card))
+        LoveLetterState::PlayStaging(round_data, staged_play)
     }
 
     /// We implement idempotency, to some extent. If a caller retried with a different request
@@ -60,7 +87,7 @@ impl LoveLetterStateMachine {
     fn handle_staging_idempotent(&self, client_player_id: &String, round_data: RoundData, staged_play: StagedPlay) -> LoveLetterState {
         // Is my turn
         if client_player_id != round_data.players.current_turn_player_id() {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("Can't play card, not your turn"));
+            self.reject_illegal_play(client_player_id, IllegalPlay::NotYourTurn, "Can't play card, not your turn");
             return LoveLetterState::PlayStaging(round_data, staged_play)
         }
 
@@ -68,7 +95,7 @@ impl LoveLetterStateMachine {
         let to_state = LoveLetterState::PlayStaging(round_data, staged_play);
 
         // Notify caller of latest game state.
-        self.streams.send_err(client_player_id, Status::out_of_range("Your local state is stale."));
+        self.reject_event(client_player_id, Status::out_of_range("Your local state is stale."));
         self.send_game_state(&to_state, client_player_id);
         to_state
     }
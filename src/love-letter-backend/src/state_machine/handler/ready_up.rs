@@ -2,7 +2,7 @@ use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
 use crate::types::RoundData;
 
 impl LoveLetterStateMachine {
-    pub fn ready_up(&self, from_state: LoveLetterState, client_player_id: String) -> LoveLetterState {
+    pub fn ready_up(&mut self, from_state: LoveLetterState, client_player_id: String) -> LoveLetterState {
         match from_state {
             LoveLetterState::TurnIntermission(round_data, mut unready_players) => {
                 unready_players.ready_up(&client_player_id);
@@ -30,12 +30,13 @@ impl LoveLetterStateMachine {
             },
             _ => {
                 // Do nothing and drop message
+                self.ignore_event();
                 from_state
             },
         }
     }
 
-    fn new_round(&self) -> RoundData {
-        RoundData::new(&self.game_data.player_id_turn_order)
+    pub fn new_round(&mut self) -> RoundData {
+        RoundData::new(&self.game_data.player_id_turn_order, self.game_data.deck_variant, &mut self.game_data.rng)
     }
 }
@@ -1,7 +1,7 @@
+use crate::deck;
 use crate::events::Card;
 use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
-use crate::types::{StagedPlay, RoundData};
-use tonic::Status;
+use crate::types::{StagedPlay, RoundData, IllegalPlay};
 
 impl LoveLetterStateMachine {
 
@@ -19,6 +19,7 @@ impl LoveLetterStateMachine {
                 // Missing: Player ID validation
                 // Missing: Card validation
                 // But this doesn't matter, we just drop the event and proactively update the client's state.
+                self.ignore_event();
                 self.send_game_state(&from_state, &client_player_id);
                 from_state
             },
@@ -34,20 +35,34 @@ impl LoveLetterStateMachine {
     ) -> LoveLetterState {
         // Is my turn
         if client_player_id != round_data.players.current_turn_player_id() {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("Can't select target card, not your turn"));
+            self.reject_illegal_play(client_player_id, IllegalPlay::NotYourTurn, "Can't select target card, not your turn");
             return LoveLetterState::PlayStaging(round_data, staged_play);
         }
 
-        // Staged card needs a card selection
-        if staged_play.played_card != Card::Guard {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("The card you played doesn't require selecting a target card"));
-            return LoveLetterState::PlayStaging(round_data, staged_play);
-        }
-
-        // Guard-specific validation
-        if staged_play.played_card == Card::Guard && target_card == Card::Guard {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("You cannot guess another player has 'Guard' for the Guard action."));
-            return LoveLetterState::PlayStaging(round_data, staged_play);
+        // Card-specific validation
+        match staged_play.played_card {
+            Card::Guard | Card::Bishop => {
+                if target_card.value(self.game_data.deck_variant) == Card::Guard.value(self.game_data.deck_variant) {
+                    let message = format!("You cannot guess another player has 'Guard' for the {:?} action.", staged_play.played_card);
+                    self.reject_illegal_play(client_player_id, IllegalPlay::CantGuessGuard, message);
+                    return LoveLetterState::PlayStaging(round_data, staged_play);
+                }
+                if !deck::contains(self.game_data.deck_variant, target_card) {
+                    self.reject_illegal_play(client_player_id, IllegalPlay::CardNotInDeck, "The card you guessed isn't in this game's deck.");
+                    return LoveLetterState::PlayStaging(round_data, staged_play);
+                }
+            },
+            Card::Chancellor => {
+                let hand_card = round_data.players.get_card(client_player_id);
+                if hand_card != Some(target_card) && !staged_play.drawn_cards.contains(&target_card) {
+                    self.reject_illegal_play(client_player_id, IllegalPlay::CardNotAvailable, "You can only keep your card in hand, or one of the cards you drew.");
+                    return LoveLetterState::PlayStaging(round_data, staged_play);
+                }
+            },
+            _ => {
+                self.reject_illegal_play(client_player_id, IllegalPlay::CardHasNoTargetCard, "The card you played doesn't require selecting a target card");
+                return LoveLetterState::PlayStaging(round_data, staged_play);
+            },
         }
 
         // Apply update
@@ -1,6 +1,5 @@
 use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
-use tonic::Status;
-use crate::types::{RoundData, StagedPlay};
+use crate::types::{RoundData, StagedPlay, IllegalPlay};
 use crate::events::Card;
 
 impl LoveLetterStateMachine {
@@ -19,6 +18,7 @@ impl LoveLetterStateMachine {
                 // Missing: Player ID validation
                 // Missing: Card validation
                 // But this doesn't matter, we just drop the event and proactively update the client's state.
+                self.ignore_event();
                 self.send_game_state(&from_state, &client_player_id);
                 from_state
             },
@@ -34,45 +34,58 @@ impl LoveLetterStateMachine {
     ) -> LoveLetterState {
         // Check: Is my turn
         if client_player_id != round_data.players.current_turn_player_id() {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("Can't select target player, not your turn"));
+            self.reject_illegal_play(client_player_id, IllegalPlay::NotYourTurn, "Can't select target player, not your turn");
             return LoveLetterState::PlayStaging(round_data, staged_play);
         }
 
         // Check: selected player is still in game
         if !round_data.players.remaining_player_ids().contains(&target_player_id) {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("Selected player is not in the round."));
+            self.reject_illegal_play(client_player_id, IllegalPlay::TargetNotInRound, "Selected player is not in the round.");
             return LoveLetterState::PlayStaging(round_data, staged_play);
         }
 
-        // 1. Staged card needs a player selection
-        // 2. Card-specific validation:
-        //    a. Selected self
-        match staged_play.played_card {
-            Card::Guard | Card::Priest | Card::Baron | Card::King => {
-                if client_player_id == &target_player_id {
-                    if there_exists_a_non_self_targetable_player(&round_data, client_player_id) {
-                        self.streams.send_err(&client_player_id, Status::failed_precondition("Cannot select self"));
-                        return LoveLetterState::PlayStaging(round_data, staged_play);
-                    }
-                    // else, valid
-                }
-            },
-            Card::Prince => { /* No card-specific validation */ },
-            _ => {
-                self.streams.send_err(&client_player_id, Status::failed_precondition("The card you played doesn't require selecting a target player"));
+        // Check: Staged card needs a player selection
+        let targeting = match PlayerTargeting::of(staged_play.played_card) {
+            Some(targeting) => targeting,
+            None => {
+                self.reject_illegal_play(client_player_id, IllegalPlay::CardHasNoTargetPlayer, "The card you played doesn't require selecting a target player");
                 return LoveLetterState::PlayStaging(round_data, staged_play);
-            }
+            },
+        };
+
+        // Check: Sycophant forces the target. For cards with 2 targets, this is checked on commit.
+        let is_sycophant_target = round_data.sycophant_player_id.as_ref() == Some(&target_player_id);
+        if let (Some(sycophant_player_id), false, false) = (&round_data.sycophant_player_id, targeting.is_two_players(), is_sycophant_target) {
+            let message = format!("The Sycophant requires you to select '{}'", sycophant_player_id);
+            self.reject_illegal_play(client_player_id, IllegalPlay::TargetNotSycophantChoice, message);
+            return LoveLetterState::PlayStaging(round_data, staged_play);
+        }
+
+        // Check: Selected self
+        if !targeting.can_target_self()
+            && client_player_id == &target_player_id
+            && !is_sycophant_target
+            && there_exists_a_non_self_targetable_player(&round_data, client_player_id) {
+            self.reject_illegal_play(client_player_id, IllegalPlay::TargetIsSelf, "Cannot select self");
+            return LoveLetterState::PlayStaging(round_data, staged_play);
         }
 
         // Check: selected player is not Handmaid
         if round_data.handmaid_immunity_player_ids.contains(&target_player_id) {
-            self.streams.send_err(&client_player_id, Status::failed_precondition("Selected player is Handmaid."));
+            self.reject_illegal_play(client_player_id, IllegalPlay::TargetHasHandmaid, "Selected player is Handmaid.");
             return LoveLetterState::PlayStaging(round_data, staged_play);
         }
 
         // Apply update
         let mut staged_play = staged_play;
-        staged_play.set_target_player(target_player_id);
+        if targeting.is_two_players() {
+            if !staged_play.toggle_target_player(target_player_id) {
+                self.reject_illegal_play(client_player_id, IllegalPlay::TooManyTargets, "You already selected 2 players. Select one of them again to un-select them.");
+                return LoveLetterState::PlayStaging(round_data, staged_play);
+            }
+        } else {
+            staged_play.set_target_player(target_player_id);
+        }
 
         // Notify state change
         let to_state = LoveLetterState::PlayStaging(round_data, staged_play);
@@ -82,6 +95,69 @@ impl LoveLetterStateMachine {
     }
 }
 
+/// How a card chooses its target players.
+pub(crate) enum PlayerTargeting {
+    /// Guard, Priest, Baron, King, Jester, Dowager Queen, Bishop
+    OneOther,
+    /// Prince, Sycophant
+    OneAny,
+    /// Cardinal: 2 players, which may include self.
+    TwoAny,
+    /// Baroness: 1 or 2 other players.
+    UpToTwoOthers,
+}
+
+impl PlayerTargeting {
+    pub(crate) fn of(card: Card) -> Option<Self> {
+        match card {
+            Card::Guard
+            | Card::Priest
+            | Card::Baron
+            | Card::King
+            | Card::Jester
+            | Card::DowagerQueen
+            | Card::Bishop => Some(PlayerTargeting::OneOther),
+            Card::Prince | Card::Sycophant => Some(PlayerTargeting::OneAny),
+            Card::Cardinal => Some(PlayerTargeting::TwoAny),
+            Card::Baroness => Some(PlayerTargeting::UpToTwoOthers),
+            Card::Handmaid
+            | Card::Countess
+            | Card::Princess
+            | Card::Spy
+            | Card::Chancellor
+            | Card::Assassin
+            | Card::Count
+            | Card::Constable => None,
+        }
+    }
+
+    pub(crate) fn can_target_self(&self) -> bool {
+        match self {
+            PlayerTargeting::OneAny | PlayerTargeting::TwoAny => true,
+            PlayerTargeting::OneOther | PlayerTargeting::UpToTwoOthers => false,
+        }
+    }
+
+    pub(crate) fn is_two_players(&self) -> bool {
+        match self {
+            PlayerTargeting::TwoAny | PlayerTargeting::UpToTwoOthers => true,
+            PlayerTargeting::OneOther | PlayerTargeting::OneAny => false,
+        }
+    }
+}
+
+/// False if the card targets players, but every player it could target is protected by the
+/// Handmaid. The card is then committed without any selection, and has no effect.
+pub(super) fn has_valid_target(round_data: &RoundData, card: Card, client_player_id: &String) -> bool {
+    match PlayerTargeting::of(card) {
+        None | Some(PlayerTargeting::OneAny) => true,
+        Some(PlayerTargeting::OneOther) | Some(PlayerTargeting::UpToTwoOthers) => {
+            there_exists_a_non_self_targetable_player(round_data, client_player_id)
+        },
+        Some(PlayerTargeting::TwoAny) => round_data.targetable_player_ids().len() >= 2,
+    }
+}
+
 fn there_exists_a_non_self_targetable_player(round_data: &RoundData, client_player_id: &String) -> bool {
     for p in round_data.players.remaining_player_ids() {
         if p != client_player_id && !round_data.handmaid_immunity_player_ids.contains(p) {
@@ -1,36 +1,88 @@
 use crate::state_machine::{LoveLetterStateMachine, LoveLetterState};
 use crate::types::{GameData, RoundData, RoundResult, UnreadyPlayers};
 use backend_framework::wire_api::proto_frj_ngn::{ProtoLvLeGameState, ProtoLvLeCard, ProtoLvLeCardSelection};
-use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::{ProtoLvLeRoundState, ProtoLvLePlayer, Stage, ProtoLvLeResultState, proto_lv_le_round_state, ProtoLvLeTurnIntermissionState};
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::{ProtoLvLeRoundState, ProtoLvLePlayer, Stage, ProtoLvLeResultState, proto_lv_le_round_state, ProtoLvLeTurnIntermissionState, ProtoLvLeGameCompleteState, ProtoLvLeDiscards, ProtoLvLeHiddenCards};
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::proto_lv_le_result_state::ProtoLvLeRoundEndReason;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_options::ProtoLvLeDeckVariant;
 use std::collections::HashMap;
 
+/// Who a game state is being built for. Spectators aren't in the game, even if their ID matches
+/// a player's, so they're only shown public information.
+#[derive(Copy, Clone)]
+enum Viewer<'a> {
+    Player(&'a String),
+    Spectator {
+        /// Also show the cards that nobody saw, once the round is over.
+        god_view: bool,
+    },
+}
+
+impl<'a> Viewer<'a> {
+    fn player_id(self) -> Option<&'a String> {
+        match self {
+            Viewer::Player(player_id) => Some(player_id),
+            Viewer::Spectator { .. } => None,
+        }
+    }
+
+    fn god_view(self) -> bool {
+        match self {
+            Viewer::Player(_) => false,
+            Viewer::Spectator { god_view } => god_view,
+        }
+    }
+}
+
 impl LoveLetterStateMachine {
     pub fn send_game_state(&self, state: &LoveLetterState, player_id: &String) {
         let proto_all_players = get_proto_all_players(&self.game_data);
 
-        self.send_game_state_to_player(player_id, state, proto_all_players);
+        let proto_state = self.proto_game_state(state, Viewer::Player(player_id), proto_all_players);
+        self.streams.send_msg(player_id, proto_state);
     }
 
+    pub fn send_game_state_to_spectator(&self, state: &LoveLetterState, spectator_id: &String) {
+        let proto_all_players = get_proto_all_players(&self.game_data);
+
+        // Not found if the spectator's stream was rejected
+        if let Some((_, god_view)) = self.streams.spectators().find(|(id, _)| *id == spectator_id) {
+            let proto_state = self.proto_game_state(state, Viewer::Spectator { god_view }, proto_all_players);
+            self.streams.send_spectator_msg(spectator_id, proto_state);
+        }
+    }
+
+    /// Every state change is sent to all players, so this is also where the turn timer moves on
+    /// to the next deadline.
     pub fn send_game_state_to_all(&self, state: &LoveLetterState) {
+        self.turn_timer.follow(state);
         let proto_all_players = get_proto_all_players(&self.game_data);
 
         for player_id in self.game_data.player_id_turn_order.iter() {
-            self.send_game_state_to_player(player_id, state, proto_all_players.clone());
+            let proto_state = self.proto_game_state(state, Viewer::Player(player_id), proto_all_players.clone());
+            self.streams.send_msg(player_id, proto_state);
+        }
+
+        for (spectator_id, god_view) in self.streams.spectators() {
+            let proto_state = self.proto_game_state(state, Viewer::Spectator { god_view }, proto_all_players.clone());
+            self.streams.send_spectator_msg(spectator_id, proto_state);
         }
     }
 
-    fn send_game_state_to_player(
+    fn proto_game_state(
         &self,
-        player_id: &String,
         state: &LoveLetterState,
+        viewer: Viewer,
         proto_all_players: Vec<ProtoLvLePlayer>
-    ) {
-        let proto_state = ProtoLvLeGameState {
+    ) -> ProtoLvLeGameState {
+        ProtoLvLeGameState {
             clock: 0,
             players: proto_all_players,
-            stage: Some(into_proto_stage(state, player_id)),
-        };
-        self.streams.send_msg(player_id, proto_state);
+            stage: Some(into_proto_stage(state, viewer, &self.game_data)),
+            win_threshold: self.game_data.win_threshold as u32,
+            deck_variant: ProtoLvLeDeckVariant::from(self.game_data.deck_variant) as i32,
+            deadline: self.turn_timer.deadline_epoch_millis(),
+            presence: self.streams.presence(),
+        }
     }
 }
 
@@ -40,7 +92,7 @@ fn get_proto_all_players(game_data: &GameData) -> Vec<ProtoLvLePlayer> {
     for player_id in game_data.player_id_turn_order.iter() {
         let proto_player_state = ProtoLvLePlayer {
             player_id: player_id.to_string(),
-            round_wins: game_data.wins_per_player.get(player_id).map(|n| *n as u32).unwrap_or(0),
+            round_wins: game_data.wins(player_id) as u32,
         };
         proto_game_players.push(proto_player_state);
     }
@@ -48,7 +100,8 @@ fn get_proto_all_players(game_data: &GameData) -> Vec<ProtoLvLePlayer> {
     proto_game_players
 }
 
-fn into_proto_stage(state: &LoveLetterState, player_id: &String) -> Stage {
+fn into_proto_stage(state: &LoveLetterState, viewer: Viewer, game_data: &GameData) -> Stage {
+    let player_id = viewer.player_id();
     match state {
         LoveLetterState::PlayPending(round_data) => Stage::RoundInProgress(
             into_proto_round_state(
@@ -58,14 +111,22 @@ fn into_proto_stage(state: &LoveLetterState, player_id: &String) -> Stage {
                 None,
             )
         ),
-        LoveLetterState::PlayStaging(round_data, staged) => Stage::RoundInProgress(
-            into_proto_round_state(
-                round_data,
-                player_id,
-                Some(ProtoLvLeCardSelection::from(staged.clone())),
-                None,
+        LoveLetterState::PlayStaging(round_data, staged) => {
+            let mut staged = staged.clone();
+            // Only the player who played the Chancellor sees the cards they drew.
+            if player_id != Some(round_data.players.current_turn_player_id()) {
+                staged.drawn_cards.clear();
+            }
+
+            Stage::RoundInProgress(
+                into_proto_round_state(
+                    round_data,
+                    player_id,
+                    Some(ProtoLvLeCardSelection::from(staged)),
+                    None,
+                )
             )
-        ),
+        },
         LoveLetterState::TurnIntermission(round_data, unready_players) => Stage::RoundInProgress(
             into_proto_round_state(
                 round_data,
@@ -75,19 +136,22 @@ fn into_proto_stage(state: &LoveLetterState, player_id: &String) -> Stage {
             )
         ),
         LoveLetterState::RoundIntermission(round_result, unready_players) => Stage::RoundIntermission(
-            into_proto_result_state(round_result.clone(), unready_players.clone().into_inner())
+            into_proto_result_state(round_result.clone(), unready_players.clone().into_inner(), viewer.god_view())
+        ),
+        LoveLetterState::GameComplete(round_result) => Stage::GameComplete(
+            into_proto_game_complete_state(round_result.clone(), game_data, viewer.god_view())
         ),
     }
 }
 
 fn into_proto_round_state(
     round_data: &RoundData,
-    my_player_id: &String,
+    my_player_id: Option<&String>,
     staged_play: Option<ProtoLvLeCardSelection>,
     opt_unready_players: Option<UnreadyPlayers>,
 ) -> ProtoLvLeRoundState {
     let remaining_player_ids = round_data.players.remaining_player_ids().clone();
-    let my_hand: i32 = match round_data.players.get_card(my_player_id) {
+    let my_hand: i32 = match my_player_id.and_then(|player_id| round_data.players.get_card(player_id)) {
         None => 0,
         Some(card) => ProtoLvLeCard::from(card) as i32,
     };
@@ -106,26 +170,41 @@ fn into_proto_round_state(
         handmaid_player_ids.push(player_id);
     }
 
-    let is_my_turn = my_player_id == round_data.players.current_turn_player_id();
-    let turn: Option<proto_lv_le_round_state::Turn> = match (opt_unready_players, is_my_turn) {
+    // The drawn card is the top of the deck until a play is staged. After that, it's in the
+    // player's hand (or discarded), and the top of the deck is the next player's card.
+    let is_my_turn = my_player_id == Some(round_data.players.current_turn_player_id());
+    let is_drawn_card_on_deck = is_my_turn && staged_play.is_none();
+    let turn: Option<proto_lv_le_round_state::Turn> = match (opt_unready_players, round_data.deck.last()) {
         (Some(unready_players), _) => {
             Some(proto_lv_le_round_state::Turn::TurnIntermission(ProtoLvLeTurnIntermissionState {
                 unready_player_ids: unready_players.into_inner()
             }))
         }
-        (None, true) => {
-            round_data.deck
-                .last()
-                .map(|c| ProtoLvLeCard::from(*c) as i32)
-                .map(|i| proto_lv_le_round_state::Turn::MyDrawnCard(i))
+        (None, Some(drawn_card)) if is_drawn_card_on_deck => {
+            Some(proto_lv_le_round_state::Turn::MyDrawnCard(ProtoLvLeCard::from(*drawn_card) as i32))
         },
-        (None, false) => {
+        (None, _) => {
             Some(proto_lv_le_round_state::Turn::CurrentTurnPlayerId(
                 round_data.players.current_turn_player_id().to_string()
             ))
         },
     };
 
+    let face_up_cards = round_data.face_up_cards
+        .iter()
+        .map(|card| ProtoLvLeCard::from(*card) as i32)
+        .collect();
+
+    let discards = round_data.discards_by_player_id
+        .iter()
+        .map(|(player_id, cards)| {
+            let cards = cards.iter()
+                .map(|card| ProtoLvLeCard::from(*card) as i32)
+                .collect();
+            (player_id.clone(), ProtoLvLeDiscards { cards })
+        })
+        .collect();
+
     ProtoLvLeRoundState {
         remaining_player_ids,
         my_hand,
@@ -134,20 +213,61 @@ fn into_proto_round_state(
         play_history,
         handmaid_player_ids,
         turn,
+        sycophant_player_id: round_data.sycophant_player_id.clone().unwrap_or_default(),
+        jester_target_player_ids: round_data.jester_target_by_player_id.clone(),
+        discards,
+        face_up_cards,
     }
 }
 
 fn into_proto_result_state(
     round_result: RoundResult,
-    unready_player_ids: Vec<String>
+    unready_player_ids: Vec<String>,
+    god_view: bool,
 ) -> ProtoLvLeResultState {
     let mut final_cards = HashMap::new();
     for (player_id, card) in round_result.final_card_by_player_id {
         final_cards.insert(player_id, ProtoLvLeCard::from(card) as i32);
     }
 
+    let opt_hidden_cards = if god_view {
+        Some(ProtoLvLeHiddenCards {
+            opt_burn_card: round_result.burn_card.map_or(0, |card| ProtoLvLeCard::from(card) as i32),
+            undrawn_cards: round_result.undrawn_cards
+                .into_iter()
+                .map(|card| ProtoLvLeCard::from(card) as i32)
+                .collect(),
+        })
+    } else {
+        None
+    };
+
     ProtoLvLeResultState {
         final_cards,
         unready_player_ids,
+        opt_hidden_cards,
+        winner_player_ids: round_result.winner_player_ids,
+        end_reason: ProtoLvLeRoundEndReason::from(round_result.end_reason) as i32,
+        discard_totals: round_result.discard_total_by_player_id,
+    }
+}
+
+fn into_proto_game_complete_state(
+    final_round_result: RoundResult,
+    game_data: &GameData,
+    god_view: bool,
+) -> ProtoLvLeGameCompleteState {
+    let final_standings = game_data.standings()
+        .into_iter()
+        .map(|player_id| ProtoLvLePlayer {
+            round_wins: game_data.wins(&player_id) as u32,
+            player_id,
+        })
+        .collect();
+
+    ProtoLvLeGameCompleteState {
+        final_round: Some(into_proto_result_state(final_round_result, Vec::new(), god_view)),
+        final_standings,
+        winner_player_ids: game_data.winner_player_ids(),
     }
 }
@@ -2,11 +2,20 @@
 //! 1. `LoveLetterStateMachine` - holds data that is present for the entirety of the game.
 //! 2. `LoveLetterState` - holds data that is optionally present depending on the current game state.
 mod handler;
+mod turn_timer;
 
-use crate::types::{StagedPlay, GameData, RoundData, RoundResult, UnreadyPlayers};
+pub(crate) use crate::state_machine::handler::PlayerTargeting;
+
+use crate::options::LoveLetterOptions;
+use crate::state_machine::turn_timer::TurnTimer;
+use crate::types::{StagedPlay, GameData, RoundData, RoundResult, UnreadyPlayers, IllegalPlay};
 use backend_framework::data_stream::PlayerDataStreams;
 use backend_framework::streaming::StreamSender;
 use backend_framework::wire_api::proto_frj_ngn::ProtoLoveLetterDataOut;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::time::SystemTime;
+use tonic::Status;
 
 /// The possible states of an instance of the game.
 ///
@@ -29,12 +38,14 @@ use backend_framework::wire_api::proto_frj_ngn::ProtoLoveLetterDataOut;
 ///                                                   |                   |
 ///                                                   +-------------------+
 /// ```
+#[derive(Serialize, Deserialize)]
 pub enum LoveLetterState {
     PlayPending(RoundData),
     PlayStaging(RoundData, StagedPlay),
     TurnIntermission(RoundData, UnreadyPlayers),
     RoundIntermission(RoundResult, UnreadyPlayers),
-    // TODO:3 - `GameComplete(())`, or we could just let players play rounds endlessly :D
+    /// Result of the final round. See `GameData::winner_player_ids()` for who won the game.
+    GameComplete(RoundResult),
 }
 
 /// A state machine executor. It operates on states as inputs/outputs, not owned data.
@@ -42,24 +53,73 @@ pub enum LoveLetterState {
 pub struct LoveLetterStateMachine {
     streams: PlayerDataStreams<ProtoLoveLetterDataOut>,
     game_data: GameData,
+    turn_timer: TurnTimer,
+    /// Set when the event being handled didn't change the game, so it's left out of the game log.
+    event_rejected: Cell<bool>,
 }
 
 impl LoveLetterStateMachine {
-    pub fn new(player_ids: Vec<String>) -> Self {
-        let streams = PlayerDataStreams::new(player_ids.clone());
-        let game_data = GameData::new(player_ids);
+    pub fn new(player_ids: Vec<String>, options: &LoveLetterOptions, rng_seed: u64) -> Self {
+        LoveLetterStateMachine::from_game_data(GameData::new(player_ids, options, rng_seed))
+    }
+
+    /// Used when restoring a game, players re-register their streams when they reconnect.
+    pub fn from_game_data(game_data: GameData) -> Self {
+        let streams = PlayerDataStreams::new(game_data.player_id_turn_order.clone());
+        let turn_timer = TurnTimer::new(game_data.turn_timeout_secs, game_data.ready_up_timeout_secs);
 
         LoveLetterStateMachine {
             streams,
             game_data,
+            turn_timer,
+            event_rejected: Cell::new(false),
         }
     }
 
+    pub fn game_data(&self) -> &GameData {
+        &self.game_data
+    }
+
     pub fn add_stream(&mut self, player_id: String, stream: StreamSender<ProtoLoveLetterDataOut>) {
         self.streams.add_stream(player_id, stream);
     }
 
+    pub fn add_spectator_stream(&mut self, spectator_id: String, god_view: bool, stream: StreamSender<ProtoLoveLetterDataOut>) {
+        self.streams.add_spectator_stream(spectator_id, god_view, stream);
+    }
+
     pub fn all_player_ids(&self) -> &Vec<String> {
         &self.game_data.player_id_turn_order
     }
+
+    /// Start the deadline for the state. Used when the state is set without being sent to players,
+    /// i.e. when the game is created or restored, and after playing for players who timed out.
+    pub fn restart_turn_timer(&self, state: &LoveLetterState) {
+        self.turn_timer.restart_for(state);
+    }
+
+    pub fn is_deadline_expired(&self, now: SystemTime) -> bool {
+        self.turn_timer.is_expired(now)
+    }
+
+    /// Returns true if the most recently handled event was rejected, and resets the flag.
+    pub fn take_event_rejected(&self) -> bool {
+        self.event_rejected.replace(false)
+    }
+
+    /// Notify the player that their event is invalid in the current state.
+    fn reject_event(&self, player_id: &String, status: Status) {
+        self.event_rejected.set(true);
+        self.streams.send_err(player_id, status);
+    }
+
+    /// Notify the player that their play breaks the rules, see `IllegalPlay`.
+    fn reject_illegal_play(&self, player_id: &String, reason: IllegalPlay, message: impl Into<String>) {
+        self.reject_event(player_id, reason.into_status(message));
+    }
+
+    /// Drop an event that is invalid in the current state, without notifying the player.
+    fn ignore_event(&self) {
+        self.event_rejected.set(true);
+    }
 }
@@ -0,0 +1,87 @@
+use crate::state_machine::LoveLetterState;
+use std::cell::Cell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The parts of the game which have a time limit. A new deadline starts each time the game moves
+/// from one to the other.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum TimedPhase {
+    /// The current player has to play a card.
+    Turn,
+    /// Players have to ready up, between turns or rounds.
+    ReadyUp,
+}
+
+impl TimedPhase {
+    fn of(state: &LoveLetterState) -> Option<Self> {
+        match state {
+            LoveLetterState::PlayPending(_)
+            | LoveLetterState::PlayStaging(_, _) => Some(TimedPhase::Turn),
+            LoveLetterState::TurnIntermission(_, _)
+            | LoveLetterState::RoundIntermission(_, _) => Some(TimedPhase::ReadyUp),
+            LoveLetterState::GameComplete(_) => None,
+        }
+    }
+}
+
+/// Tracks the deadline of the current turn or ready up, for the time limits chosen by the host.
+///
+/// The deadline follows the state as it's sent to players (see `send_game_state_to_all()`), so
+/// it has interior mutability.
+pub struct TurnTimer {
+    turn_timeout: Option<Duration>,
+    ready_up_timeout: Option<Duration>,
+    phase: Cell<Option<TimedPhase>>,
+    deadline: Cell<Option<SystemTime>>,
+}
+
+impl TurnTimer {
+    pub fn new(turn_timeout_secs: Option<u32>, ready_up_timeout_secs: Option<u32>) -> Self {
+        let to_duration = |secs: Option<u32>| secs.map(|secs| Duration::from_secs(secs as u64));
+
+        TurnTimer {
+            turn_timeout: to_duration(turn_timeout_secs),
+            ready_up_timeout: to_duration(ready_up_timeout_secs),
+            phase: Cell::new(None),
+            deadline: Cell::new(None),
+        }
+    }
+
+    /// Start a new deadline if the state is in a different phase than before.
+    pub fn follow(&self, state: &LoveLetterState) {
+        let phase = TimedPhase::of(state);
+        if phase != self.phase.get() {
+            self.restart(phase);
+        }
+    }
+
+    /// Start a new deadline, even if the state is in the same phase as before.
+    pub fn restart_for(&self, state: &LoveLetterState) {
+        self.restart(TimedPhase::of(state));
+    }
+
+    fn restart(&self, phase: Option<TimedPhase>) {
+        let timeout = match phase {
+            Some(TimedPhase::Turn) => self.turn_timeout,
+            Some(TimedPhase::ReadyUp) => self.ready_up_timeout,
+            None => None,
+        };
+
+        self.phase.set(phase);
+        self.deadline.set(timeout.map(|timeout| SystemTime::now() + timeout));
+    }
+
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        match self.deadline.get() {
+            Some(deadline) => deadline <= now,
+            None => false,
+        }
+    }
+
+    /// Milliseconds since the Unix epoch, or 0 if there's no deadline.
+    pub fn deadline_epoch_millis(&self) -> u64 {
+        self.deadline.get()
+            .and_then(|deadline| deadline.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |since_epoch| since_epoch.as_millis() as u64)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::LoveLetterInstanceManager;
+use crate::events::{LoveLetterEvent, LoveLetterEventType};
+use backend_framework::channel::OverflowPolicy;
+use backend_framework::common_types::ClientInfo;
+use backend_framework::game_instance_manager::GameInstanceManager;
+use backend_framework::streaming::{self, StreamReceiver};
+use backend_framework::wire_api::proto_frj_ngn::ProtoLoveLetterDataOut;
+
+/// Player IDs "p1" to "pN".
+pub(crate) fn player_ids(num_players: usize) -> Vec<String> {
+    (1..=num_players).map(|i| format!("p{}", i)).collect()
+}
+
+pub(crate) fn client_info(player_id: &str) -> ClientInfo {
+    ClientInfo {
+        player_id: player_id.to_string(),
+        game_id: "game".to_string(),
+    }
+}
+
+pub(crate) fn event(player_id: &str, payload: LoveLetterEventType) -> LoveLetterEvent {
+    LoveLetterEvent {
+        client_info: client_info(player_id),
+        payload,
+    }
+}
+
+pub(crate) fn open_stream(game: &mut LoveLetterInstanceManager, player_id: &str) -> StreamReceiver<ProtoLoveLetterDataOut> {
+    let (stream_out, stream_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+    game.handle_event(event(player_id, LoveLetterEventType::RegisterDataStream(stream_out)));
+    stream_rcv
+}
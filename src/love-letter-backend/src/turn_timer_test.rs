@@ -0,0 +1,124 @@
+mod turn_timer_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::LoveLetterEventType;
+    use crate::options::{DeckVariant, LoveLetterOptions};
+    use crate::replay;
+    use crate::state_machine::LoveLetterState;
+    use crate::test_util::player_ids;
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming;
+    use backend_framework::wire_api::proto_frj_ngn::proto_love_letter_data_out::ProtoLvLeOut;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use tokio::stream::StreamExt;
+
+    fn timed_options() -> LoveLetterOptions {
+        LoveLetterOptions {
+            turn_timeout_secs: Some(30),
+            ready_up_timeout_secs: Some(10),
+            ..LoveLetterOptions::default()
+        }
+    }
+
+    fn after(secs: u64) -> SystemTime {
+        SystemTime::now() + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn no_deadline_by_default() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), LoveLetterOptions::default(), 42);
+
+        assert!(!game.handle_expired_deadlines(after(60 * 60)));
+        assert!(matches!(game.state(), LoveLetterState::PlayPending(_)));
+        assert!(game.game_log().events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn deadline_is_in_game_state() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), timed_options(), 42);
+        let (stream_out, mut stream_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+        game.handle_player_event("p1".to_string(), LoveLetterEventType::RegisterDataStream(stream_out));
+
+        let deadline = match stream_rcv.next().await.unwrap().unwrap().proto_lv_le_out {
+            Some(ProtoLvLeOut::GameState(game_state)) => UNIX_EPOCH + Duration::from_millis(game_state.deadline),
+            other => panic!("Expected game state, got {:?}", other),
+        };
+        assert!(deadline > after(25));
+        assert!(deadline <= after(30));
+    }
+
+    #[test]
+    fn timed_out_turn_is_played_then_players_are_readied_up() {
+        let mut game = LoveLetterInstanceManager::from_seed(player_ids(3), timed_options(), 42);
+        let first_player_id = match game.state() {
+            LoveLetterState::PlayPending(round_data) => round_data.players.current_turn_player_id().clone(),
+            _ => panic!("Game should start with a play pending"),
+        };
+
+        assert!(!game.handle_expired_deadlines(after(20)));
+        assert!(matches!(game.state(), LoveLetterState::PlayPending(_)));
+
+        // Ready up has a shorter time limit than the turn.
+        assert!(game.handle_expired_deadlines(after(31)));
+        assert!(matches!(game.state(), LoveLetterState::TurnIntermission(_, _)));
+        assert!(game.game_log().events.iter().all(|logged_event| logged_event.player_id == first_player_id));
+        assert!(!game.handle_expired_deadlines(after(9)));
+
+        assert!(game.handle_expired_deadlines(after(11)));
+        match game.state() {
+            LoveLetterState::PlayPending(round_data) => assert_ne!(&first_player_id, round_data.players.current_turn_player_id()),
+            _ => panic!("Next turn should be pending"),
+        }
+    }
+
+    /// Players only send the default events, which must all be legal, until the game is over.
+    /// The logged game replays to the same game, since the default events don't use the game's
+    /// random numbers.
+    #[test]
+    fn timed_out_players_finish_game_with_legal_plays() {
+        for &(deck_variant, num_players) in &[(DeckVariant::Classic, 2), (DeckVariant::Revised2019, 6), (DeckVariant::Premium, 8)] {
+            let options = LoveLetterOptions {
+                win_threshold: Some(2),
+                deck_variant,
+                ..timed_options()
+            };
+            let mut game = LoveLetterInstanceManager::from_seed(player_ids(num_players), options, 42);
+            let mut rng = PrngRand::new(7);
+
+            while !game.is_game_complete() {
+                let default_events = game.state_machine.default_events(game.state(), &mut rng);
+                assert!(!default_events.is_empty());
+
+                for (player_id, payload) in default_events {
+                    let debug_payload = format!("{:?}", payload);
+                    let num_logged_events = game.game_log().events.len();
+                    game.handle_player_event(player_id, payload);
+                    assert_eq!(num_logged_events + 1, game.game_log().events.len(), "{:?} {} was rejected", deck_variant, debug_payload);
+                }
+            }
+
+            let replayed_game = replay::replay(game.game_log(), game.game_log().events.len()).unwrap();
+            assert_eq!(game.wins_per_player(), replayed_game.wins_per_player());
+        }
+    }
+
+    /// Every auto-play is picked with the game's seeded random numbers, so the same seed always
+    /// times out the same way.
+    #[test]
+    fn same_seed_makes_same_auto_plays() {
+        let play_out_clock = || {
+            let options = LoveLetterOptions {
+                win_threshold: Some(2),
+                ..timed_options()
+            };
+            let mut game = LoveLetterInstanceManager::from_seed(player_ids(4), options, 42);
+            while !game.is_game_complete() {
+                assert!(game.handle_expired_deadlines(after(60 * 60)));
+            }
+            game.game_log().events.clone()
+        };
+
+        assert_eq!(play_out_clock(), play_out_clock());
+    }
+}
@@ -1,11 +1,18 @@
 use crate::events::{Card, PlayCardSource};
-use crate::types::{StagedPlay, CommittedPlayOutcome, CommittedPlay};
-use backend_framework::wire_api::proto_frj_ngn::{ProtoLvLeCard, ProtoLvLeCommittedPlay, ProtoLvLeCardSelection, proto_lv_le_card_selection};
+use crate::options::DeckVariant;
+use crate::types::{StagedPlay, CommittedPlayOutcome, CommittedPlay, RoundEndReason, IllegalPlay};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoLvLeCard, ProtoLvLeCommittedPlay, ProtoLvLeCardSelection, ProtoLvLeIllegalPlay, proto_lv_le_card_selection};
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_illegal_play::ProtoLvLeIllegalPlayReason;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_game_state::proto_lv_le_result_state::ProtoLvLeRoundEndReason;
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_options::ProtoLvLeDeckVariant;
 use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_play_card_req::ProtoLvLeCardSource;
-use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_card_outcome::{ProtoGuardOutcome, ProtoBaronOutcome, ProtoPrinceOutcome, ProtoPriestOutcome};
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_card_outcome::{ProtoGuardOutcome, ProtoBaronOutcome, ProtoPrinceOutcome, ProtoPriestOutcome, ProtoCardinalOutcome, ProtoBaronessOutcome, ProtoBishopOutcome, ProtoNoEffectOutcome};
 use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_card_outcome::proto_baron_outcome::ProtoBaronLoserInfo;
-use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_card_selection::{ProtoGuardSelection, ProtoPriestSelection, ProtoBaronSelection, ProtoPrinceSelection, ProtoKingSelection};
+use backend_framework::wire_api::proto_frj_ngn::proto_lv_le_card_selection::{ProtoGuardSelection, ProtoPriestSelection, ProtoBaronSelection, ProtoPrinceSelection, ProtoKingSelection, ProtoChancellorSelection, ProtoJesterSelection, ProtoCardinalSelection, ProtoBaronessSelection, ProtoSycophantSelection, ProtoDowagerQueenSelection, ProtoBishopSelection};
+use prost::Message;
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use tonic::{Code, Status};
 
 impl TryFrom<ProtoLvLeCardSource> for PlayCardSource {
     type Error = ();
@@ -33,6 +40,17 @@ impl TryFrom<ProtoLvLeCard> for Card {
             ProtoLvLeCard::King => Ok(Card::King),
             ProtoLvLeCard::Countess => Ok(Card::Countess),
             ProtoLvLeCard::Princess => Ok(Card::Princess),
+            ProtoLvLeCard::Spy => Ok(Card::Spy),
+            ProtoLvLeCard::Chancellor => Ok(Card::Chancellor),
+            ProtoLvLeCard::Jester => Ok(Card::Jester),
+            ProtoLvLeCard::Assassin => Ok(Card::Assassin),
+            ProtoLvLeCard::Cardinal => Ok(Card::Cardinal),
+            ProtoLvLeCard::Baroness => Ok(Card::Baroness),
+            ProtoLvLeCard::Sycophant => Ok(Card::Sycophant),
+            ProtoLvLeCard::Count => Ok(Card::Count),
+            ProtoLvLeCard::Constable => Ok(Card::Constable),
+            ProtoLvLeCard::DowagerQueen => Ok(Card::DowagerQueen),
+            ProtoLvLeCard::Bishop => Ok(Card::Bishop),
         }
     }
 }
@@ -48,20 +66,98 @@ impl From<Card> for ProtoLvLeCard {
             Card::King => ProtoLvLeCard::King,
             Card::Countess => ProtoLvLeCard::Countess,
             Card::Princess => ProtoLvLeCard::Princess,
+            Card::Spy => ProtoLvLeCard::Spy,
+            Card::Chancellor => ProtoLvLeCard::Chancellor,
+            Card::Jester => ProtoLvLeCard::Jester,
+            Card::Assassin => ProtoLvLeCard::Assassin,
+            Card::Cardinal => ProtoLvLeCard::Cardinal,
+            Card::Baroness => ProtoLvLeCard::Baroness,
+            Card::Sycophant => ProtoLvLeCard::Sycophant,
+            Card::Count => ProtoLvLeCard::Count,
+            Card::Constable => ProtoLvLeCard::Constable,
+            Card::DowagerQueen => ProtoLvLeCard::DowagerQueen,
+            Card::Bishop => ProtoLvLeCard::Bishop,
+        }
+    }
+}
+
+impl From<ProtoLvLeDeckVariant> for DeckVariant {
+    fn from(proto: ProtoLvLeDeckVariant) -> Self {
+        match proto {
+            ProtoLvLeDeckVariant::UnspecifiedDeckVariant => DeckVariant::default(),
+            ProtoLvLeDeckVariant::Classic => DeckVariant::Classic,
+            ProtoLvLeDeckVariant::Revised2019 => DeckVariant::Revised2019,
+            ProtoLvLeDeckVariant::Premium => DeckVariant::Premium,
+        }
+    }
+}
+
+impl From<DeckVariant> for ProtoLvLeDeckVariant {
+    fn from(deck_variant: DeckVariant) -> Self {
+        match deck_variant {
+            DeckVariant::Classic => ProtoLvLeDeckVariant::Classic,
+            DeckVariant::Revised2019 => ProtoLvLeDeckVariant::Revised2019,
+            DeckVariant::Premium => ProtoLvLeDeckVariant::Premium,
+        }
+    }
+}
+
+impl From<IllegalPlay> for ProtoLvLeIllegalPlayReason {
+    fn from(illegal_play: IllegalPlay) -> Self {
+        match illegal_play {
+            IllegalPlay::NotYourTurn => ProtoLvLeIllegalPlayReason::NotYourTurn,
+            IllegalPlay::CountessMustBePlayed => ProtoLvLeIllegalPlayReason::CountessMustBePlayed,
+            IllegalPlay::CardHasNoTargetPlayer => ProtoLvLeIllegalPlayReason::CardHasNoTargetPlayer,
+            IllegalPlay::TargetNotInRound => ProtoLvLeIllegalPlayReason::TargetNotInRound,
+            IllegalPlay::TargetIsSelf => ProtoLvLeIllegalPlayReason::TargetIsSelf,
+            IllegalPlay::TargetHasHandmaid => ProtoLvLeIllegalPlayReason::TargetHasHandmaid,
+            IllegalPlay::TargetNotSycophantChoice => ProtoLvLeIllegalPlayReason::TargetNotSycophantChoice,
+            IllegalPlay::TooManyTargets => ProtoLvLeIllegalPlayReason::TooManyTargets,
+            IllegalPlay::CardHasNoTargetCard => ProtoLvLeIllegalPlayReason::CardHasNoTargetCard,
+            IllegalPlay::CantGuessGuard => ProtoLvLeIllegalPlayReason::CantGuessGuard,
+            IllegalPlay::CardNotInDeck => ProtoLvLeIllegalPlayReason::CardNotInDeck,
+            IllegalPlay::CardNotAvailable => ProtoLvLeIllegalPlayReason::CardNotAvailable,
+            IllegalPlay::MissingSelection => ProtoLvLeIllegalPlayReason::MissingSelection,
+        }
+    }
+}
+
+impl IllegalPlay {
+    /// `FAILED_PRECONDITION`, with a `ProtoLvLeIllegalPlay` as the status details.
+    pub fn into_status(self, message: impl Into<String>) -> Status {
+        let details = ProtoLvLeIllegalPlay {
+            reason: ProtoLvLeIllegalPlayReason::from(self) as i32,
+        };
+        let mut encoded_details = Vec::with_capacity(details.encoded_len());
+        details.encode(&mut encoded_details).expect("Vec has enough capacity");
+
+        Status::with_details(Code::FailedPrecondition, message, encoded_details.into())
+    }
+}
+
+impl From<RoundEndReason> for ProtoLvLeRoundEndReason {
+    fn from(end_reason: RoundEndReason) -> Self {
+        match end_reason {
+            RoundEndReason::LastPlayerStanding => ProtoLvLeRoundEndReason::LastPlayerStanding,
+            RoundEndReason::DeckExhausted => ProtoLvLeRoundEndReason::DeckExhausted,
+            RoundEndReason::Tiebreak => ProtoLvLeRoundEndReason::Tiebreak,
+            RoundEndReason::WinThresholdReached => ProtoLvLeRoundEndReason::WinThresholdReached,
         }
     }
 }
 
 impl CommittedPlay {
-    pub fn into_proto(self, requesting_player_id: &String) -> ProtoLvLeCommittedPlay {
+    /// `requesting_player_id` is None for spectators, who only see public information.
+    pub fn into_proto(self, requesting_player_id: Option<&String>) -> ProtoLvLeCommittedPlay {
         match self.outcome {
-            CommittedPlayOutcome::Guard { target_player_id, guessed_card, correct } => {
+            CommittedPlayOutcome::Guard { target_player_id, guessed_card, correct, assassinated } => {
                 let selection = ProtoGuardSelection {
                     opt_player_id: target_player_id,
                     opt_card: ProtoLvLeCard::from(guessed_card) as i32,
                 };
                 let outcome = ProtoGuardOutcome {
-                    correct
+                    correct,
+                    assassinated,
                 };
 
                 ProtoLvLeCommittedPlay::from((selection, outcome))
@@ -71,7 +167,7 @@ impl CommittedPlay {
                     opt_player_id: target_player_id
                 };
 
-                let opt_opponent_card = if requesting_player_id == &self.committer_player_id {
+                let opt_opponent_card = if requesting_player_id == Some(&self.committer_player_id) {
                     ProtoLvLeCard::from(opponent_card) as i32
                 } else {
                     0
@@ -126,7 +222,112 @@ impl CommittedPlay {
             },
             CommittedPlayOutcome::Princess => {
                 ProtoLvLeCommittedPlay::empty()
-            }
+            },
+            CommittedPlayOutcome::Spy => {
+                ProtoLvLeCommittedPlay::empty()
+            },
+            CommittedPlayOutcome::Chancellor => {
+                // Which cards were kept and returned is secret.
+                ProtoLvLeCommittedPlay::empty()
+            },
+            CommittedPlayOutcome::Jester { target_player_id } => {
+                let selection = ProtoJesterSelection {
+                    opt_player_id: target_player_id
+                };
+
+                ProtoLvLeCommittedPlay::from(selection)
+            },
+            CommittedPlayOutcome::Assassin => {
+                ProtoLvLeCommittedPlay::empty()
+            },
+            CommittedPlayOutcome::Cardinal { target_player_id, second_target_player_id, viewed_card } => {
+                let selection = ProtoCardinalSelection {
+                    opt_player_id: target_player_id,
+                    opt_second_player_id: second_target_player_id,
+                };
+
+                let opt_viewed_card = if requesting_player_id == Some(&self.committer_player_id) {
+                    ProtoLvLeCard::from(viewed_card) as i32
+                } else {
+                    0
+                };
+
+                let outcome = ProtoCardinalOutcome {
+                    opt_viewed_card,
+                };
+
+                ProtoLvLeCommittedPlay::from((selection, outcome))
+            },
+            CommittedPlayOutcome::Baroness { viewed_card_by_player_id } => {
+                let mut player_ids = viewed_card_by_player_id.iter().map(|(player_id, _)| player_id.clone());
+                let selection = ProtoBaronessSelection {
+                    opt_player_id: player_ids.next().unwrap_or_default(),
+                    opt_second_player_id: player_ids.next().unwrap_or_default(),
+                };
+
+                let opt_viewed_cards = if requesting_player_id == Some(&self.committer_player_id) {
+                    viewed_card_by_player_id
+                        .into_iter()
+                        .map(|(player_id, card)| (player_id, ProtoLvLeCard::from(card) as i32))
+                        .collect()
+                } else {
+                    HashMap::new()
+                };
+
+                let outcome = ProtoBaronessOutcome {
+                    opt_viewed_cards,
+                };
+
+                ProtoLvLeCommittedPlay::from((selection, outcome))
+            },
+            CommittedPlayOutcome::Sycophant { target_player_id } => {
+                let selection = ProtoSycophantSelection {
+                    opt_player_id: target_player_id
+                };
+
+                ProtoLvLeCommittedPlay::from(selection)
+            },
+            CommittedPlayOutcome::Count => {
+                ProtoLvLeCommittedPlay::empty()
+            },
+            CommittedPlayOutcome::Constable => {
+                ProtoLvLeCommittedPlay::empty()
+            },
+            CommittedPlayOutcome::DowagerQueen {
+                target_player_id,
+                eliminated_player_id_and_card,
+            } => {
+                let selection = ProtoDowagerQueenSelection {
+                    opt_player_id: target_player_id
+                };
+                let opt_loser_info = eliminated_player_id_and_card
+                    .map(|(losing_player_id, card)| ProtoBaronLoserInfo {
+                        losing_player_id,
+                        losing_player_card: ProtoLvLeCard::from(card) as i32,
+                    });
+                let outcome = ProtoBaronOutcome {
+                    opt_loser_info,
+                };
+
+                ProtoLvLeCommittedPlay::from((selection, outcome))
+            },
+            CommittedPlayOutcome::Bishop { target_player_id, guessed_card, correct, discarded_card } => {
+                let selection = ProtoBishopSelection {
+                    opt_player_id: target_player_id,
+                    opt_card: ProtoLvLeCard::from(guessed_card) as i32,
+                };
+                let outcome = ProtoBishopOutcome {
+                    correct,
+                    opt_discarded_card: discarded_card.map(|c| ProtoLvLeCard::from(c) as i32).unwrap_or(0),
+                };
+
+                ProtoLvLeCommittedPlay::from((selection, outcome))
+            },
+            CommittedPlayOutcome::NoEffect { played_card } => {
+                ProtoLvLeCommittedPlay::from(ProtoNoEffectOutcome {
+                    played_card: ProtoLvLeCard::from(played_card) as i32,
+                })
+            },
         }
     }
 }
@@ -134,11 +335,12 @@ impl CommittedPlay {
 impl From<StagedPlay> for ProtoLvLeCardSelection {
     fn from(staged_play: StagedPlay) -> Self {
         let opt = |s: Option<String>| s.unwrap_or("".to_string());
+        let opt_card = |c: Option<Card>| c.map(|c| ProtoLvLeCard::from(c) as i32).unwrap_or(0);
 
         let proto_selection = match staged_play.played_card {
             Card::Guard => Some(proto_lv_le_card_selection::Inner::Guard(ProtoGuardSelection {
                 opt_player_id: opt(staged_play.target_player),
-                opt_card: staged_play.target_card.map(|c| ProtoLvLeCard::from(c) as i32).unwrap_or(0),
+                opt_card: opt_card(staged_play.target_card),
             })),
             Card::Priest => Some(proto_lv_le_card_selection::Inner::Priest(ProtoPriestSelection {
                 opt_player_id: opt(staged_play.target_player),
@@ -152,6 +354,31 @@ impl From<StagedPlay> for ProtoLvLeCardSelection {
             Card::King => Some(proto_lv_le_card_selection::Inner::King(ProtoKingSelection {
                 opt_player_id: opt(staged_play.target_player),
             })),
+            Card::Chancellor => Some(proto_lv_le_card_selection::Inner::Chancellor(ProtoChancellorSelection {
+                opt_drawn_cards: staged_play.drawn_cards.into_iter().map(|c| ProtoLvLeCard::from(c) as i32).collect(),
+                opt_kept_card: opt_card(staged_play.target_card),
+            })),
+            Card::Jester => Some(proto_lv_le_card_selection::Inner::Jester(ProtoJesterSelection {
+                opt_player_id: opt(staged_play.target_player),
+            })),
+            Card::Cardinal => Some(proto_lv_le_card_selection::Inner::Cardinal(ProtoCardinalSelection {
+                opt_player_id: opt(staged_play.target_player),
+                opt_second_player_id: opt(staged_play.second_target_player),
+            })),
+            Card::Baroness => Some(proto_lv_le_card_selection::Inner::Baroness(ProtoBaronessSelection {
+                opt_player_id: opt(staged_play.target_player),
+                opt_second_player_id: opt(staged_play.second_target_player),
+            })),
+            Card::Sycophant => Some(proto_lv_le_card_selection::Inner::Sycophant(ProtoSycophantSelection {
+                opt_player_id: opt(staged_play.target_player),
+            })),
+            Card::DowagerQueen => Some(proto_lv_le_card_selection::Inner::DowagerQueen(ProtoDowagerQueenSelection {
+                opt_player_id: opt(staged_play.target_player),
+            })),
+            Card::Bishop => Some(proto_lv_le_card_selection::Inner::Bishop(ProtoBishopSelection {
+                opt_player_id: opt(staged_play.target_player),
+                opt_card: opt_card(staged_play.target_card),
+            })),
             _ => None
         };
 
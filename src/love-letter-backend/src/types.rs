@@ -1,20 +1,51 @@
 use crate::deck;
 use crate::events::Card;
+use crate::options::{DeckVariant, LoveLetterOptions};
+use backend_framework::prng::PrngRand;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
+/// Cards which are set aside face up in 2 player rounds, in addition to the burn card.
+const NUM_FACE_UP_CARDS_2_PLAYERS: usize = 3;
+
 // ---------------- struct defs --------------------
 
+#[derive(Serialize, Deserialize)]
 pub struct GameData {
     pub player_id_turn_order: Vec<String>,
     pub wins_per_player: HashMap<String, u8>,
+    /// The game is over once a player has this many round wins.
+    pub win_threshold: u8,
+    pub deck_variant: DeckVariant,
+    /// Source of all randomness in the game, so a game can be replayed from its seed.
+    pub rng: PrngRand,
+    /// See `LoveLetterOptions`.
+    #[serde(default)]
+    pub turn_timeout_secs: Option<u32>,
+    /// See `LoveLetterOptions`.
+    #[serde(default)]
+    pub ready_up_timeout_secs: Option<u32>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct RoundData {
     pub deck: Vec<Card>,
+    /// Set aside face down at the start of the round. Only drawn if a card makes a player discard
+    /// and draw when the deck is empty.
+    pub burn_card: Option<Card>,
+    /// Set aside face up at the start of 2 player rounds.
+    pub face_up_cards: Vec<Card>,
     pub players: Players,
     pub play_history: Vec<Card>,
     pub most_recent_play_details: Option<CommittedPlay>,
-    pub handmaid_immunity_player_ids: HashSet<String>
+    pub handmaid_immunity_player_ids: HashSet<String>,
+    /// Each player's discarded cards, in order. Players stay in the map after they're out.
+    pub discards_by_player_id: HashMap<String, Vec<Card>>,
+    /// Set by the Sycophant. The next played card which targets players must target this one.
+    pub sycophant_player_id: Option<String>,
+    /// Set by the Jester. Jester player => the player they gain a token from, if that player
+    /// wins the round.
+    pub jester_target_by_player_id: HashMap<String, String>,
 }
 
 /// Struct to track which players are still in game, which card they have, and turn order.
@@ -24,6 +55,7 @@ pub struct RoundData {
 /// possible invalid states to this struct, and (2) to continue on with development.
 ///
 /// TODO:1.5 Use a linked hash map or just a Vec<(String, Card)>.
+#[derive(Serialize, Deserialize)]
 pub struct Players {
     cards: HashMap<String, Card>,
     // This will be in the same cyclical order as GameData's order, but
@@ -32,25 +64,31 @@ pub struct Players {
     turn_cursor: usize,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct StagedPlay {
     pub played_card: Card,
     pub target_player: Option<String>,
+    /// Only for cards which target 2 players (Cardinal, Baroness).
+    pub second_target_player: Option<String>,
     pub target_card: Option<Card>,
+    /// Only for the Chancellor. Player-specific.
+    pub drawn_cards: Vec<Card>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CommittedPlay {
     pub committer_player_id: String,
     pub outcome: CommittedPlayOutcome,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum CommittedPlayOutcome {
     Guard {
         target_player_id: String,
         guessed_card: Card,
         correct: bool,
+        /// The target had an Assassin, so the Guard player is out instead.
+        assassinated: bool,
     },
     Priest {
         target_player_id: String,
@@ -73,15 +111,102 @@ pub enum CommittedPlayOutcome {
     },
     Countess,
     Princess,
+    Spy,
+    Chancellor,
+    Jester {
+        target_player_id: String,
+    },
+    Assassin,
+    Cardinal {
+        target_player_id: String,
+        second_target_player_id: String,
+        // Player-specific: the first target's new card.
+        viewed_card: Card,
+    },
+    Baroness {
+        // Player-specific:
+        viewed_card_by_player_id: Vec<(String, Card)>,
+    },
+    Sycophant {
+        target_player_id: String,
+    },
+    Count,
+    Constable,
+    DowagerQueen {
+        target_player_id: String,
+        eliminated_player_id_and_card: Option<(String, Card)>,
+    },
+    Bishop {
+        target_player_id: String,
+        guessed_card: Card,
+        correct: bool,
+        /// Set if the guess was correct.
+        discarded_card: Option<Card>,
+    },
+    /// The card targets players, but every player it could target has Handmaid protection.
+    NoEffect {
+        played_card: Card,
+    },
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct RoundResult {
     /// Sparse map, missing value => player eliminated
     pub final_card_by_player_id: HashMap<String, Card>,
+    /// Players who won the round. More than one if the tiebreak is tied.
+    #[serde(default)]
+    pub winner_player_ids: Vec<String>,
+    #[serde(default)]
+    pub end_reason: RoundEndReason,
+    /// Total value of each remaining player's discards, used to break ties.
+    #[serde(default)]
+    pub discard_total_by_player_id: HashMap<String, u32>,
+    /// Never seen during the round, it's only revealed to the host's god view.
+    #[serde(default)]
+    pub burn_card: Option<Card>,
+    /// Never seen during the round, it's only revealed to the host's god view.
+    #[serde(default)]
+    pub undrawn_cards: Vec<Card>,
 }
 
-#[derive(Clone)]
+/// Snapshots from before the end reason was recorded default to `DeckExhausted`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundEndReason {
+    /// Everyone else was knocked out.
+    LastPlayerStanding,
+    /// The deck ran out, and the highest card won.
+    #[default]
+    DeckExhausted,
+    /// The deck ran out, and the highest cards were tied. The highest discard total won.
+    Tiebreak,
+    /// A player reached the win threshold mid-round. Nobody won the round.
+    WinThresholdReached,
+}
+
+/// Why a play breaks the rules. Sent to the player along with the error message, so clients
+/// can react without parsing the message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IllegalPlay {
+    NotYourTurn,
+    /// Holding the Countess with the King or Prince, so the Countess must be played.
+    CountessMustBePlayed,
+    CardHasNoTargetPlayer,
+    TargetNotInRound,
+    TargetIsSelf,
+    TargetHasHandmaid,
+    /// The Sycophant chose who the played card must target.
+    TargetNotSycophantChoice,
+    TooManyTargets,
+    CardHasNoTargetCard,
+    CantGuessGuard,
+    CardNotInDeck,
+    /// The Chancellor's kept card must be in hand, or one of the drawn cards.
+    CardNotAvailable,
+    /// Committed without selecting the played card's targets.
+    MissingSelection,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct UnreadyPlayers {
     player_ids: Vec<String>,
 }
@@ -89,18 +214,72 @@ pub struct UnreadyPlayers {
 // ---------------- impl blocks --------------------
 
 impl GameData {
-    pub fn new(player_ids: Vec<String>) -> Self {
+    pub fn new(player_ids: Vec<String>, options: &LoveLetterOptions, rng_seed: u64) -> Self {
         GameData {
+            win_threshold: options.win_threshold(player_ids.len()),
+            deck_variant: options.deck_variant,
             player_id_turn_order: player_ids,
             wins_per_player: HashMap::new(),
+            rng: PrngRand::new(rng_seed),
+            turn_timeout_secs: options.turn_timeout_secs,
+            ready_up_timeout_secs: options.ready_up_timeout_secs,
         }
     }
+
+    pub fn wins(&self, player_id: &str) -> u8 {
+        self.wins_per_player.get(player_id).copied().unwrap_or(0)
+    }
+
+    /// Give the player a token of affection.
+    pub fn add_win(&mut self, player_id: &str) {
+        *self.wins_per_player.entry(player_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Players who reached the win threshold with the most round wins, in turn order. More than
+    /// one player if the final round was tied. Empty until the game is complete.
+    pub fn winner_player_ids(&self) -> Vec<String> {
+        let most_wins = self.player_id_turn_order
+            .iter()
+            .map(|player_id| self.wins(player_id))
+            .max()
+            .unwrap_or(0);
+        if most_wins < self.win_threshold {
+            return Vec::new();
+        }
+
+        self.player_id_turn_order
+            .iter()
+            .filter(|player_id| self.wins(player_id) == most_wins)
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !self.winner_player_ids().is_empty()
+    }
+
+    /// All players, most round wins first. Players with equal wins stay in turn order.
+    pub fn standings(&self) -> Vec<String> {
+        let mut standings = self.player_id_turn_order.clone();
+        standings.sort_by_key(|player_id| std::cmp::Reverse(self.wins(player_id)));
+        standings
+    }
 }
 
 impl RoundData {
-    pub fn new(player_ids: &Vec<String>) -> Self {
-        let mut deck = deck::new_shuffled_deck();
-        let mut turn_cursor = rand::random::<usize>() % player_ids.len();
+    pub fn new(player_ids: &[String], deck_variant: DeckVariant, rng: &mut PrngRand) -> Self {
+        let mut deck = deck::new_shuffled_deck(deck_variant, rng);
+
+        // Set aside cards, so the last card in the deck is a mystery.
+        let burn_card = deck.pop();
+        let mut face_up_cards = Vec::new();
+        if player_ids.len() == 2 {
+            for _ in 0..NUM_FACE_UP_CARDS_2_PLAYERS {
+                face_up_cards.push(deck.pop().expect("deck out of cards before game start"));
+            }
+        }
+
+        let mut turn_cursor = (rng.next() % player_ids.len() as u64) as usize;
         let mut players = Players::with_capacity(player_ids.len());
 
         // Deal 1 card to each player
@@ -118,12 +297,123 @@ impl RoundData {
 
         RoundData {
             deck,
+            burn_card,
+            face_up_cards,
             players,
             play_history,
             most_recent_play_details: None,
             handmaid_immunity_player_ids: HashSet::new(),
+            discards_by_player_id: HashMap::new(),
+            sycophant_player_id: None,
+            jester_target_by_player_id: HashMap::new(),
         }
     }
+
+    /// For cards which make a player discard and draw a new card. When the deck is empty, the
+    /// burn card is drawn instead.
+    pub fn draw_replacement_card(&mut self) -> Card {
+        self.deck.pop()
+            .or_else(|| self.burn_card.take())
+            .expect("Round was incorrectly not ended when the deck was empty")
+    }
+
+    /// Every discarded card goes through here, so it's in the play history and the player's
+    /// discards.
+    pub fn discard(&mut self, player_id: &str, card: Card) {
+        self.play_history.push(card);
+        self.discards_by_player_id
+            .entry(player_id.to_string())
+            .or_default()
+            .push(card);
+    }
+
+    /// Players who are still in the round, and not protected by a Handmaid.
+    pub fn targetable_player_ids(&self) -> Vec<&String> {
+        self.players
+            .remaining_player_ids()
+            .iter()
+            .filter(|player_id| !self.handmaid_immunity_player_ids.contains(*player_id))
+            .collect()
+    }
+
+    /// Ends the round, which is won by the player with the highest card. Each discarded Count
+    /// adds 1 to the player's card, and the Bishop loses to the Princess. Ties go to the player
+    /// with the highest total value of discards. If that's tied too, all tied players win.
+    pub fn into_round_result(self, deck_variant: DeckVariant) -> RoundResult {
+        let RoundData { deck, burn_card, players, discards_by_player_id, .. } = self;
+
+        let discard_total_by_player_id: HashMap<String, u32> = players
+            .remaining_player_ids()
+            .iter()
+            .map(|player_id| {
+                let total = discards_by_player_id
+                    .get(player_id)
+                    .map_or(0, |discards| discards.iter().map(|card| card.value(deck_variant) as u32).sum());
+                (player_id.clone(), total)
+            })
+            .collect();
+        let num_discarded = |player_id: &str, card: Card| -> usize {
+            discards_by_player_id
+                .get(player_id)
+                .map_or(0, |discards| discards.iter().filter(|c| **c == card).count())
+        };
+
+        let final_card_by_player_id = players.into_player_card_map();
+        if final_card_by_player_id.is_empty() {
+            panic!("LoveLetter round ended with no players remaining");
+        }
+
+        let (winner_player_ids, end_reason) = if final_card_by_player_id.len() == 1 {
+            let winner_player_ids = final_card_by_player_id.keys().cloned().collect();
+            (winner_player_ids, RoundEndReason::LastPlayerStanding)
+        } else {
+            let princess_remains = final_card_by_player_id.values().any(|card| *card == Card::Princess);
+            let contender_player_ids = final_card_by_player_id
+                .iter()
+                .filter(|(_, card)| !(princess_remains && **card == Card::Bishop))
+                .map(|(player_id, _)| player_id);
+            let highest_card_player_ids = highest_by(contender_player_ids, |player_id| {
+                final_card_by_player_id[player_id].value(deck_variant) as usize + num_discarded(player_id, Card::Count)
+            });
+
+            if highest_card_player_ids.len() == 1 {
+                (highest_card_player_ids, RoundEndReason::DeckExhausted)
+            } else {
+                let winner_player_ids = highest_by(highest_card_player_ids.iter(), |player_id| {
+                    discard_total_by_player_id[player_id] as usize
+                });
+                (winner_player_ids, RoundEndReason::Tiebreak)
+            }
+        };
+
+        RoundResult {
+            final_card_by_player_id,
+            winner_player_ids,
+            end_reason,
+            discard_total_by_player_id,
+            burn_card,
+            undrawn_cards: deck,
+        }
+    }
+
+    /// Ends the round without a winner, because a player reached the win threshold mid-round.
+    pub fn into_interrupted_round_result(self) -> RoundResult {
+        RoundResult {
+            final_card_by_player_id: self.players.into_player_card_map(),
+            winner_player_ids: Vec::new(),
+            end_reason: RoundEndReason::WinThresholdReached,
+            discard_total_by_player_id: HashMap::new(),
+            burn_card: self.burn_card,
+            undrawn_cards: self.deck,
+        }
+    }
+
+    pub fn has_discarded(&self, player_id: &str, card: Card) -> bool {
+        self.discards_by_player_id
+            .get(player_id)
+            .into_iter()
+            .any(|discards| discards.contains(&card))
+    }
 }
 
 impl Players {
@@ -158,6 +448,13 @@ impl Players {
             .expect("No fricking way. There's a validation for this 4 lines of code above.")
     }
 
+    pub fn swap_cards(&mut self, player_id_1: &str, player_id_2: &str) {
+        let card_1 = *self.cards.get(player_id_1)
+            .expect("Players.swap_cards() can only be called on players who are in the game.");
+        let card_2 = self.replace_card(player_id_2.to_string(), card_1);
+        self.replace_card(player_id_1.to_string(), card_2);
+    }
+
     pub fn remaining_player_ids(&self) -> &Vec<String> {
         &self.turn_order
     }
@@ -195,8 +492,10 @@ impl Players {
             self.turn_cursor += 1;
         }
         // If we didn't increment turn, it's possible cursor is now out of bounds. So we
-        // call this no matter what.
-        self.turn_cursor %= self.turn_order.len();
+        // call this no matter what. Unless nobody is left, then there's no next turn.
+        if !self.turn_order.is_empty() {
+            self.turn_cursor %= self.turn_order.len();
+        }
 
         let removed_card = self.cards
             .remove(player_id)
@@ -207,7 +506,7 @@ impl Players {
     }
 
     fn validate_invariants(&mut self) {
-        assert!(self.turn_cursor < self.turn_order.len(), "turn_cursor out of bounds");
+        assert!(self.turn_order.is_empty() || self.turn_cursor < self.turn_order.len(), "turn_cursor out of bounds");
         assert_eq!(self.cards.len(), self.turn_order.len(), "num players != num cards");
         for player_id in self.turn_order.iter() {
             assert!(self.cards.contains_key(player_id), "player in game, but with no card");
@@ -224,7 +523,9 @@ impl StagedPlay {
         StagedPlay {
             played_card,
             target_player: None,
-            target_card: None
+            second_target_player: None,
+            target_card: None,
+            drawn_cards: Vec::new(),
         }
     }
 
@@ -232,16 +533,33 @@ impl StagedPlay {
         self.target_player.replace(player_id);
     }
 
-    pub fn set_target_card(&mut self, card: Card) {
-        self.target_card.replace(card);
+    /// For cards which target 2 players. Selecting an already selected player un-selects them.
+    /// Returns false if 2 other players are already selected.
+    pub fn toggle_target_player(&mut self, player_id: String) -> bool {
+        if self.target_player.as_ref() == Some(&player_id) {
+            self.target_player = self.second_target_player.take();
+        } else if self.second_target_player.as_ref() == Some(&player_id) {
+            self.second_target_player = None;
+        } else if self.target_player.is_none() {
+            self.target_player = Some(player_id);
+        } else if self.second_target_player.is_none() {
+            self.second_target_player = Some(player_id);
+        } else {
+            return false;
+        }
+
+        true
     }
-}
 
-impl RoundResult {
-    pub fn new(final_card_by_player_id: HashMap<String, Card>) -> Self {
-        RoundResult {
-            final_card_by_player_id,
-        }
+    pub fn target_player_ids(&self) -> Vec<&String> {
+        self.target_player
+            .iter()
+            .chain(self.second_target_player.iter())
+            .collect()
+    }
+
+    pub fn set_target_card(&mut self, card: Card) {
+        self.target_card.replace(card);
     }
 }
 
@@ -262,7 +580,30 @@ impl UnreadyPlayers {
         self.player_ids.is_empty()
     }
 
+    pub fn player_ids(&self) -> &Vec<String> {
+        &self.player_ids
+    }
+
     pub fn into_inner(self) -> Vec<String> {
         self.player_ids
     }
-}
\ No newline at end of file
+}
+
+/// All of the players with the highest score, sorted so the result doesn't depend on hash map
+/// iteration order.
+fn highest_by<'a>(player_ids: impl Iterator<Item = &'a String>, score: impl Fn(&str) -> usize) -> Vec<String> {
+    let mut highest_player_ids: Vec<String> = Vec::new();
+    let mut high_score = 0;
+    for player_id in player_ids {
+        let player_score = score(player_id);
+        if player_score > high_score || highest_player_ids.is_empty() {
+            highest_player_ids = vec![player_id.clone()];
+            high_score = player_score;
+        } else if player_score == high_score {
+            highest_player_ids.push(player_id.clone());
+        }
+    }
+
+    highest_player_ids.sort();
+    highest_player_ids
+}
@@ -112,4 +112,331 @@ mod players_tests {
         assert_eq!(Card::Guard, players.eliminate_and_increment_turn("p1"));
         assert_eq!(Card::Baron, players.eliminate_and_increment_turn("p3"));
     }
+
+    #[test]
+    fn swap_cards() {
+        let mut players = simple_setup();
+        players.swap_cards("p1", "p3");
+
+        assert_eq!(Some(Card::Baron), players.get_card(&"p1".to_string()));
+        assert_eq!(Some(Card::Priest), players.get_card(&"p2".to_string()));
+        assert_eq!(Some(Card::Guard), players.get_card(&"p3".to_string()));
+    }
+}
+
+mod snapshot_tests {
+    use crate::LoveLetterInstanceManager;
+    use crate::events::Card;
+    use crate::options::LoveLetterOptions;
+    use crate::types::Players;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::prng::PrngRand;
+
+    #[test]
+    fn players_round_trip_keeps_turn_order() {
+        let mut players = Players::with_capacity(3);
+        players.insert_at_tail("p1".to_string(), Card::Guard);
+        players.insert_at_tail("p2".to_string(), Card::Priest);
+        players.insert_at_tail("p3".to_string(), Card::Baron);
+        players.increment_turn();
+
+        let json = serde_json::to_vec(&players).unwrap();
+        let mut restored: Players = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!("p2", restored.current_turn_player_id());
+        assert_eq!(Some(Card::Priest), restored.get_card(&"p2".to_string()));
+        restored.increment_turn();
+        assert_eq!("p3", restored.current_turn_player_id());
+    }
+
+    #[test]
+    fn restored_game_has_same_snapshot() {
+        let player_ids = vec!["p1".to_string(), "p2".to_string(), "p3".to_string()];
+        let game = LoveLetterInstanceManager::create_new_game(player_ids.clone(), LoveLetterOptions::default(), PrngRand::new(42));
+
+        let snapshot = game.snapshot().unwrap();
+        let restored = LoveLetterInstanceManager::restore_game(&snapshot).unwrap();
+
+        assert_eq!(&player_ids, restored.player_ids());
+        // Compare as JSON values, since hash map iteration order isn't stable across instances.
+        let snapshot: serde_json::Value = serde_json::from_slice(&snapshot).unwrap();
+        let restored_snapshot: serde_json::Value = serde_json::from_slice(&restored.snapshot().unwrap()).unwrap();
+        assert_eq!(snapshot, restored_snapshot);
+    }
+
+    #[test]
+    fn restore_rejects_invalid_snapshot() {
+        assert!(LoveLetterInstanceManager::restore_game(b"not a snapshot").is_err());
+    }
+}
+
+mod game_data_tests {
+    use crate::options::LoveLetterOptions;
+    use crate::types::GameData;
+
+    fn game_data_with_wins(wins: &[(&str, u8)], win_threshold: u8) -> GameData {
+        let player_ids = wins.iter().map(|(player_id, _)| player_id.to_string()).collect();
+        let options = LoveLetterOptions {
+            win_threshold: Some(win_threshold),
+            ..LoveLetterOptions::default()
+        };
+        let mut game_data = GameData::new(player_ids, &options, 42);
+        for (player_id, num_wins) in wins {
+            game_data.wins_per_player.insert(player_id.to_string(), *num_wins);
+        }
+
+        game_data
+    }
+
+    #[test]
+    fn official_win_threshold_depends_on_player_count() {
+        let options = LoveLetterOptions::default();
+
+        assert_eq!(7, options.win_threshold(2));
+        assert_eq!(5, options.win_threshold(3));
+        assert_eq!(4, options.win_threshold(4));
+    }
+
+    #[test]
+    fn win_threshold_can_be_overridden() {
+        let options = LoveLetterOptions {
+            win_threshold: Some(2),
+            ..LoveLetterOptions::default()
+        };
+
+        assert_eq!(2, options.win_threshold(2));
+        assert_eq!(2, options.win_threshold(4));
+    }
+
+    #[test]
+    fn no_winner_below_win_threshold() {
+        let game_data = game_data_with_wins(&[("p1", 4), ("p2", 6)], 7);
+
+        assert!(game_data.winner_player_ids().is_empty());
+        assert!(!game_data.is_complete());
+    }
+
+    #[test]
+    fn winner_reached_win_threshold() {
+        let game_data = game_data_with_wins(&[("p1", 4), ("p2", 5), ("p3", 2)], 5);
+
+        assert_eq!(vec!["p2".to_string()], game_data.winner_player_ids());
+        assert!(game_data.is_complete());
+    }
+
+    #[test]
+    fn tied_players_both_win() {
+        let game_data = game_data_with_wins(&[("p1", 4), ("p2", 3), ("p3", 4), ("p4", 1)], 4);
+
+        assert_eq!(vec!["p1".to_string(), "p3".to_string()], game_data.winner_player_ids());
+    }
+
+    #[test]
+    fn standings_sorted_by_wins_then_turn_order() {
+        let game_data = game_data_with_wins(&[("p1", 1), ("p2", 5), ("p3", 1), ("p4", 0)], 5);
+
+        assert_eq!(vec!["p2", "p1", "p3", "p4"], game_data.standings());
+    }
+}
+
+mod deck_variant_tests {
+    use crate::deck;
+    use crate::events::Card;
+    use crate::options::{DeckVariant, LoveLetterOptions};
+    use backend_framework::prng::PrngRand;
+
+    #[test]
+    fn deck_size_per_variant() {
+        let mut rng = PrngRand::new(42);
+
+        assert_eq!(16, deck::new_shuffled_deck(DeckVariant::Classic, &mut rng).len());
+        assert_eq!(21, deck::new_shuffled_deck(DeckVariant::Revised2019, &mut rng).len());
+        assert_eq!(32, deck::new_shuffled_deck(DeckVariant::Premium, &mut rng).len());
+    }
+
+    #[test]
+    fn deck_contains_only_its_editions_cards() {
+        assert!(!deck::contains(DeckVariant::Classic, Card::Spy));
+        assert!(deck::contains(DeckVariant::Revised2019, Card::Chancellor));
+        assert!(!deck::contains(DeckVariant::Revised2019, Card::Bishop));
+        assert!(deck::contains(DeckVariant::Premium, Card::Bishop));
+        assert!(!deck::contains(DeckVariant::Premium, Card::Spy));
+    }
+
+    #[test]
+    fn revised_2019_renumbers_royalty() {
+        assert_eq!(6, Card::King.value(DeckVariant::Classic));
+        assert_eq!(7, Card::King.value(DeckVariant::Revised2019));
+        assert_eq!(8, Card::Countess.value(DeckVariant::Revised2019));
+        assert_eq!(9, Card::Princess.value(DeckVariant::Revised2019));
+        assert_eq!(8, Card::Princess.value(DeckVariant::Premium));
+        assert_eq!(0, Card::Spy.value(DeckVariant::Revised2019));
+        assert_eq!(6, Card::Chancellor.value(DeckVariant::Revised2019));
+    }
+
+    #[test]
+    fn official_win_threshold_per_variant() {
+        let options = |deck_variant| LoveLetterOptions {
+            deck_variant,
+            ..LoveLetterOptions::default()
+        };
+
+        assert_eq!(6, options(DeckVariant::Revised2019).win_threshold(2));
+        assert_eq!(3, options(DeckVariant::Revised2019).win_threshold(6));
+        assert_eq!(7, options(DeckVariant::Premium).win_threshold(2));
+        assert_eq!(3, options(DeckVariant::Premium).win_threshold(8));
+    }
+}
+
+mod staged_play_tests {
+    use crate::events::Card;
+    use crate::types::StagedPlay;
+
+    #[test]
+    fn toggle_target_player_selects_up_to_two() {
+        let mut staged_play = StagedPlay::new(Card::Cardinal);
+
+        assert!(staged_play.toggle_target_player("p1".to_string()));
+        assert!(staged_play.toggle_target_player("p2".to_string()));
+        assert!(!staged_play.toggle_target_player("p3".to_string()));
+        assert_eq!(vec!["p1", "p2"], staged_play.target_player_ids());
+    }
+
+    #[test]
+    fn toggle_target_player_unselects() {
+        let mut staged_play = StagedPlay::new(Card::Baroness);
+        staged_play.toggle_target_player("p1".to_string());
+        staged_play.toggle_target_player("p2".to_string());
+
+        // Second player moves up to first
+        assert!(staged_play.toggle_target_player("p1".to_string()));
+        assert_eq!(Some("p2".to_string()), staged_play.target_player);
+        assert_eq!(None, staged_play.second_target_player);
+
+        assert!(staged_play.toggle_target_player("p2".to_string()));
+        assert!(staged_play.target_player_ids().is_empty());
+    }
+}
+
+mod round_data_tests {
+    use crate::events::Card;
+    use crate::options::DeckVariant;
+    use crate::test_util::player_ids;
+    use crate::types::{Players, RoundData, RoundEndReason};
+    use backend_framework::prng::PrngRand;
+
+    /// A round where the deck ran out, with the given final card and discards for each player.
+    fn finished_round(hands: Vec<(&str, Card, Vec<Card>)>) -> RoundData {
+        let mut round_data = RoundData::new(&player_ids(hands.len()), DeckVariant::Classic, &mut PrngRand::new(42));
+        round_data.deck.clear();
+        round_data.players = Players::with_capacity(hands.len());
+        for (player_id, card, discards) in hands {
+            round_data.players.insert_at_tail(player_id.to_string(), card);
+            for discard in discards {
+                round_data.discard(player_id, discard);
+            }
+        }
+
+        round_data
+    }
+
+    #[test]
+    fn two_player_round_sets_aside_burn_and_face_up_cards() {
+        let round_data = RoundData::new(&player_ids(2), DeckVariant::Classic, &mut PrngRand::new(42));
+
+        assert!(round_data.burn_card.is_some());
+        assert_eq!(3, round_data.face_up_cards.len());
+        // 16 - 1 burned - 3 face up - 2 dealt
+        assert_eq!(10, round_data.deck.len());
+    }
+
+    #[test]
+    fn more_players_only_set_aside_burn_card() {
+        let round_data = RoundData::new(&player_ids(4), DeckVariant::Classic, &mut PrngRand::new(42));
+
+        assert!(round_data.burn_card.is_some());
+        assert!(round_data.face_up_cards.is_empty());
+        // 16 - 1 burned - 4 dealt
+        assert_eq!(11, round_data.deck.len());
+    }
+
+    #[test]
+    fn draw_replacement_card_takes_burn_card_when_deck_is_empty() {
+        let mut round_data = RoundData::new(&player_ids(3), DeckVariant::Classic, &mut PrngRand::new(42));
+        let top_deck = *round_data.deck.last().unwrap();
+        let burn_card = round_data.burn_card.unwrap();
+
+        assert_eq!(top_deck, round_data.draw_replacement_card());
+
+        round_data.deck.clear();
+        assert_eq!(burn_card, round_data.draw_replacement_card());
+        assert_eq!(None, round_data.burn_card);
+    }
+
+    #[test]
+    fn last_player_standing_wins() {
+        let mut round_data = finished_round(vec![
+            ("p1", Card::Guard, vec![]),
+            ("p2", Card::Princess, vec![]),
+        ]);
+        round_data.players.eliminate_and_increment_turn("p2");
+
+        let round_result = round_data.into_round_result(DeckVariant::Classic);
+        assert_eq!(vec!["p1".to_string()], round_result.winner_player_ids);
+        assert_eq!(RoundEndReason::LastPlayerStanding, round_result.end_reason);
+    }
+
+    #[test]
+    fn highest_card_wins_when_deck_exhausted() {
+        let round_data = finished_round(vec![
+            ("p1", Card::Baron, vec![Card::Prince]),
+            ("p2", Card::King, vec![]),
+            ("p3", Card::Priest, vec![]),
+        ]);
+
+        let round_result = round_data.into_round_result(DeckVariant::Classic);
+        assert_eq!(vec!["p2".to_string()], round_result.winner_player_ids);
+        assert_eq!(RoundEndReason::DeckExhausted, round_result.end_reason);
+        assert_eq!(Some(&5), round_result.discard_total_by_player_id.get("p1"));
+        assert_eq!(Some(&0), round_result.discard_total_by_player_id.get("p2"));
+    }
+
+    #[test]
+    fn tied_cards_are_broken_by_discard_total() {
+        let round_data = finished_round(vec![
+            ("p1", Card::King, vec![Card::Guard, Card::Priest]),
+            ("p2", Card::King, vec![Card::Handmaid]),
+            ("p3", Card::Guard, vec![Card::Prince, Card::Baron]),
+        ]);
+
+        let round_result = round_data.into_round_result(DeckVariant::Classic);
+        assert_eq!(vec!["p2".to_string()], round_result.winner_player_ids);
+        assert_eq!(RoundEndReason::Tiebreak, round_result.end_reason);
+    }
+
+    #[test]
+    fn tied_discard_totals_share_the_win() {
+        let round_data = finished_round(vec![
+            ("p1", Card::Baron, vec![Card::Handmaid]),
+            ("p2", Card::Baron, vec![Card::Guard, Card::Baron]),
+        ]);
+
+        let round_result = round_data.into_round_result(DeckVariant::Classic);
+        assert_eq!(vec!["p1".to_string(), "p2".to_string()], round_result.winner_player_ids);
+        assert_eq!(RoundEndReason::Tiebreak, round_result.end_reason);
+    }
+
+    #[test]
+    fn count_and_bishop_change_final_card_value() {
+        let round_data = finished_round(vec![
+            ("p1", Card::Bishop, vec![]),
+            ("p2", Card::Princess, vec![Card::Guard]),
+            ("p3", Card::King, vec![Card::Count, Card::Count, Card::Count]),
+        ]);
+
+        // Bishop loses to the Princess, and 3 Counts make the King(6) a 9.
+        let round_result = round_data.into_round_result(DeckVariant::Premium);
+        assert_eq!(vec!["p3".to_string()], round_result.winner_player_ids);
+        assert_eq!(RoundEndReason::DeckExhausted, round_result.end_reason);
+    }
 }
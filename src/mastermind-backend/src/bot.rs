@@ -0,0 +1,149 @@
+use crate::options::MastermindOptions;
+use crate::state_machine::compare;
+use crate::types::{Color, ResultPegs, Row, NO_COLOR};
+use backend_framework::bot::Bot;
+use backend_framework::prng::PrngRand;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataIn, ProtoMastermindDataOut, ProtoMmBoard, ProtoMmCommitRowReq, ProtoMmGameState, ProtoMmPutPegReq, ProtoMmRow, ProtoMmSubmitPasswordReq};
+use backend_framework::wire_api::proto_frj_ngn::proto_mastermind_data_in::ProtoMmIn;
+use backend_framework::wire_api::proto_frj_ngn::proto_mastermind_data_out::ProtoMmOut;
+use backend_framework::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
+
+/// Random guesses are tried until one could be the password, so each guess is consistent with
+/// the feedback so far. This bounds the time spent on a single guess, for large boards.
+const MAX_GUESS_ATTEMPTS: usize = 10_000;
+
+/// Picks a random password, and guesses one row at a time. Each guess could be the password,
+/// given the feedback on the previous guesses.
+pub struct MastermindBot {
+    rng: PrngRand,
+    game_state: Option<ProtoMmGameState>,
+}
+
+impl MastermindBot {
+    pub fn new(rng: PrngRand) -> Self {
+        MastermindBot {
+            rng,
+            game_state: None,
+        }
+    }
+
+    fn moves(&mut self, game_state: &ProtoMmGameState) -> Vec<ProtoMmIn> {
+        let board = match &game_state.my_board {
+            Some(board) => board,
+            None => return Vec::new(),
+        };
+        let options = options(game_state);
+
+        match ProtoMmStage::from_i32(game_state.stage) {
+            Some(ProtoMmStage::PasswordSelection) if !board.password_submitted => {
+                let password = Row::random_password(&options, &mut self.rng);
+                let mut moves = put_row(&password);
+                moves.push(ProtoMmIn::SubmitPassword(ProtoMmSubmitPasswordReq {}));
+                moves
+            },
+            // Cooperating players also put pegs on the current guess, so the bot waits until
+            // it's blank.
+            Some(ProtoMmStage::Guessing) if !board.solved && !board.failed && is_blank(&board.current_guess) => {
+                let guess = self.consistent_guess(board, &options);
+                let mut moves = put_row(&guess);
+                moves.push(ProtoMmIn::CommitRow(ProtoMmCommitRowReq {}));
+                moves
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// A guess which scores the same against every previous guess as the password did. Falls
+    /// back to the last random guess if none is found in time.
+    fn consistent_guess(&mut self, board: &ProtoMmBoard, options: &MastermindOptions) -> Row {
+        let completed_rows: Vec<(Row, ResultPegs)> = board.completed_rows
+            .iter()
+            .map(|completed_row| {
+                let guess = to_row(completed_row.guess.as_ref(), options);
+                let result_pegs = ResultPegs {
+                    correct: completed_row.correct as u8,
+                    correct_color_wrong_slot: completed_row.correct_color_wrong_slot as u8,
+                };
+                (guess, result_pegs)
+            })
+            .collect();
+
+        let mut guess = Row::random_password(options, &mut self.rng);
+        for _ in 1..MAX_GUESS_ATTEMPTS {
+            let is_consistent = completed_rows
+                .iter()
+                .all(|(previous_guess, result_pegs)| compare(previous_guess, &guess) == *result_pegs);
+            if is_consistent {
+                break;
+            }
+            guess = Row::random_password(options, &mut self.rng);
+        }
+
+        guess
+    }
+}
+
+impl Bot for MastermindBot {
+    type DataIn = ProtoMastermindDataIn;
+    type DataOut = ProtoMastermindDataOut;
+
+    fn observe(&mut self, message: ProtoMastermindDataOut) {
+        if let Some(ProtoMmOut::GameState(game_state)) = message.proto_mm_out {
+            self.game_state = Some(game_state);
+        }
+    }
+
+    fn next_moves(&mut self) -> Vec<ProtoMastermindDataIn> {
+        let game_state = match self.game_state.take() {
+            Some(game_state) => game_state,
+            None => return Vec::new(),
+        };
+        let moves = self.moves(&game_state);
+        self.game_state = Some(game_state);
+
+        moves.into_iter()
+            .map(|proto_mm_in| ProtoMastermindDataIn {
+                clock: 0,
+                proto_mm_in: Some(proto_mm_in),
+            })
+            .collect()
+    }
+}
+
+/// Only the options which decide what a password can be.
+fn options(game_state: &ProtoMmGameState) -> MastermindOptions {
+    MastermindOptions {
+        num_colors: game_state.num_colors as u8,
+        num_pegs: game_state.num_pegs as u8,
+        allow_duplicate_colors: !game_state.no_duplicate_colors,
+        allow_blanks: game_state.allow_blanks,
+        ..MastermindOptions::default()
+    }
+}
+
+fn to_row(proto_row: Option<&ProtoMmRow>, options: &MastermindOptions) -> Row {
+    let mut row = Row::new(options.num_pegs as usize, options.num_colors);
+    if let Some(proto_row) = proto_row {
+        for (peg, color) in proto_row.pegs.iter().enumerate() {
+            let _ = row.try_set(peg, *color as Color);
+        }
+    }
+
+    row
+}
+
+fn is_blank(proto_row: &Option<ProtoMmRow>) -> bool {
+    match proto_row {
+        Some(row) => row.pegs.iter().all(|color| *color == NO_COLOR as u32),
+        None => true,
+    }
+}
+
+fn put_row(row: &Row) -> Vec<ProtoMmIn> {
+    (0..row.len())
+        .map(|peg| ProtoMmIn::PutPeg(ProtoMmPutPegReq {
+            peg_index: peg as u32,
+            color: row.peg(peg) as u32,
+        }))
+        .collect()
+}
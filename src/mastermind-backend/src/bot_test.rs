@@ -0,0 +1,104 @@
+mod bot_tests {
+    use crate::bot::MastermindBot;
+    use crate::events::MastermindEventType;
+    use crate::instance_manager::MastermindInstanceManager;
+    use crate::options::MastermindOptions;
+    use crate::plugin::MastermindPlugin;
+    use crate::test_util::{client_info, event};
+    use backend_framework::bot::Bot;
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::game_plugin::GamePlugin;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataOut, ProtoMmGameState};
+    use backend_framework::wire_api::proto_frj_ngn::proto_mastermind_data_out::ProtoMmOut;
+    use backend_framework::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
+
+    /// Bots play a whole match against each other, or alone. Any rejected move fails the test,
+    /// since it would leave the bot waiting forever. Returns the final game state of the first bot.
+    fn play_bots_only_game(num_players: usize, options: MastermindOptions, seed: u64) -> ProtoMmGameState {
+        let player_ids: Vec<String> = (1..=num_players).map(|i| format!("bot:{}", i)).collect();
+        let mut game = MastermindInstanceManager::create_new_game(player_ids.clone(), options, PrngRand::new(seed));
+
+        let mut bots: Vec<(String, MastermindBot, StreamReceiver<ProtoMastermindDataOut>)> = Vec::new();
+        for player_id in player_ids {
+            let (stream_out, stream_rcv) = streaming::stream_channel(OverflowPolicy::DropOldest);
+            game.handle_event(event(&player_id, MastermindEventType::RegisterDataStream(stream_out)));
+            let bot = MastermindBot::new(PrngRand::new(seed));
+            bots.push((player_id, bot, stream_rcv));
+        }
+
+        let mut last_game_state = None;
+        for _ in 0..1_000 {
+            for (i, (player_id, bot, stream_rcv)) in bots.iter_mut().enumerate() {
+                let mut has_observed = false;
+                while let Some(message) = stream_rcv.try_next() {
+                    let message = message.unwrap_or_else(|status| panic!("Move by '{}' was rejected: {:?}", player_id, status));
+                    if let (0, Some(ProtoMmOut::GameState(game_state))) = (i, &message.proto_mm_out) {
+                        last_game_state = Some(game_state.clone());
+                    }
+                    bot.observe(message);
+                    has_observed = true;
+                }
+                if !has_observed {
+                    continue;
+                }
+
+                for message in bot.next_moves() {
+                    let event = MastermindPlugin.decode_event(client_info(player_id), message).expect("Bot sends valid messages");
+                    game.handle_event(event);
+                }
+            }
+
+            if let Some(game_state) = &last_game_state {
+                if game_state.stage == ProtoMmStage::Complete as i32 {
+                    return game_state.clone();
+                }
+            }
+        }
+
+        panic!("Bots didn't finish the game: {:?}", last_game_state);
+    }
+
+    #[test]
+    fn bot_solves_single_player_board() {
+        for seed in 1..=10 {
+            let game_state = play_bots_only_game(1, MastermindOptions::default(), seed);
+
+            let board = game_state.my_board.unwrap();
+            assert!(board.solved, "Seed {}: {:?}", seed, board);
+        }
+    }
+
+    #[test]
+    fn bot_solves_board_with_blanks_and_no_duplicates() {
+        let options = MastermindOptions {
+            num_colors: 6,
+            num_pegs: 5,
+            allow_duplicate_colors: false,
+            allow_blanks: true,
+            ..MastermindOptions::default()
+        };
+        for seed in 1..=10 {
+            let game_state = play_bots_only_game(1, options.clone(), seed);
+
+            let board = game_state.my_board.unwrap();
+            assert!(board.solved, "Seed {}: {:?}", seed, board);
+        }
+    }
+
+    #[test]
+    fn bots_play_a_match() {
+        let options = MastermindOptions {
+            num_rounds: 3,
+            ..MastermindOptions::default()
+        };
+        for seed in 1..=5 {
+            let game_state = play_bots_only_game(2, options.clone(), seed);
+
+            assert_eq!(3, game_state.round);
+            assert_eq!(3, game_state.round_results.len());
+        }
+    }
+}
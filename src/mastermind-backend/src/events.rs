@@ -1,19 +1,19 @@
 use crate::types::Color;
+use backend_framework::common_types::ClientInfo;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::ProtoMastermindDataOut;
 
 #[derive(Debug)]
 pub struct MastermindEvent {
-    pub client_player_id: String,
+    // TODO:3 this unnecessarily leaks `game_id` into individual instance managers
+    pub client_info: ClientInfo,
     pub payload: MastermindEventType,
 }
 
-/// TODO:1.5 should be `backend_framework::streaming::StreamSender`
-#[derive(Debug)]
-pub struct FakeStreamSender;
-
 #[derive(Debug)]
 pub enum MastermindEventType {
     // Common
-    RegisterDataStream(FakeStreamSender),
+    RegisterDataStream(StreamSender<ProtoMastermindDataOut>),
     GetGameState,
     SubmitPassword,
 
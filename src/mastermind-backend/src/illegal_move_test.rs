@@ -0,0 +1,175 @@
+mod illegal_move_tests {
+    use crate::events::MastermindEventType;
+    use crate::instance_manager::MastermindInstanceManager;
+    use crate::options::MastermindOptions;
+    use crate::test_util::{event, recv_game_state};
+    use crate::types::Color;
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataOut, ProtoMmGameState, ProtoMmIllegalMove};
+    use backend_framework::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
+    use backend_framework::wire_api::proto_frj_ngn::proto_mm_illegal_move::ProtoMmIllegalMoveReason;
+    use prost::Message;
+    use tokio::stream::StreamExt;
+    use tonic::Code;
+
+    struct TestGame {
+        game: MastermindInstanceManager,
+        p1_rcv: StreamReceiver<ProtoMastermindDataOut>,
+        p2_rcv: StreamReceiver<ProtoMastermindDataOut>,
+    }
+
+    impl TestGame {
+        /// Both players' streams are already past their initial game state.
+        async fn new(options: MastermindOptions) -> Self {
+            let player_ids = vec!["p1".to_string(), "p2".to_string()];
+            let mut game = MastermindInstanceManager::create_new_game(player_ids, options, PrngRand::new(42));
+
+            let (p1_out, mut p1_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+            let (p2_out, mut p2_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+            game.handle_event(event("p1", MastermindEventType::RegisterDataStream(p1_out)));
+            game.handle_event(event("p2", MastermindEventType::RegisterDataStream(p2_out)));
+            recv_game_state(&mut p1_rcv).await;
+            recv_game_state(&mut p2_rcv).await;
+
+            TestGame {
+                game,
+                p1_rcv,
+                p2_rcv,
+            }
+        }
+
+        fn stream_rcv(&mut self, player_id: &str) -> &mut StreamReceiver<ProtoMastermindDataOut> {
+            match player_id {
+                "p1" => &mut self.p1_rcv,
+                _ => &mut self.p2_rcv,
+            }
+        }
+
+        /// Returns the acting player's game state, after it's sent to both players.
+        async fn accept(&mut self, player_id: &str, payload: MastermindEventType) -> ProtoMmGameState {
+            self.game.handle_event(event(player_id, payload));
+            let p1_state = recv_game_state(&mut self.p1_rcv).await;
+            let p2_state = recv_game_state(&mut self.p2_rcv).await;
+            match player_id {
+                "p1" => p1_state,
+                _ => p2_state,
+            }
+        }
+
+        async fn reject(&mut self, player_id: &str, payload: MastermindEventType) -> (Code, ProtoMmIllegalMoveReason) {
+            self.game.handle_event(event(player_id, payload));
+            let status = self.stream_rcv(player_id).next().await
+                .expect("Stream should have a message")
+                .expect_err("Move should be rejected");
+
+            let details = base64::decode(status.details()).expect("Status details are base64 encoded");
+            let illegal_move = ProtoMmIllegalMove::decode(details.as_slice()).expect("Status details are a ProtoMmIllegalMove");
+            let reason = ProtoMmIllegalMoveReason::from_i32(illegal_move.reason).expect("Reason is a known enum value");
+            (status.code(), reason)
+        }
+
+        async fn put_row(&mut self, player_id: &str, pegs: &[Color]) {
+            for (peg, color) in pegs.iter().enumerate() {
+                self.accept(player_id, MastermindEventType::PutPeg { peg, color: *color }).await;
+            }
+        }
+
+        /// Rejected moves must not have sent anything else to either player.
+        async fn assert_no_more_messages(self) {
+            drop(self.game);
+            assert_eq!(0, self.p1_rcv.collect::<Vec<_>>().await.len());
+            assert_eq!(0, self.p2_rcv.collect::<Vec<_>>().await.len());
+        }
+    }
+
+    fn put_peg(peg: usize, color: Color) -> MastermindEventType {
+        MastermindEventType::PutPeg { peg, color }
+    }
+
+    #[tokio::test]
+    async fn moves_during_password_selection_are_rejected() {
+        let mut game = TestGame::new(MastermindOptions::default()).await;
+
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::NotGuessing), game.reject("p1", MastermindEventType::CommitRow).await);
+        assert_eq!((Code::InvalidArgument, ProtoMmIllegalMoveReason::PegOutOfBounds), game.reject("p1", put_peg(4, 1)).await);
+        assert_eq!((Code::InvalidArgument, ProtoMmIllegalMoveReason::ColorOutOfBounds), game.reject("p2", put_peg(0, 9)).await);
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::RowIncomplete), game.reject("p2", MastermindEventType::SubmitPassword).await);
+
+        game.assert_no_more_messages().await;
+    }
+
+    #[tokio::test]
+    async fn duplicate_colors_are_rejected_when_disallowed() {
+        let mut game = TestGame::new(MastermindOptions {
+            allow_duplicate_colors: false,
+            ..MastermindOptions::default()
+        }).await;
+
+        game.put_row("p1", &[1, 1, 2, 3]).await;
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::DuplicateColors), game.reject("p1", MastermindEventType::SubmitPassword).await);
+
+        game.accept("p1", put_peg(1, 4)).await;
+        let game_state = game.accept("p1", MastermindEventType::SubmitPassword).await;
+        assert!(game_state.my_board.unwrap().password_submitted);
+
+        game.assert_no_more_messages().await;
+    }
+
+    #[tokio::test]
+    async fn solved_player_and_complete_game_reject_moves() {
+        let mut game = TestGame::new(MastermindOptions::default()).await;
+        game.put_row("p1", &[1, 2, 3, 4]).await;
+        game.put_row("p2", &[4, 3, 2, 1]).await;
+        game.accept("p1", MastermindEventType::SubmitPassword).await;
+        let game_state = game.accept("p2", MastermindEventType::SubmitPassword).await;
+        assert_eq!(ProtoMmStage::Guessing as i32, game_state.stage);
+
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::PasswordSelectionOver), game.reject("p1", MastermindEventType::SubmitPassword).await);
+
+        // p1 guesses p2's password.
+        game.put_row("p1", &[4, 3, 2, 1]).await;
+        let game_state = game.accept("p1", MastermindEventType::CommitRow).await;
+        assert!(game_state.my_board.unwrap().solved);
+
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::AlreadySolved), game.reject("p1", put_peg(0, 1)).await);
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::AlreadySolved), game.reject("p1", MastermindEventType::CommitRow).await);
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::RowIncomplete), game.reject("p2", MastermindEventType::CommitRow).await);
+
+        // p2 guesses p1's password.
+        game.put_row("p2", &[1, 2, 3, 4]).await;
+        let game_state = game.accept("p2", MastermindEventType::CommitRow).await;
+        assert_eq!(ProtoMmStage::Complete as i32, game_state.stage);
+
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::GameComplete), game.reject("p2", put_peg(0, 1)).await);
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::GameComplete), game.reject("p1", MastermindEventType::CommitRow).await);
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::GameComplete), game.reject("p1", MastermindEventType::SubmitPassword).await);
+
+        game.assert_no_more_messages().await;
+    }
+
+    #[tokio::test]
+    async fn player_out_of_rows_rejects_moves() {
+        let options = MastermindOptions {
+            max_rows: 1,
+            ..MastermindOptions::default()
+        };
+        let mut game = TestGame::new(options).await;
+        game.put_row("p1", &[1, 2, 3, 4]).await;
+        game.put_row("p2", &[4, 3, 2, 1]).await;
+        game.accept("p1", MastermindEventType::SubmitPassword).await;
+        game.accept("p2", MastermindEventType::SubmitPassword).await;
+
+        // p1 uses their only row on a wrong guess.
+        game.put_row("p1", &[1, 2, 3, 4]).await;
+        let game_state = game.accept("p1", MastermindEventType::CommitRow).await;
+        assert!(!game_state.my_board.unwrap().solved);
+
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::OutOfRows), game.reject("p1", put_peg(0, 1)).await);
+        assert_eq!((Code::FailedPrecondition, ProtoMmIllegalMoveReason::OutOfRows), game.reject("p1", MastermindEventType::CommitRow).await);
+
+        game.assert_no_more_messages().await;
+    }
+}
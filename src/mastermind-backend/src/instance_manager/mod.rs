@@ -1,57 +1,121 @@
-use crate::events::MastermindEvent;
+use crate::events::{MastermindEvent, MastermindEventType};
+use crate::options::MastermindOptions;
 use crate::state_machine::{BoardState, MastermindStateMachine};
-use crate::types::Players;
+use crate::types::{ActiveBoard, Players, Row};
 use backend_framework::activity_timer::ActivityTracker;
 use backend_framework::game_instance_manager::GameInstanceManager;
+use backend_framework::prng::PrngRand;
 use std::time::Duration;
 use backend_framework::holder::Holder;
-use crate::state_machine::data::PregameData;
-
-const NUM_COLORS: u8 = 8; // TODO:2 parameterize colors
+use crate::state_machine::data::{DoneData, PregameData};
+use serde::{Deserialize, Serialize};
+use tonic::Status;
 
 pub struct MastermindInstanceManager {
     state: Holder<BoardState>,
     state_machine: MastermindStateMachine,
-    players: Players,
     activity_tracker: ActivityTracker,
 }
 
+/// What's saved by `snapshot()`. Streams and activity aren't part of it.
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    state: &'a BoardState,
+    players: &'a Players,
+    options: &'a MastermindOptions,
+    completed_rounds: &'a Vec<DoneData>,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    state: BoardState,
+    players: Players,
+    options: MastermindOptions,
+    completed_rounds: Vec<DoneData>,
+}
+
 impl GameInstanceManager<MastermindEvent> for MastermindInstanceManager {
-    fn create_new_game(mut player_ids: Vec<String>) -> Self {
-        assert_eq!(player_ids.len(), 2, "Mastermind validation bug: should've validated 2 players");
-        let p2 = player_ids.pop().expect("Mastermind validation bug: should've validated 2 players");
-        let p1 = player_ids.pop().expect("Mastermind validation bug: should've validated 2 players");
+    type Options = MastermindOptions;
+
+    fn create_new_game(mut player_ids: Vec<String>, options: MastermindOptions, mut rng: PrngRand) -> Self {
+        let (state, players) = if options.is_shared_board(player_ids.len()) {
+            // The server picks the password, so guessing starts right away.
+            let password = Row::random_password(&options, &mut rng);
+            (BoardState::SharedActive(ActiveBoard::new(password)), Players::shared(player_ids))
+        } else {
+            assert_eq!(player_ids.len(), 2, "Mastermind validation bug: should've validated 2 players");
+            let p2 = player_ids.pop().expect("Mastermind validation bug: should've validated 2 players");
+            let p1 = player_ids.pop().expect("Mastermind validation bug: should've validated 2 players");
+            (BoardState::Pregame(PregameData::new(&options)), Players::new(p1, p2))
+        };
 
         MastermindInstanceManager {
-            state: Holder::new(BoardState::Pregame(PregameData::new(NUM_COLORS))),
-            state_machine: MastermindStateMachine::new(),
-            players: Players::new(p1, p2),
+            state: Holder::new(state),
+            state_machine: MastermindStateMachine::new(players, options, Vec::new()),
             activity_tracker: ActivityTracker::new(),
         }
     }
 
-    fn handle_event(&mut self, event: MastermindEvent) {
-        let player = match self.players.get_side(&event.client_player_id) {
+    fn handle_event(&mut self, event: MastermindEvent) -> bool {
+        let player_id = event.client_info.player_id;
+        let player = match self.state_machine.players().get_side(&player_id) {
             Some(player) => player,
             None => {
-                // Player not in match.
-                // No way to notify player :P oh well.
-                return;
+                // Player not in match. We can only notify them if they're opening a stream.
+                if let MastermindEventType::RegisterDataStream(stream_out) = event.payload {
+                    let _ = stream_out.send_error_message(Status::permission_denied("You are not a player in this game."));
+                }
+                return false;
             },
         };
+        let is_read_only = matches!(event.payload, MastermindEventType::GetGameState | MastermindEventType::RegisterDataStream(_));
 
         // Transition
         let from_state = self.state.take();
-        let to_state = self.state_machine.handle_transition(from_state, player, event.payload);
+        let (to_state, changed) = match self.state_machine.handle_transition(from_state, &player_id, player, event.payload) {
+            Ok(to_state) => (to_state, !is_read_only),
+            Err(rejected) => {
+                self.state_machine.send_illegal_move(&player_id, rejected.reason);
+                (*rejected.state, false)
+            },
+        };
         self.state.put(to_state);
         self.activity_tracker.ping();
+        changed
     }
 
     fn player_ids(&self) -> &Vec<String> {
-        &self.players.as_vec
+        &self.state_machine.players().as_vec
     }
 
     fn is_game_stale(&self, expiry_duration: Duration) -> bool {
         self.activity_tracker.has_inactivity_elapsed(expiry_duration)
     }
-}
\ No newline at end of file
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        let snapshot = SnapshotRef {
+            state: self.state.get(),
+            players: self.state_machine.players(),
+            options: self.state_machine.options(),
+            completed_rounds: self.state_machine.completed_rounds(),
+        };
+        match serde_json::to_vec(&snapshot) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                println!("ERROR: Failed to snapshot Mastermind game: {}", e);
+                None
+            },
+        }
+    }
+
+    fn restore_game(snapshot: &[u8]) -> Result<Self, String> {
+        let snapshot: Snapshot = serde_json::from_slice(snapshot)
+            .map_err(|e| format!("Invalid Mastermind snapshot: {}", e))?;
+
+        Ok(MastermindInstanceManager {
+            state: Holder::new(snapshot.state),
+            state_machine: MastermindStateMachine::new(snapshot.players, snapshot.options, snapshot.completed_rounds),
+            activity_tracker: ActivityTracker::new(),
+        })
+    }
+}
@@ -1,4 +1,21 @@
+mod bot;
 pub mod instance_manager;
 pub mod events;
+pub mod options;
+pub mod plugin;
 mod state_machine;
+mod type_converters;
 mod types;
+
+#[cfg(test)]
+mod bot_test;
+#[cfg(test)]
+mod illegal_move_test;
+#[cfg(test)]
+mod match_test;
+#[cfg(test)]
+mod shared_board_test;
+#[cfg(test)]
+pub(crate) mod test_util;
+#[cfg(test)]
+mod types_test;
@@ -0,0 +1,185 @@
+mod round_result_tests {
+    use crate::state_machine::data::{DoneData, MatchScore};
+    use crate::types::{CompletedBoard, PlayerSide, ResultPegs, Row};
+    use std::time::Duration;
+
+    fn row(pegs: &[u8]) -> Row {
+        let mut row = Row::new(pegs.len(), 8);
+        for (i, color) in pegs.iter().enumerate() {
+            row.try_set(i, *color).unwrap();
+        }
+        row
+    }
+
+    /// Guesses wrong `wrong_guesses` times, then guesses right if `solved`.
+    fn board(wrong_guesses: usize, solved: bool, secs: u64) -> CompletedBoard {
+        let password = row(&[1, 2, 3, 4]);
+        let mut completed_rows: Vec<(Row, ResultPegs)> = (0..wrong_guesses)
+            .map(|_| (row(&[5, 5, 5, 5]), ResultPegs::default()))
+            .collect();
+        if solved {
+            completed_rows.push((password.clone(), ResultPegs { correct: 4, correct_color_wrong_slot: 0 }));
+        }
+
+        CompletedBoard::new(completed_rows, password, Duration::from_secs(secs))
+    }
+
+    fn round(left: CompletedBoard, right: CompletedBoard) -> DoneData {
+        DoneData {
+            left,
+            right,
+        }
+    }
+
+    #[test]
+    fn fewest_rows_wins_then_fastest_time() {
+        assert_eq!(Some(PlayerSide::Left), round(board(2, true, 60), board(3, true, 10)).winner());
+        assert_eq!(Some(PlayerSide::Right), round(board(2, true, 60), board(2, true, 10)).winner());
+        assert_eq!(None, round(board(2, true, 10), board(2, true, 10)).winner());
+    }
+
+    #[test]
+    fn failed_board_loses_to_any_solved_board() {
+        let failed = board(10, false, 5);
+        assert!(!failed.is_solved());
+
+        assert_eq!(Some(PlayerSide::Right), round(board(10, false, 5), board(9, true, 60)).winner());
+        assert_eq!(None, round(board(10, false, 5), board(10, false, 60)).winner());
+    }
+
+    #[test]
+    fn code_maker_earns_a_point_per_row_and_a_bonus_if_unsolved() {
+        let round = round(board(3, true, 10), board(10, false, 10));
+
+        // Left used 4 rows on Right's password, Right failed after 10 rows on Left's password.
+        assert_eq!(4, round.points(PlayerSide::Right));
+        assert_eq!(11, round.points(PlayerSide::Left));
+    }
+
+    #[test]
+    fn match_is_won_on_points_then_round_wins() {
+        let rounds = [
+            round(board(1, true, 10), board(4, true, 10)),
+            round(board(3, true, 10), board(2, true, 10)),
+        ];
+        let rounds: Vec<&DoneData> = rounds.iter().collect();
+        assert_eq!(MatchScore { points: 8, round_wins: 1 }, MatchScore::new(&rounds, PlayerSide::Left));
+        assert_eq!(MatchScore { points: 6, round_wins: 1 }, MatchScore::new(&rounds, PlayerSide::Right));
+        assert_eq!(Some(PlayerSide::Left), MatchScore::winner(&rounds));
+
+        // Tied on points, but Right won 2 rounds.
+        let rounds = [
+            round(board(2, true, 20), board(2, true, 10)),
+            round(board(2, true, 20), board(2, true, 10)),
+        ];
+        let rounds: Vec<&DoneData> = rounds.iter().collect();
+        assert_eq!(Some(PlayerSide::Right), MatchScore::winner(&rounds));
+
+        let rounds = [round(board(2, true, 10), board(2, true, 10))];
+        let rounds: Vec<&DoneData> = rounds.iter().collect();
+        assert_eq!(None, MatchScore::winner(&rounds));
+    }
+}
+
+mod match_tests {
+    use crate::events::MastermindEventType;
+    use crate::instance_manager::MastermindInstanceManager;
+    use crate::options::MastermindOptions;
+    use crate::test_util::{event, recv_game_state};
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataOut, ProtoMmGameState};
+    use backend_framework::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
+
+    struct TestMatch {
+        game: MastermindInstanceManager,
+        p1_rcv: StreamReceiver<ProtoMastermindDataOut>,
+        p2_rcv: StreamReceiver<ProtoMastermindDataOut>,
+    }
+
+    impl TestMatch {
+        async fn new(options: MastermindOptions) -> Self {
+            let player_ids = vec!["p1".to_string(), "p2".to_string()];
+            let mut game = MastermindInstanceManager::create_new_game(player_ids, options, PrngRand::new(42));
+
+            let (p1_out, mut p1_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+            let (p2_out, mut p2_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+            game.handle_event(event("p1", MastermindEventType::RegisterDataStream(p1_out)));
+            game.handle_event(event("p2", MastermindEventType::RegisterDataStream(p2_out)));
+            recv_game_state(&mut p1_rcv).await;
+            recv_game_state(&mut p2_rcv).await;
+
+            TestMatch {
+                game,
+                p1_rcv,
+                p2_rcv,
+            }
+        }
+
+        /// Returns p1's game state, after it's sent to both players.
+        async fn play(&mut self, player_id: &str, payload: MastermindEventType) -> ProtoMmGameState {
+            self.game.handle_event(event(player_id, payload));
+            recv_game_state(&mut self.p2_rcv).await;
+            recv_game_state(&mut self.p1_rcv).await
+        }
+
+        async fn play_row(&mut self, player_id: &str, pegs: &[u8], finish: MastermindEventType) -> ProtoMmGameState {
+            for (peg, color) in pegs.iter().enumerate() {
+                self.play(player_id, MastermindEventType::PutPeg { peg, color: *color }).await;
+            }
+            self.play(player_id, finish).await
+        }
+
+        async fn submit_passwords(&mut self, p1_password: &[u8], p2_password: &[u8]) {
+            self.play_row("p1", p1_password, MastermindEventType::SubmitPassword).await;
+            self.play_row("p2", p2_password, MastermindEventType::SubmitPassword).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn board_fails_after_max_rows_and_next_round_starts() {
+        let mut game = TestMatch::new(MastermindOptions {
+            max_rows: 2,
+            num_rounds: 2,
+            ..MastermindOptions::default()
+        }).await;
+
+        // -- round 1: p1 fails, p2 solves on the first guess --
+        game.submit_passwords(&[1, 2, 3, 4], &[4, 3, 2, 1]).await;
+        game.play_row("p1", &[5, 5, 5, 5], MastermindEventType::CommitRow).await;
+        let game_state = game.play_row("p1", &[6, 6, 6, 6], MastermindEventType::CommitRow).await;
+        let my_board = game_state.my_board.unwrap();
+        assert!(my_board.failed);
+        assert!(!my_board.solved);
+        assert_eq!(ProtoMmStage::Guessing as i32, game_state.stage);
+
+        let game_state = game.play_row("p2", &[1, 2, 3, 4], MastermindEventType::CommitRow).await;
+        assert_eq!(ProtoMmStage::PasswordSelection as i32, game_state.stage);
+        assert_eq!(2, game_state.round);
+        assert_eq!(1, game_state.round_results.len());
+        assert_eq!("p2", game_state.round_results[0].winner_player_id);
+        let points: Vec<u32> = game_state.round_results[0].players.iter().map(|player| player.points).collect();
+        assert_eq!(vec![1, 3], points);
+        assert!(game_state.my_board.unwrap().completed_rows.is_empty());
+        assert!(game_state.match_winner_player_id.is_empty());
+
+        // -- round 2: p1 solves on the first guess, p2 on the second --
+        game.submit_passwords(&[1, 1, 1, 1], &[2, 2, 2, 2]).await;
+        game.play_row("p1", &[2, 2, 2, 2], MastermindEventType::CommitRow).await;
+        game.play_row("p2", &[3, 3, 3, 3], MastermindEventType::CommitRow).await;
+        let game_state = game.play_row("p2", &[1, 1, 1, 1], MastermindEventType::CommitRow).await;
+        assert_eq!(ProtoMmStage::Complete as i32, game_state.stage);
+        assert_eq!(2, game_state.round);
+        assert_eq!(2, game_state.round_results.len());
+
+        let scores: Vec<(&str, u32, u32)> = game_state.match_scores
+            .iter()
+            .map(|score| (score.player_id.as_str(), score.points, score.round_wins))
+            .collect();
+        // Round wins are tied, so p2 wins on points.
+        assert_eq!(vec![("p1", 3, 1), ("p2", 4, 1)], scores);
+        assert_eq!("p2", game_state.match_winner_player_id);
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// Chosen by the host when creating a game. Unset options follow the classic board game.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MastermindOptions {
+    /// Number of colors a peg can be.
+    pub num_colors: u8,
+    /// Number of pegs in the password and in each guess.
+    pub num_pegs: u8,
+    /// If false, a password can't use a color more than once. Guesses always can.
+    pub allow_duplicate_colors: bool,
+    /// If true, pegs can be left blank. A blank is scored like any other color, and counts as a
+    /// duplicate if there's more than one.
+    pub allow_blanks: bool,
+    /// Number of guesses a player gets before their board fails.
+    pub max_rows: u8,
+    /// Number of rounds in the match. Both players make and break a password every round.
+    pub num_rounds: u8,
+    /// If true, every player guesses on one board against a password picked by the server.
+    /// Single player games are always played this way.
+    pub cooperative: bool,
+}
+
+pub const MIN_COLORS: u8 = 2;
+pub const MAX_COLORS: u8 = 10;
+pub const MIN_PEGS: u8 = 3;
+pub const MAX_PEGS: u8 = 8;
+pub const MIN_ROWS: u8 = 1;
+pub const MAX_ROWS: u8 = 20;
+pub const MIN_ROUNDS: u8 = 1;
+pub const MAX_ROUNDS: u8 = 9;
+/// Only cooperative games can have more than 2 players.
+pub const MAX_PLAYERS: usize = 8;
+
+impl Default for MastermindOptions {
+    fn default() -> Self {
+        MastermindOptions {
+            num_colors: 8,
+            num_pegs: 4,
+            allow_duplicate_colors: true,
+            allow_blanks: false,
+            max_rows: 10,
+            num_rounds: 1,
+            cooperative: false,
+        }
+    }
+}
+
+impl MastermindOptions {
+    /// True if the players share a board, rather than guessing each other's passwords.
+    pub fn is_shared_board(&self, num_players: usize) -> bool {
+        self.cooperative || num_players == 1
+    }
+
+    /// Number of distinct values a peg can take, including blank when blanks are allowed.
+    pub fn num_peg_values(&self) -> usize {
+        self.num_colors as usize + if self.allow_blanks { 1 } else { 0 }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::bot::MastermindBot;
+use crate::events::{MastermindEvent, MastermindEventType};
+use crate::instance_manager::MastermindInstanceManager;
+use crate::options::{MastermindOptions, MAX_COLORS, MAX_PEGS, MAX_PLAYERS, MAX_ROUNDS, MAX_ROWS, MIN_COLORS, MIN_PEGS, MIN_ROUNDS, MIN_ROWS};
+use backend_framework::common_types::ClientInfo;
+use backend_framework::game_instance_manager::GameInstanceManager;
+use backend_framework::game_plugin::{BoxedBot, GamePlugin};
+use backend_framework::prng::PrngRand;
+use backend_framework::streaming::StreamSender;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataIn, ProtoMastermindDataOut, ProtoGameType, ProtoMmOptions};
+use backend_framework::wire_api::proto_frj_ngn::proto_mastermind_data_in::ProtoMmIn;
+use prost::Message;
+use std::convert::TryFrom;
+use tonic::Status;
+
+/// Describes Mastermind to the engine.
+pub struct MastermindPlugin;
+
+impl GamePlugin for MastermindPlugin {
+    type DataIn = ProtoMastermindDataIn;
+    type DataOut = ProtoMastermindDataOut;
+    type Event = MastermindEvent;
+    type Instance = MastermindInstanceManager;
+
+    fn game_type(&self) -> ProtoGameType {
+        ProtoGameType::Mastermind
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Mastermind"
+    }
+
+    /// 1 player plays alone against the server. 2 players play against each other, unless they
+    /// choose to cooperate. More than 2 players always cooperate.
+    fn player_count_min_max(&self) -> (usize, usize) {
+        (1, MAX_PLAYERS)
+    }
+
+    fn decode_event(&self, client_info: ClientInfo, message: ProtoMastermindDataIn) -> Result<MastermindEvent, Status> {
+        let payload = match message.proto_mm_in {
+            None => return Err(Status::invalid_argument("Missing proto_mm_in field.")),
+            Some(payload) => convert_payload(payload)?,
+        };
+
+        Ok(MastermindEvent {
+            client_info,
+            payload,
+        })
+    }
+
+    fn register_data_stream_event(&self, client_info: ClientInfo, stream_out: StreamSender<ProtoMastermindDataOut>) -> MastermindEvent {
+        MastermindEvent {
+            client_info,
+            payload: MastermindEventType::RegisterDataStream(stream_out),
+        }
+    }
+
+    fn reject_event(&self, event: MastermindEvent, status: Status) {
+        if let MastermindEventType::RegisterDataStream(stream) = event.payload {
+            let _ = stream.send_error_message(status);
+        }
+    }
+
+    fn decode_options(&self, game_options: &[u8]) -> Result<MastermindOptions, Status> {
+        let proto_options = ProtoMmOptions::decode(game_options)
+            .map_err(|e| Status::invalid_argument(format!("Game options are not a valid ProtoMmOptions message: {}", e)))?;
+        convert_options(proto_options)
+    }
+
+    /// Only 2 players can play against each other, and only they can play multiple rounds.
+    fn create_instance(&self, player_ids: Vec<String>, game_options: &[u8], rng: PrngRand) -> Result<MastermindInstanceManager, Status> {
+        let options = self.decode_options(game_options)?;
+
+        if player_ids.len() > 2 && !options.cooperative {
+            return Err(Status::invalid_argument(format!(
+                "Only 2 players can play against each other, but there are {}. Choose a cooperative game instead.",
+                player_ids.len(),
+            )));
+        }
+
+        if options.is_shared_board(player_ids.len()) && options.num_rounds > 1 {
+            return Err(Status::invalid_argument(format!(
+                "A shared board is only played for 1 round, but {} rounds were chosen.",
+                options.num_rounds,
+            )));
+        }
+
+        Ok(MastermindInstanceManager::create_new_game(player_ids, options, rng))
+    }
+
+    fn supports_bots(&self) -> bool {
+        true
+    }
+
+    fn create_bot(&self, _player_id: String, rng: PrngRand) -> Option<BoxedBot<Self>> {
+        Some(Box::new(MastermindBot::new(rng)))
+    }
+}
+
+fn convert_options(proto_options: ProtoMmOptions) -> Result<MastermindOptions, Status> {
+    let mut options = MastermindOptions::default();
+
+    if proto_options.num_colors != 0 {
+        options.num_colors = convert_bounded_option("colors", proto_options.num_colors, MIN_COLORS, MAX_COLORS)?;
+    }
+
+    if proto_options.num_pegs != 0 {
+        options.num_pegs = convert_bounded_option("pegs", proto_options.num_pegs, MIN_PEGS, MAX_PEGS)?;
+    }
+
+    if proto_options.max_rows != 0 {
+        options.max_rows = convert_bounded_option("rows", proto_options.max_rows, MIN_ROWS, MAX_ROWS)?;
+    }
+
+    if proto_options.num_rounds != 0 {
+        options.num_rounds = convert_bounded_option("rounds", proto_options.num_rounds, MIN_ROUNDS, MAX_ROUNDS)?;
+    }
+
+    options.allow_duplicate_colors = !proto_options.no_duplicate_colors;
+    options.allow_blanks = proto_options.allow_blanks;
+    options.cooperative = proto_options.cooperative;
+
+    // Otherwise no password could be made.
+    if !options.allow_duplicate_colors && options.num_peg_values() < options.num_pegs as usize {
+        return Err(Status::invalid_argument(format!(
+            "Without duplicate colors, there must be at least as many colors as pegs, but there are {} colors for {} pegs.",
+            options.num_peg_values(),
+            options.num_pegs
+        )));
+    }
+
+    Ok(options)
+}
+
+fn convert_bounded_option(name: &str, value: u32, min: u8, max: u8) -> Result<u8, Status> {
+    u8::try_from(value)
+        .ok()
+        .filter(|value| (min..=max).contains(value))
+        .ok_or_else(|| Status::invalid_argument(format!(
+            "Number of {} must be from {} to {}, but was {}.",
+            name,
+            min,
+            max,
+            value
+        )))
+}
+
+fn convert_payload(payload: ProtoMmIn) -> Result<MastermindEventType, Status> {
+    match payload {
+        ProtoMmIn::GameState(_) => Ok(MastermindEventType::GetGameState),
+        ProtoMmIn::PutPeg(req) => {
+            let color = u8::try_from(req.color)
+                .map_err(|_| Status::invalid_argument("Color is out of bounds"))?;
+            Ok(MastermindEventType::PutPeg {
+                peg: req.peg_index as usize,
+                color,
+            })
+        },
+        ProtoMmIn::CommitRow(_) => Ok(MastermindEventType::CommitRow),
+        ProtoMmIn::SubmitPassword(_) => Ok(MastermindEventType::SubmitPassword),
+    }
+}
@@ -0,0 +1,208 @@
+mod shared_board_tests {
+    use crate::events::MastermindEventType;
+    use crate::instance_manager::MastermindInstanceManager;
+    use crate::options::MastermindOptions;
+    use crate::plugin::MastermindPlugin;
+    use crate::test_util::{event, recv_game_state};
+    use crate::types::Row;
+    use backend_framework::channel::OverflowPolicy;
+    use backend_framework::game_instance_manager::GameInstanceManager;
+    use backend_framework::game_plugin::GamePlugin;
+    use backend_framework::prng::PrngRand;
+    use backend_framework::streaming::{self, StreamReceiver};
+    use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataOut, ProtoMmGameState, ProtoMmIllegalMove, ProtoMmOptions};
+    use backend_framework::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
+    use backend_framework::wire_api::proto_frj_ngn::proto_mm_illegal_move::ProtoMmIllegalMoveReason;
+    use prost::Message;
+    use tokio::stream::StreamExt;
+    use tonic::{Code, Status};
+
+    const SEED: u64 = 42;
+
+    struct TestGame {
+        game: MastermindInstanceManager,
+        // Same order as the player ids.
+        stream_rcvs: Vec<StreamReceiver<ProtoMastermindDataOut>>,
+    }
+
+    impl TestGame {
+        /// Every player's stream is already past its initial game state, which is returned.
+        async fn new(player_ids: &[&str], options: MastermindOptions) -> (Self, ProtoMmGameState) {
+            let owned_ids = player_ids.iter().map(|player_id| player_id.to_string()).collect();
+            let mut game = MastermindInstanceManager::create_new_game(owned_ids, options, PrngRand::new(SEED));
+
+            let mut stream_rcvs = Vec::new();
+            let mut game_state = None;
+            for player_id in player_ids {
+                let (stream_out, mut stream_rcv) = streaming::stream_channel(OverflowPolicy::Reject);
+                game.handle_event(event(player_id, MastermindEventType::RegisterDataStream(stream_out)));
+                game_state = Some(recv_game_state(&mut stream_rcv).await);
+                stream_rcvs.push(stream_rcv);
+            }
+
+            let test_game = TestGame {
+                game,
+                stream_rcvs,
+            };
+            (test_game, game_state.expect("At least 1 player"))
+        }
+
+        /// Returns the game state, after asserting every player was sent the same one.
+        async fn accept(&mut self, player_id: &str, payload: MastermindEventType) -> ProtoMmGameState {
+            self.game.handle_event(event(player_id, payload));
+
+            let mut game_states = Vec::new();
+            for stream_rcv in self.stream_rcvs.iter_mut() {
+                game_states.push(recv_game_state(stream_rcv).await);
+            }
+            let game_state = game_states.pop().expect("At least 1 player");
+            for other_game_state in game_states {
+                assert_eq!(game_state, other_game_state);
+            }
+            game_state
+        }
+
+        async fn put_row(&mut self, player_id: &str, row: &Row) {
+            for peg in 0..row.len() {
+                self.accept(player_id, MastermindEventType::PutPeg { peg, color: row.peg(peg) }).await;
+            }
+        }
+    }
+
+    /// The password the server picks for a game created with `SEED`.
+    fn expected_password(options: &MastermindOptions) -> Row {
+        Row::random_password(options, &mut PrngRand::new(SEED))
+    }
+
+    /// Any row that isn't `password`.
+    fn wrong_guess(password: &Row, options: &MastermindOptions) -> Row {
+        let mut rng = PrngRand::new(SEED + 1);
+        loop {
+            let guess = Row::random_password(options, &mut rng);
+            if guess != *password {
+                return guess;
+            }
+        }
+    }
+
+    fn proto_pegs(row: &Row) -> Vec<u32> {
+        (0..row.len()).map(|peg| row.peg(peg) as u32).collect()
+    }
+
+    #[tokio::test]
+    async fn single_player_solves_the_server_password() {
+        let options = MastermindOptions::default();
+        let password = expected_password(&options);
+        let (mut game, game_state) = TestGame::new(&["p1"], options.clone()).await;
+
+        assert_eq!(ProtoMmStage::Guessing as i32, game_state.stage);
+        assert!(game_state.shared_board);
+        assert_eq!(None, game_state.opponent_board);
+        assert!(game_state.match_scores.is_empty());
+        let board = game_state.my_board.expect("Board is set");
+        assert_eq!("", board.player_id);
+        assert_eq!(None, board.password);
+
+        game.put_row("p1", &wrong_guess(&password, &options)).await;
+        game.accept("p1", MastermindEventType::CommitRow).await;
+        game.put_row("p1", &password).await;
+        let game_state = game.accept("p1", MastermindEventType::CommitRow).await;
+
+        assert_eq!(ProtoMmStage::Complete as i32, game_state.stage);
+        let board = game_state.my_board.expect("Board is set");
+        assert!(board.solved);
+        assert_eq!(2, board.completed_rows.len());
+        assert_eq!(Some(proto_pegs(&password)), board.password.map(|password| password.pegs));
+        assert_eq!("", game_state.match_winner_player_id);
+    }
+
+    #[tokio::test]
+    async fn cooperative_players_take_turns_on_one_board() {
+        let options = MastermindOptions {
+            cooperative: true,
+            ..MastermindOptions::default()
+        };
+        let password = expected_password(&options);
+        let (mut game, _) = TestGame::new(&["p1", "p2", "p3"], options).await;
+
+        // Anyone can put any peg, and everyone sees the same board.
+        for peg in 0..password.len() {
+            let player_id = ["p1", "p2", "p3"][peg % 3];
+            game.accept(player_id, MastermindEventType::PutPeg { peg, color: password.peg(peg) }).await;
+        }
+        let game_state = game.accept("p3", MastermindEventType::CommitRow).await;
+
+        assert_eq!(ProtoMmStage::Complete as i32, game_state.stage);
+        assert!(game_state.my_board.expect("Board is set").solved);
+    }
+
+    #[tokio::test]
+    async fn shared_board_has_no_password_selection() {
+        let (mut game, _) = TestGame::new(&["p1"], MastermindOptions::default()).await;
+
+        game.game.handle_event(event("p1", MastermindEventType::SubmitPassword));
+        let status = game.stream_rcvs[0].next().await
+            .expect("Stream should have a message")
+            .expect_err("Move should be rejected");
+
+        let details = base64::decode(status.details()).expect("Status details are base64 encoded");
+        let illegal_move = ProtoMmIllegalMove::decode(details.as_slice()).expect("Status details are a ProtoMmIllegalMove");
+        assert_eq!(Code::FailedPrecondition, status.code());
+        assert_eq!(ProtoMmIllegalMoveReason::NoPasswordSelection as i32, illegal_move.reason);
+    }
+
+    #[test]
+    fn password_follows_the_rules() {
+        let options = MastermindOptions {
+            num_pegs: 6,
+            allow_duplicate_colors: false,
+            allow_blanks: true,
+            ..MastermindOptions::default()
+        };
+
+        for seed in 1..=100 {
+            let password = Row::random_password(&options, &mut PrngRand::new(seed));
+            assert_eq!(6, password.len());
+            assert!((0..6).all(|peg| password.peg(peg) <= options.num_colors));
+            assert!(!password.has_duplicate_colors());
+            assert_eq!(password, Row::random_password(&options, &mut PrngRand::new(seed)));
+        }
+    }
+
+    fn create_instance(num_players: usize, options: ProtoMmOptions) -> Result<(), Status> {
+        let player_ids = (1..=num_players).map(|i| format!("p{}", i)).collect();
+        let mut encoded = Vec::with_capacity(options.encoded_len());
+        options.encode(&mut encoded).expect("Vec has enough capacity for the encoded message");
+
+        MastermindPlugin.create_instance(player_ids, &encoded, PrngRand::new(SEED)).map(|_| ())
+    }
+
+    #[test]
+    fn player_count_depends_on_cooperative_option() {
+        let cooperative = || ProtoMmOptions {
+            cooperative: true,
+            ..ProtoMmOptions::default()
+        };
+
+        assert!(create_instance(1, ProtoMmOptions::default()).is_ok());
+        assert!(create_instance(2, ProtoMmOptions::default()).is_ok());
+        assert!(create_instance(2, cooperative()).is_ok());
+        assert!(create_instance(8, cooperative()).is_ok());
+
+        let status = create_instance(3, ProtoMmOptions::default()).expect_err("3 players must cooperate");
+        assert_eq!(Code::InvalidArgument, status.code());
+    }
+
+    #[test]
+    fn shared_board_is_a_single_round() {
+        let rounds = |num_rounds, cooperative| ProtoMmOptions {
+            num_rounds,
+            cooperative,
+            ..ProtoMmOptions::default()
+        };
+
+        assert!(create_instance(2, rounds(3, false)).is_ok());
+        assert_eq!(Code::InvalidArgument, create_instance(1, rounds(3, false)).expect_err("Solo").code());
+        assert_eq!(Code::InvalidArgument, create_instance(2, rounds(3, true)).expect_err("Cooperative").code());
+    }
+}
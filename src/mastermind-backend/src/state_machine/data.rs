@@ -1,27 +1,35 @@
+use crate::options::MastermindOptions;
 use crate::types::{PreparingBoard, PlayerSide, ActiveBoard, CompletedBoard};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 // ---------------- struct ----------------
 
+#[derive(Serialize, Deserialize)]
 pub struct PregameData {
     pub left: PreparingBoard,
     pub right: PreparingBoard,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct ActiveData {
     pub left: ActiveBoard,
     pub right: ActiveBoard,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct LActiveRDoneData {
     pub left: ActiveBoard,
     pub right: CompletedBoard,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct LDoneRActiveData {
     pub left: CompletedBoard,
     pub right: ActiveBoard,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct DoneData {
     pub left: CompletedBoard,
     pub right: CompletedBoard,
@@ -30,10 +38,18 @@ pub struct DoneData {
 // ---------------- impl ----------------
 
 impl PregameData {
-    pub fn new(num_colors: u8) -> Self {
+    pub fn new(options: &MastermindOptions) -> Self {
+        let num_pegs = options.num_pegs as usize;
         PregameData {
-            left: PreparingBoard::new(num_colors),
-            right: PreparingBoard::new(num_colors),
+            left: PreparingBoard::new(num_pegs, options.num_colors),
+            right: PreparingBoard::new(num_pegs, options.num_colors),
+        }
+    }
+
+    pub fn my_board(&self, me: PlayerSide) -> &PreparingBoard {
+        match me {
+            PlayerSide::Left => &self.left,
+            PlayerSide::Right => &self.right,
         }
     }
 
@@ -75,6 +91,57 @@ impl DoneData {
             PlayerSide::Right => &self.left,
         }
     }
+
+    /// Fewest rows wins, then fastest time. A failed board loses to any solved board. `None` if
+    /// the round is a tie.
+    pub fn winner(&self) -> Option<PlayerSide> {
+        match (self.left.is_solved(), self.right.is_solved()) {
+            (false, false) => None,
+            (true, false) => Some(PlayerSide::Left),
+            (false, true) => Some(PlayerSide::Right),
+            (true, true) => {
+                let left = (self.left.completed_rows.len(), self.left.completion_timing);
+                let right = (self.right.completed_rows.len(), self.right.completion_timing);
+                match left.cmp(&right) {
+                    Ordering::Less => Some(PlayerSide::Left),
+                    Ordering::Greater => Some(PlayerSide::Right),
+                    Ordering::Equal => None,
+                }
+            },
+        }
+    }
+
+    /// Points `me` earned as code-maker, for the password my opponent had to guess.
+    pub fn points(&self, me: PlayerSide) -> u32 {
+        self.op_board(me).code_maker_points()
+    }
+}
+
+/// A player's cumulative score over the rounds of a match.
+#[derive(Debug, Default, PartialEq)]
+pub struct MatchScore {
+    pub points: u32,
+    pub round_wins: u32,
+}
+
+impl MatchScore {
+    pub fn new(rounds: &[&DoneData], me: PlayerSide) -> Self {
+        MatchScore {
+            points: rounds.iter().map(|round| round.points(me)).sum(),
+            round_wins: rounds.iter().filter(|round| round.winner() == Some(me)).count() as u32,
+        }
+    }
+
+    /// The most points wins, then the most round wins. `None` if the match is a draw.
+    pub fn winner(rounds: &[&DoneData]) -> Option<PlayerSide> {
+        let left = MatchScore::new(rounds, PlayerSide::Left);
+        let right = MatchScore::new(rounds, PlayerSide::Right);
+        match (left.points, left.round_wins).cmp(&(right.points, right.round_wins)) {
+            Ordering::Greater => Some(PlayerSide::Left),
+            Ordering::Less => Some(PlayerSide::Right),
+            Ordering::Equal => None,
+        }
+    }
 }
 
 mod type_converters {
@@ -1,24 +1,55 @@
 mod transitions;
 pub(crate) mod data;
 
-use crate::types::PlayerSide;
+pub(crate) use crate::state_machine::transitions::compare;
+
+use crate::options::MastermindOptions;
+use crate::types::{PlayerSide, Players, IllegalMove, ActiveBoard, CompletedBoard};
 use crate::events::MastermindEventType;
 use crate::state_machine::data::{PregameData, ActiveData, LActiveRDoneData, LDoneRActiveData, DoneData};
+use backend_framework::data_stream::PlayerDataStreams;
+use backend_framework::wire_api::proto_frj_ngn::ProtoMastermindDataOut;
+use serde::{Deserialize, Serialize};
 
 // --------------------- State ---------------------
 
+#[derive(Serialize, Deserialize)]
 pub enum BoardState {
     Pregame(PregameData),
     Active(ActiveData),
     LActiveRDone(LActiveRDoneData),
     LDoneRActive(LDoneRActiveData),
     Done(DoneData),
+
+    // Single player and cooperative games, where the server picked the password.
+    SharedActive(ActiveBoard),
+    SharedDone(CompletedBoard),
+}
+
+/// A rejected event leaves the state as it was. It's boxed, since rejections are rare and the
+/// state is large.
+pub struct RejectedTransition {
+    pub state: Box<BoardState>,
+    pub reason: IllegalMove,
+}
+
+pub type TransitionResult = Result<BoardState, RejectedTransition>;
+
+fn reject(state: BoardState, reason: IllegalMove) -> TransitionResult {
+    Err(RejectedTransition {
+        state: Box::new(state),
+        reason,
+    })
 }
 
 // --------------------- State Machine ---------------------
 
 struct MastermindStateMachineImpl {
-    // nothing
+    players: Players,
+    streams: PlayerDataStreams<ProtoMastermindDataOut>,
+    options: MastermindOptions,
+    // Head => first round. Doesn't include the current round.
+    completed_rounds: Vec<DoneData>,
 }
 
 pub struct MastermindStateMachine {
@@ -26,36 +57,72 @@ pub struct MastermindStateMachine {
 }
 
 impl MastermindStateMachine {
-    pub fn new() -> Self {
+    pub fn new(players: Players, options: MastermindOptions, completed_rounds: Vec<DoneData>) -> Self {
+        let streams = PlayerDataStreams::new(players.as_vec.clone());
+
         MastermindStateMachine {
-            inner: MastermindStateMachineImpl {}
+            inner: MastermindStateMachineImpl {
+                players,
+                streams,
+                options,
+                completed_rounds,
+            }
         }
     }
 
+    pub fn players(&self) -> &Players {
+        &self.inner.players
+    }
+
+    pub fn options(&self) -> &MastermindOptions {
+        &self.inner.options
+    }
+
+    pub fn completed_rounds(&self) -> &Vec<DoneData> {
+        &self.inner.completed_rounds
+    }
+
+    /// Game state is only sent to players if the event is accepted. A rejected event should be
+    /// reported back to the player with `send_illegal_move()`.
     pub fn handle_transition(
-        &self,
+        &mut self,
         mut from_state: BoardState,
+        player_id: &String,
         player: PlayerSide,
         event: MastermindEventType,
-    ) -> BoardState {
+    ) -> TransitionResult {
         match event {
-            MastermindEventType::RegisterDataStream(_) => {
-                unimplemented!("MastermindEventType::RegisterDataStream")
+            MastermindEventType::RegisterDataStream(stream_out) => {
+                self.inner.streams.add_stream(player_id.clone(), stream_out);
+                self.inner.send_game_state(&from_state, player_id, player);
+                Ok(from_state)
             },
             MastermindEventType::GetGameState => {
-                self.inner.send_game_state(&from_state, player);
-                from_state
+                self.inner.send_game_state(&from_state, player_id, player);
+                Ok(from_state)
             },
             MastermindEventType::SubmitPassword => {
-                self.inner.submit_password(from_state, player)
+                let to_state = self.inner.submit_password(from_state, player)?;
+                self.inner.send_game_state_to_all(&to_state);
+                Ok(to_state)
             }
             MastermindEventType::PutPeg { peg, color } => {
-                self.inner.put_peg(&mut from_state, player, peg, color);
-                from_state
+                if let Err(reason) = self.inner.put_peg(&mut from_state, player, peg, color) {
+                    return reject(from_state, reason);
+                }
+                self.inner.send_game_state_to_all(&from_state);
+                Ok(from_state)
             },
             MastermindEventType::CommitRow => {
-                self.inner.commit_row(from_state, player)
+                let to_state = self.inner.commit_row(from_state, player)?;
+                let to_state = self.inner.start_next_round_if_done(to_state);
+                self.inner.send_game_state_to_all(&to_state);
+                Ok(to_state)
             },
         }
     }
+
+    pub fn send_illegal_move(&self, player_id: &String, reason: IllegalMove) {
+        self.inner.streams.send_err(player_id, reason.into_status());
+    }
 }
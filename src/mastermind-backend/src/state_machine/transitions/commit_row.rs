@@ -1,93 +1,104 @@
-use crate::state_machine::{MastermindStateMachineImpl, BoardState};
-use crate::types::{PlayerSide, CompletedBoard, ActiveBoard, Row, ResultPegs, Color};
+use crate::state_machine::{MastermindStateMachineImpl, BoardState, TransitionResult, reject};
+use crate::types::{PlayerSide, CompletedBoard, ActiveBoard, Row, ResultPegs, Color, IllegalMove};
 use crate::state_machine::data::{LDoneRActiveData, LActiveRDoneData, DoneData};
 use std::collections::HashMap;
 
 impl MastermindStateMachineImpl {
-    pub fn commit_row(&self, from_state: BoardState, player: PlayerSide) -> BoardState {
+    pub fn commit_row(&self, from_state: BoardState, player: PlayerSide) -> TransitionResult {
         match from_state {
             BoardState::Active(mut data) => {
-                let mut my_board = data.my_board_mut(player);
-                if my_board.current_guess.is_complete() {
-                    // TODO:1.5 notify caller
-                    return BoardState::Active(data);
+                let my_board = data.my_board_mut(player);
+                if !my_board.current_guess.is_complete(self.options.allow_blanks) {
+                    return reject(BoardState::Active(data), IllegalMove::RowIncomplete);
                 }
 
-                let is_board_done = commit_active_board(&mut my_board);
+                let is_board_done = commit_active_board(my_board, self.options.max_rows);
 
                 match (player, is_board_done) {
                     (PlayerSide::Left, true) => {
-                        BoardState::LDoneRActive(LDoneRActiveData {
+                        Ok(BoardState::LDoneRActive(LDoneRActiveData {
                             left: CompletedBoard::from(data.left),
                             right: data.right,
-                        })
+                        }))
                     },
                     (PlayerSide::Right, true) => {
-                        BoardState::LActiveRDone(LActiveRDoneData {
+                        Ok(BoardState::LActiveRDone(LActiveRDoneData {
                             left: data.left,
                             right: CompletedBoard::from(data.right),
-                        })
+                        }))
                     }
                     (_, false) => {
-                        BoardState::Active(data)
+                        Ok(BoardState::Active(data))
                     }
                 }
             },
             BoardState::LActiveRDone(mut data) => {
                 if player == PlayerSide::Right {
-                    // TODO:1.5 notify caller already done
-                    return BoardState::LActiveRDone(data);
+                    let illegal_move = data.right.illegal_move();
+                    return reject(BoardState::LActiveRDone(data), illegal_move);
                 }
 
-                if !data.left.current_guess.is_complete() {
-                    // TODO:1.5 notify caller
-                    return BoardState::LActiveRDone(data);
+                if !data.left.current_guess.is_complete(self.options.allow_blanks) {
+                    return reject(BoardState::LActiveRDone(data), IllegalMove::RowIncomplete);
                 }
 
-                let is_board_done = commit_active_board(&mut data.left);
+                let is_board_done = commit_active_board(&mut data.left, self.options.max_rows);
                 if !is_board_done {
-                    BoardState::LActiveRDone(data)
+                    Ok(BoardState::LActiveRDone(data))
                 } else {
-                    BoardState::Done(DoneData {
+                    Ok(BoardState::Done(DoneData {
                         left: CompletedBoard::from(data.left),
                         right: data.right,
-                    })
+                    }))
                 }
             },
             BoardState::LDoneRActive(mut data) => {
                 if player == PlayerSide::Left {
-                    // TODO:1.5 notify caller already done
-                    return BoardState::LDoneRActive(data);
+                    let illegal_move = data.left.illegal_move();
+                    return reject(BoardState::LDoneRActive(data), illegal_move);
                 }
 
-                if !data.right.current_guess.is_complete() {
-                    // TODO:1.5 notify caller
-                    return BoardState::LDoneRActive(data);
+                if !data.right.current_guess.is_complete(self.options.allow_blanks) {
+                    return reject(BoardState::LDoneRActive(data), IllegalMove::RowIncomplete);
                 }
 
-                let is_board_done = commit_active_board(&mut data.right);
+                let is_board_done = commit_active_board(&mut data.right, self.options.max_rows);
                 if !is_board_done {
-                    BoardState::LDoneRActive(data)
+                    Ok(BoardState::LDoneRActive(data))
                 } else {
-                    BoardState::Done(DoneData {
+                    Ok(BoardState::Done(DoneData {
                         left: data.left,
                         right: CompletedBoard::from(data.right)
-                    })
+                    }))
                 }
             },
-            _ => {
-                // TODO:1.5 notify caller unhappy case
-                from_state
+            BoardState::SharedActive(mut board) => {
+                if !board.current_guess.is_complete(self.options.allow_blanks) {
+                    return reject(BoardState::SharedActive(board), IllegalMove::RowIncomplete);
+                }
+
+                if commit_active_board(&mut board, self.options.max_rows) {
+                    Ok(BoardState::SharedDone(CompletedBoard::from(board)))
+                } else {
+                    Ok(BoardState::SharedActive(board))
+                }
+            },
+            from_state @ BoardState::Pregame(_) => reject(from_state, IllegalMove::NotGuessing),
+            from_state @ BoardState::Done(_) | from_state @ BoardState::SharedDone(_) => {
+                reject(from_state, IllegalMove::GameComplete)
             },
         }
     }
 }
 
-fn commit_active_board(board: &mut ActiveBoard) -> bool {
+/// Returns true if the board is done, either because the password was guessed or because the
+/// board is out of rows.
+fn commit_active_board(board: &mut ActiveBoard, max_rows: u8) -> bool {
     let result_pegs = compare(&board.current_guess, &board.password_to_guess);
-    let is_board_done = result_pegs.correct == board.password_to_guess.len() as u8;
+    let is_solved = result_pegs.correct == board.password_to_guess.len() as u8;
     board.completed_rows.push((board.current_guess.clone(), result_pegs));
-    board.current_guess = Row::new(8); // TODO:1.5 param
+    let is_board_done = is_solved || board.completed_rows.len() >= max_rows as usize;
+    board.current_guess = Row::new(board.password_to_guess.len(), board.password_to_guess.max_color());
     is_board_done
 }
 
@@ -96,8 +107,10 @@ fn commit_active_board(board: &mut ActiveBoard) -> bool {
 ///
 /// For `c` color choices and `p` pegs in a row, this algo is `O(p)`.
 ///
+/// Blanks are `NO_COLOR` pegs, so they're scored like any other color.
+///
 /// `bool` in return is if password was guessed correctly.
-fn compare(guess: &Row, password: &Row) -> ResultPegs {
+pub(crate) fn compare(guess: &Row, password: &Row) -> ResultPegs {
     assert_eq!(guess.len(), password.len());
 
     // `O(p)`, but could be pre-computed
@@ -134,7 +147,7 @@ fn compare(guess: &Row, password: &Row) -> ResultPegs {
     let mut correct_color_wrong_slot = 0;
 
     // `O(p)` - technically `O(c)`, but the `c` here is always bounded by `p`.
-    for (color, mut result) in password_colors.iter_mut() {
+    for (color, result) in password_colors.iter_mut() {
         let num_occurrences_in_password = password_colors_count.get(&color)
             .expect("UNREACHABLE BUG MOTHER FUCKER.");
 
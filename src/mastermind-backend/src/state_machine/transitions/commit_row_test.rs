@@ -0,0 +1,105 @@
+mod commit_row_tests {
+    use crate::options::{MAX_COLORS, MAX_PEGS, MIN_COLORS, MIN_PEGS};
+    use crate::state_machine::transitions::commit_row::compare;
+    use crate::types::{Color, ResultPegs, Row, NO_COLOR};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn row(pegs: &[Color], num_colors: u8) -> Row {
+        let mut row = Row::new(pegs.len(), num_colors);
+        for (i, color) in pegs.iter().enumerate() {
+            assert!(row.try_set(i, *color).is_ok(), "Peg {} color {} should be valid", i, color);
+        }
+        row
+    }
+
+    fn score(guess: &[Color], password: &[Color]) -> ResultPegs {
+        compare(&row(guess, MAX_COLORS), &row(password, MAX_COLORS))
+    }
+
+    /// The textbook definition: pegs in the right slot, plus pegs that would be in the right slot
+    /// after rearranging the rest of the guess.
+    fn reference_score(guess: &[Color], password: &[Color]) -> ResultPegs {
+        let correct = guess.iter()
+            .zip(password.iter())
+            .filter(|(g, p)| g == p)
+            .count() as u8;
+        let color_matches: usize = (NO_COLOR..=MAX_COLORS)
+            .map(|color| {
+                let in_guess = guess.iter().filter(|peg| **peg == color).count();
+                let in_password = password.iter().filter(|peg| **peg == color).count();
+                in_guess.min(in_password)
+            })
+            .sum();
+
+        ResultPegs {
+            correct,
+            correct_color_wrong_slot: color_matches as u8 - correct,
+        }
+    }
+
+    /// A password and a guess for a random board, following random rules. Guesses can always
+    /// contain duplicates.
+    fn password_and_guess() -> impl Strategy<Value = (Vec<Color>, Vec<Color>)> {
+        (MIN_PEGS..=MAX_PEGS, MIN_COLORS..=MAX_COLORS, any::<bool>(), any::<bool>())
+            .prop_filter(
+                "Without duplicates, a password needs at least as many colors as pegs",
+                |(num_pegs, num_colors, allow_duplicate_colors, allow_blanks)| {
+                    *allow_duplicate_colors || num_colors + *allow_blanks as u8 >= *num_pegs
+                },
+            )
+            .prop_flat_map(|(num_pegs, num_colors, allow_duplicate_colors, allow_blanks)| {
+                let num_pegs = num_pegs as usize;
+                let first_color = if allow_blanks { NO_COLOR } else { 1 };
+                let password = if allow_duplicate_colors {
+                    vec(first_color..=num_colors, num_pegs).boxed()
+                } else {
+                    Just((first_color..=num_colors).collect::<Vec<Color>>())
+                        .prop_shuffle()
+                        .prop_map(move |colors| colors[..num_pegs].to_vec())
+                        .boxed()
+                };
+                (password, vec(first_color..=num_colors, num_pegs))
+            })
+    }
+
+    #[test]
+    fn duplicates_in_guess_are_only_counted_once_per_password_peg() {
+        assert_eq!(ResultPegs { correct: 1, correct_color_wrong_slot: 2 }, score(&[1, 2, 1, 1], &[1, 1, 2, 2]));
+        assert_eq!(ResultPegs { correct: 0, correct_color_wrong_slot: 2 }, score(&[2, 2, 2, 3], &[1, 1, 3, 2]));
+    }
+
+    #[test]
+    fn blanks_are_scored_like_a_color() {
+        assert_eq!(ResultPegs { correct: 1, correct_color_wrong_slot: 1 }, score(&[0, 0, 2], &[0, 1, 0]));
+        assert_eq!(ResultPegs { correct: 0, correct_color_wrong_slot: 0 }, score(&[0, 0, 0], &[1, 2, 3]));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_reference_scoring((password, guess) in password_and_guess()) {
+            prop_assert_eq!(reference_score(&guess, &password), score(&guess, &password));
+        }
+
+        #[test]
+        fn is_bounded_by_the_number_of_pegs((password, guess) in password_and_guess()) {
+            let result = score(&guess, &password);
+            prop_assert!((result.correct + result.correct_color_wrong_slot) as usize <= password.len());
+            prop_assert_eq!(guess == password, result.correct as usize == password.len());
+        }
+
+        #[test]
+        fn is_symmetric((password, guess) in password_and_guess()) {
+            prop_assert_eq!(score(&guess, &password), score(&password, &guess));
+        }
+
+        #[test]
+        fn password_guessed_with_its_own_colors_scores_every_peg((password, _guess) in password_and_guess()) {
+            let mut reversed = password.clone();
+            reversed.reverse();
+
+            let result = score(&reversed, &password);
+            prop_assert_eq!(password.len(), (result.correct + result.correct_color_wrong_slot) as usize);
+        }
+    }
+}
@@ -1,4 +1,9 @@
 mod commit_row;
+#[cfg(test)]
+mod commit_row_test;
+mod next_round;
 mod put_peg;
 mod submit_password;
 mod send_game_state;
+
+pub(crate) use commit_row::compare;
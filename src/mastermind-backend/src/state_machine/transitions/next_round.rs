@@ -0,0 +1,30 @@
+use crate::state_machine::{MastermindStateMachineImpl, BoardState};
+use crate::state_machine::data::{DoneData, PregameData};
+
+impl MastermindStateMachineImpl {
+    /// Once both players are done guessing, the round is recorded and the next round starts with
+    /// new passwords. The last round stays `Done`.
+    pub fn start_next_round_if_done(&mut self, state: BoardState) -> BoardState {
+        match state {
+            BoardState::Done(data) if self.current_round() < self.options.num_rounds => {
+                self.completed_rounds.push(data);
+                BoardState::Pregame(PregameData::new(&self.options))
+            },
+            state => state,
+        }
+    }
+
+    /// Starts at 1.
+    pub fn current_round(&self) -> u8 {
+        self.completed_rounds.len() as u8 + 1
+    }
+
+    /// Head => first round. Includes the current round if it's complete.
+    pub fn round_results<'a>(&'a self, state: &'a BoardState) -> Vec<&'a DoneData> {
+        let mut round_results: Vec<&DoneData> = self.completed_rounds.iter().collect();
+        if let BoardState::Done(data) = state {
+            round_results.push(data);
+        }
+        round_results
+    }
+}
@@ -1,57 +1,41 @@
 use crate::state_machine::{MastermindStateMachineImpl, BoardState};
-use crate::types::{PlayerSide, Color};
+use crate::types::{PlayerSide, Color, IllegalMove};
 
 impl MastermindStateMachineImpl {
+    /// Sets the peg in place, since there's no state change.
     pub fn put_peg(
         &self,
-        from_state: &mut BoardState,
+        state: &mut BoardState,
         player: PlayerSide,
         peg: usize,
         color: Color,
-    ) {
-        // happy case only
-        match from_state {
+    ) -> Result<(), IllegalMove> {
+        match state {
             BoardState::Pregame(ref mut data) => {
                 let my_board = data.my_board_mut(player);
-                if let Err(_e) = my_board.sparse_password.try_set(peg, color) {
-                    // TODO:1.5 notify caller of error
-                } else {
-                    my_board.ready = false;
-                }
+                my_board.sparse_password.try_set(peg, color)?;
+                // Changing the password un-submits it.
+                my_board.ready = false;
+                Ok(())
             },
             BoardState::Active(ref mut data) => {
-                let my_board = data.my_board_mut(player);
-                if let Err(_e) = my_board.current_guess.try_set(peg, color) {
-                    // TODO:1.5 notify caller of error
-                }
+                data.my_board_mut(player).current_guess.try_set(peg, color)
             },
             BoardState::LActiveRDone(ref mut data) => {
                 match player {
-                    PlayerSide::Left => {
-                        if let Err(_e) = data.left.current_guess.try_set(peg, color) {
-                            // TODO:1.5 notify caller of error
-                        }
-                    },
-                    PlayerSide::Right => {
-                        // TODO:1.5 notify caller of not their turn
-                    },
+                    PlayerSide::Left => data.left.current_guess.try_set(peg, color),
+                    PlayerSide::Right => Err(data.right.illegal_move()),
                 }
             },
             BoardState::LDoneRActive(ref mut data) => {
                 match player {
-                    PlayerSide::Left => {
-                        // TODO:1.5 notify caller of not their turn
-                    },
-                    PlayerSide::Right => {
-                        if let Err(_e) = data.right.current_guess.try_set(peg, color) {
-                            // TODO:1.5 notify caller of error
-                        }
-                    },
+                    PlayerSide::Left => Err(data.left.illegal_move()),
+                    PlayerSide::Right => data.right.current_guess.try_set(peg, color),
                 }
             },
-            BoardState::Done(_) => {
-                // TODO:1.5 notify caller bad state
-            },
+            BoardState::Done(_) => Err(IllegalMove::GameComplete),
+            BoardState::SharedActive(ref mut board) => board.current_guess.try_set(peg, color),
+            BoardState::SharedDone(_) => Err(IllegalMove::GameComplete),
         }
     }
-}
\ No newline at end of file
+}
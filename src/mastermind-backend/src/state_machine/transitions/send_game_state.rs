@@ -1,12 +1,268 @@
 use crate::state_machine::{MastermindStateMachineImpl, BoardState};
-use crate::types::PlayerSide;
+use crate::state_machine::data::{DoneData, MatchScore};
+use crate::types::{PlayerSide, ActiveBoard, CompletedBoard, PreparingBoard, Row, ResultPegs};
+use backend_framework::wire_api::proto_frj_ngn::{ProtoMmGameState, ProtoMmBoard, ProtoMmRow, ProtoMmCompletedRow, ProtoMmRoundResult, ProtoMmPlayerRoundResult, ProtoMmMatchScore};
+use backend_framework::wire_api::proto_frj_ngn::proto_mm_game_state::ProtoMmStage;
 
 impl MastermindStateMachineImpl {
-    pub fn send_game_state(&self, _state: &BoardState, _player: PlayerSide) {
-        unimplemented!() // TODO:1.5 convert to proto model
+    pub fn send_game_state(&self, state: &BoardState, player_id: &String, player: PlayerSide) {
+        let proto_state = self.to_proto_game_state(state, player);
+        self.streams.send_msg(player_id, proto_state);
     }
 
-    pub fn send_game_state_to_all(&self, _state: &BoardState) {
-        unimplemented!() // TODO:1.5 convert to proto model
+    pub fn send_game_state_to_all(&self, state: &BoardState) {
+        for player_id in self.players.as_vec.iter() {
+            if let Some(player) = self.players.get_side(player_id) {
+                self.send_game_state(state, player_id, player);
+            }
+        }
     }
-}
\ No newline at end of file
+
+    fn to_proto_game_state(&self, state: &BoardState, me: PlayerSide) -> ProtoMmGameState {
+        let op = me.opponent();
+
+        let (stage, left, right) = match state {
+            BoardState::SharedActive(board) => {
+                return self.to_proto_shared_game_state(state, ProtoMmStage::Guessing, GuessingBoard::Active(board));
+            },
+            BoardState::SharedDone(board) => {
+                return self.to_proto_shared_game_state(state, ProtoMmStage::Complete, GuessingBoard::Completed(board));
+            },
+            BoardState::Pregame(data) => {
+                return self.to_proto_game_state_with_boards(
+                    state,
+                    ProtoMmStage::PasswordSelection,
+                    self.to_proto_preparing_board(me, data.my_board(me), true),
+                    Some(self.to_proto_preparing_board(op, data.my_board(op), false)),
+                );
+            },
+            BoardState::Active(data) => (
+                ProtoMmStage::Guessing,
+                GuessingBoard::Active(&data.left),
+                GuessingBoard::Active(&data.right),
+            ),
+            BoardState::LActiveRDone(data) => (
+                ProtoMmStage::Guessing,
+                GuessingBoard::Active(&data.left),
+                GuessingBoard::Completed(&data.right),
+            ),
+            BoardState::LDoneRActive(data) => (
+                ProtoMmStage::Guessing,
+                GuessingBoard::Completed(&data.left),
+                GuessingBoard::Active(&data.right),
+            ),
+            BoardState::Done(data) => (
+                ProtoMmStage::Complete,
+                GuessingBoard::Completed(&data.left),
+                GuessingBoard::Completed(&data.right),
+            ),
+        };
+
+        let (my_guessing_board, op_guessing_board) = match me {
+            PlayerSide::Left => (left, right),
+            PlayerSide::Right => (right, left),
+        };
+
+        // I always know the password I created. I only know my opponent's password once I'm done
+        // guessing it.
+        let reveal_op_password = my_guessing_board.completed_board().is_some();
+
+        self.to_proto_game_state_with_boards(
+            state,
+            stage,
+            self.to_proto_guessing_board(
+                self.players.get_id(me).clone(),
+                &my_guessing_board,
+                Some(op_guessing_board.password()),
+            ),
+            Some(self.to_proto_guessing_board(
+                self.players.get_id(op).clone(),
+                &op_guessing_board,
+                Some(my_guessing_board.password()).filter(|_| reveal_op_password),
+            )),
+        )
+    }
+
+    /// Everyone sees the same board. Nobody knows the password until the board is complete.
+    fn to_proto_shared_game_state(&self, state: &BoardState, stage: ProtoMmStage, board: GuessingBoard) -> ProtoMmGameState {
+        let is_complete = board.completed_board().is_some();
+
+        self.to_proto_game_state_with_boards(
+            state,
+            stage,
+            self.to_proto_guessing_board(String::new(), &board, Some(board.password()).filter(|_| is_complete)),
+            None,
+        )
+    }
+
+    fn to_proto_game_state_with_boards(
+        &self,
+        state: &BoardState,
+        stage: ProtoMmStage,
+        my_board: ProtoMmBoard,
+        opponent_board: Option<ProtoMmBoard>,
+    ) -> ProtoMmGameState {
+        let round_results = self.round_results(state);
+        let is_match_complete = stage == ProtoMmStage::Complete;
+        // Shared boards have no opponent to score against.
+        let scored_players: &[PlayerSide] = if self.players.shared_board {
+            &[]
+        } else {
+            &[PlayerSide::Left, PlayerSide::Right]
+        };
+
+        ProtoMmGameState {
+            stage: stage as i32,
+            num_colors: self.options.num_colors as u32,
+            num_pegs: self.options.num_pegs as u32,
+            no_duplicate_colors: !self.options.allow_duplicate_colors,
+            allow_blanks: self.options.allow_blanks,
+            max_rows: self.options.max_rows as u32,
+            num_rounds: self.options.num_rounds as u32,
+            my_board: Some(my_board),
+            opponent_board,
+            presence: self.streams.presence(),
+            round: self.current_round() as u32,
+            round_results: round_results
+                .iter()
+                .map(|round| self.to_proto_round_result(round))
+                .collect(),
+            match_scores: scored_players
+                .iter()
+                .map(|player| self.to_proto_match_score(*player, &round_results))
+                .collect(),
+            match_winner_player_id: MatchScore::winner(&round_results)
+                .filter(|_| is_match_complete)
+                .map(|player| self.players.get_id(player).clone())
+                .unwrap_or_default(),
+            shared_board: self.players.shared_board,
+        }
+    }
+
+    fn to_proto_round_result(&self, round: &DoneData) -> ProtoMmRoundResult {
+        ProtoMmRoundResult {
+            winner_player_id: round.winner()
+                .map(|player| self.players.get_id(player).clone())
+                .unwrap_or_default(),
+            players: [PlayerSide::Left, PlayerSide::Right]
+                .iter()
+                .map(|player| {
+                    let board = round.my_board(*player);
+                    ProtoMmPlayerRoundResult {
+                        player_id: self.players.get_id(*player).clone(),
+                        rows_used: board.completed_rows.len() as u32,
+                        solved: board.is_solved(),
+                        solve_duration_millis: solve_duration_millis(board),
+                        points: round.points(*player),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn to_proto_match_score(&self, player: PlayerSide, round_results: &[&DoneData]) -> ProtoMmMatchScore {
+        let match_score = MatchScore::new(round_results, player);
+        ProtoMmMatchScore {
+            player_id: self.players.get_id(player).clone(),
+            points: match_score.points,
+            round_wins: match_score.round_wins,
+        }
+    }
+
+    fn to_proto_preparing_board(&self, player: PlayerSide, board: &PreparingBoard, reveal_password: bool) -> ProtoMmBoard {
+        ProtoMmBoard {
+            player_id: self.players.get_id(player).clone(),
+            password: Some(board.sparse_password.clone()).filter(|_| reveal_password).map(into_proto_row),
+            password_submitted: board.ready,
+            completed_rows: Vec::new(),
+            current_guess: None,
+            solved: false,
+            solve_duration_millis: 0,
+            failed: false,
+        }
+    }
+
+    /// `board` is the board that `player_id` is guessing on. `password` is the password shown with
+    /// the board, i.e. the password the player created for their opponent, if it's revealed.
+    fn to_proto_guessing_board(
+        &self,
+        player_id: String,
+        board: &GuessingBoard,
+        password: Option<&Row>,
+    ) -> ProtoMmBoard {
+        let completed_board = board.completed_board();
+
+        ProtoMmBoard {
+            player_id,
+            password: password.cloned().map(into_proto_row),
+            password_submitted: true,
+            completed_rows: board.completed_rows()
+                .iter()
+                .map(|(row, result_pegs)| into_proto_completed_row(row, result_pegs))
+                .collect(),
+            current_guess: board.current_guess().cloned().map(into_proto_row),
+            solved: completed_board.map(CompletedBoard::is_solved).unwrap_or(false),
+            solve_duration_millis: completed_board.map(solve_duration_millis).unwrap_or(0),
+            failed: completed_board.map(|board| !board.is_solved()).unwrap_or(false),
+        }
+    }
+}
+
+/// A board which is past the password selection phase.
+enum GuessingBoard<'a> {
+    Active(&'a ActiveBoard),
+    Completed(&'a CompletedBoard),
+}
+
+impl GuessingBoard<'_> {
+    fn completed_rows(&self) -> &Vec<(Row, ResultPegs)> {
+        match self {
+            GuessingBoard::Active(board) => &board.completed_rows,
+            GuessingBoard::Completed(board) => &board.completed_rows,
+        }
+    }
+
+    fn current_guess(&self) -> Option<&Row> {
+        match self {
+            GuessingBoard::Active(board) => Some(&board.current_guess),
+            GuessingBoard::Completed(_) => None,
+        }
+    }
+
+    fn password(&self) -> &Row {
+        match self {
+            GuessingBoard::Active(board) => &board.password_to_guess,
+            GuessingBoard::Completed(board) => &board.password,
+        }
+    }
+
+    fn completed_board(&self) -> Option<&CompletedBoard> {
+        match self {
+            GuessingBoard::Active(_) => None,
+            GuessingBoard::Completed(board) => Some(board),
+        }
+    }
+}
+
+/// 0 unless the board was solved.
+fn solve_duration_millis(board: &CompletedBoard) -> u64 {
+    if board.is_solved() {
+        board.completion_timing.as_millis() as u64
+    } else {
+        0
+    }
+}
+
+fn into_proto_row(row: Row) -> ProtoMmRow {
+    ProtoMmRow {
+        pegs: (0..row.len()).map(|i| row.peg(i) as u32).collect(),
+    }
+}
+
+fn into_proto_completed_row(row: &Row, result_pegs: &ResultPegs) -> ProtoMmCompletedRow {
+    ProtoMmCompletedRow {
+        guess: Some(into_proto_row(row.clone())),
+        correct: result_pegs.correct as u32,
+        correct_color_wrong_slot: result_pegs.correct_color_wrong_slot as u32,
+    }
+}
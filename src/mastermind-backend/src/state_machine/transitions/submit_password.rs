@@ -1,31 +1,40 @@
-use crate::state_machine::{MastermindStateMachineImpl, BoardState};
-use crate::state_machine::data::{PregameData, ActiveData};
-use crate::types::PlayerSide;
+use crate::state_machine::{MastermindStateMachineImpl, BoardState, TransitionResult, reject};
+use crate::options::MastermindOptions;
+use crate::state_machine::data::ActiveData;
+use crate::types::{PlayerSide, PreparingBoard, IllegalMove};
 
 impl MastermindStateMachineImpl {
-    pub fn submit_password(&self, from_state: BoardState, player: PlayerSide) -> BoardState {
-        // Happy case
-        if let BoardState::Pregame(mut data) = from_state {
-            if set_my_ready(&mut data, player) && data.op_board(player).ready {
-                BoardState::Active(ActiveData::from(data))
-            } else {
-                BoardState::Pregame(data)
-            }
+    pub fn submit_password(&self, from_state: BoardState, player: PlayerSide) -> TransitionResult {
+        let mut data = match from_state {
+            BoardState::Pregame(data) => data,
+            from_state @ BoardState::Done(_) => return reject(from_state, IllegalMove::GameComplete),
+            from_state @ BoardState::SharedActive(_) | from_state @ BoardState::SharedDone(_) => {
+                return reject(from_state, IllegalMove::NoPasswordSelection);
+            },
+            from_state => return reject(from_state, IllegalMove::PasswordSelectionOver),
+        };
+
+        if let Err(reason) = validate_password(data.my_board(player), &self.options) {
+            return reject(BoardState::Pregame(data), reason);
+        }
+
+        data.my_board_mut(player).ready = true;
+        if data.op_board(player).ready {
+            Ok(BoardState::Active(ActiveData::from(data)))
         } else {
-            // TODO:1.5 notify caller bad state
-            from_state
+            Ok(BoardState::Pregame(data))
         }
     }
 }
 
-/// return true if we successfully ready-ed up.
-fn set_my_ready(data: &mut PregameData, player: PlayerSide) -> bool {
-    let my_board = data.my_board_mut(player);
-    if !my_board.sparse_password.is_complete() {
-        // TODO:1.5 notify player of error
-        return false;
+fn validate_password(my_board: &PreparingBoard, options: &MastermindOptions) -> Result<(), IllegalMove> {
+    if !my_board.sparse_password.is_complete(options.allow_blanks) {
+        return Err(IllegalMove::RowIncomplete);
+    }
+
+    if !options.allow_duplicate_colors && my_board.sparse_password.has_duplicate_colors() {
+        return Err(IllegalMove::DuplicateColors);
     }
 
-    my_board.ready = true;
-    true
+    Ok(())
 }
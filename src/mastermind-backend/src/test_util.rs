@@ -0,0 +1,30 @@
+use crate::events::{MastermindEvent, MastermindEventType};
+use backend_framework::common_types::ClientInfo;
+use backend_framework::streaming::StreamReceiver;
+use backend_framework::wire_api::proto_frj_ngn::{ProtoMastermindDataOut, ProtoMmGameState};
+use backend_framework::wire_api::proto_frj_ngn::proto_mastermind_data_out::ProtoMmOut;
+use tokio::stream::StreamExt;
+
+pub(crate) fn client_info(player_id: &str) -> ClientInfo {
+    ClientInfo {
+        player_id: player_id.to_string(),
+        game_id: "game".to_string(),
+    }
+}
+
+pub(crate) fn event(player_id: &str, payload: MastermindEventType) -> MastermindEvent {
+    MastermindEvent {
+        client_info: client_info(player_id),
+        payload,
+    }
+}
+
+pub(crate) async fn recv_game_state(stream_rcv: &mut StreamReceiver<ProtoMastermindDataOut>) -> ProtoMmGameState {
+    let message = stream_rcv.next().await
+        .expect("Stream should have a message")
+        .expect("Message should not be an error");
+    match message.proto_mm_out {
+        Some(ProtoMmOut::GameState(game_state)) => game_state,
+        None => panic!("Expected game state, got empty message"),
+    }
+}
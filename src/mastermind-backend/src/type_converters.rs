@@ -0,0 +1,49 @@
+use crate::types::IllegalMove;
+use backend_framework::wire_api::proto_frj_ngn::ProtoMmIllegalMove;
+use backend_framework::wire_api::proto_frj_ngn::proto_mm_illegal_move::ProtoMmIllegalMoveReason;
+use prost::Message;
+use tonic::{Code, Status};
+
+impl From<IllegalMove> for ProtoMmIllegalMoveReason {
+    fn from(illegal_move: IllegalMove) -> Self {
+        match illegal_move {
+            IllegalMove::PegOutOfBounds => ProtoMmIllegalMoveReason::PegOutOfBounds,
+            IllegalMove::ColorOutOfBounds => ProtoMmIllegalMoveReason::ColorOutOfBounds,
+            IllegalMove::RowIncomplete => ProtoMmIllegalMoveReason::RowIncomplete,
+            IllegalMove::DuplicateColors => ProtoMmIllegalMoveReason::DuplicateColors,
+            IllegalMove::NotGuessing => ProtoMmIllegalMoveReason::NotGuessing,
+            IllegalMove::PasswordSelectionOver => ProtoMmIllegalMoveReason::PasswordSelectionOver,
+            IllegalMove::AlreadySolved => ProtoMmIllegalMoveReason::AlreadySolved,
+            IllegalMove::OutOfRows => ProtoMmIllegalMoveReason::OutOfRows,
+            IllegalMove::GameComplete => ProtoMmIllegalMoveReason::GameComplete,
+            IllegalMove::NoPasswordSelection => ProtoMmIllegalMoveReason::NoPasswordSelection,
+        }
+    }
+}
+
+impl IllegalMove {
+    /// Out of bounds input is `INVALID_ARGUMENT`, anything else is `FAILED_PRECONDITION`. The
+    /// status details are a `ProtoMmIllegalMove`.
+    pub fn into_status(self) -> Status {
+        let (code, message) = match self {
+            IllegalMove::PegOutOfBounds => (Code::InvalidArgument, "Peg is out of bounds"),
+            IllegalMove::ColorOutOfBounds => (Code::InvalidArgument, "Color is out of bounds"),
+            IllegalMove::RowIncomplete => (Code::FailedPrecondition, "Every peg must be set"),
+            IllegalMove::DuplicateColors => (Code::FailedPrecondition, "The password can't use a color more than once"),
+            IllegalMove::NotGuessing => (Code::FailedPrecondition, "Can't guess until both passwords are submitted"),
+            IllegalMove::PasswordSelectionOver => (Code::FailedPrecondition, "Can't change the password after guessing has started"),
+            IllegalMove::AlreadySolved => (Code::FailedPrecondition, "You already guessed the password"),
+            IllegalMove::OutOfRows => (Code::FailedPrecondition, "You're out of guesses"),
+            IllegalMove::GameComplete => (Code::FailedPrecondition, "The game is complete"),
+            IllegalMove::NoPasswordSelection => (Code::FailedPrecondition, "The server picks the password when the board is shared"),
+        };
+
+        let details = ProtoMmIllegalMove {
+            reason: ProtoMmIllegalMoveReason::from(self) as i32,
+        };
+        let mut encoded_details = Vec::with_capacity(details.encoded_len());
+        details.encode(&mut encoded_details).expect("Vec has enough capacity");
+
+        Status::with_details(code, message, encoded_details.into())
+    }
+}
@@ -1,93 +1,130 @@
+use crate::options::MastermindOptions;
+use backend_framework::prng::PrngRand;
+use backend_framework::shuffler;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::time::{Instant, Duration};
 
 /// Allow variable-number of active colors in a game, up to 256.
-/// Reserve 0x00 for Option::None.
+/// Reserve 0x00 for Option::None, which is also a blank when the game allows blanks.
 pub type Color = u8;
 pub const NO_COLOR: u8 = 0;
-pub const NUM_PEGS_PER_ROW: usize = 4; // TODO:2 variable peg size
 
 // ------------- struct -------------
 
-#[derive(Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ResultPegs {
     pub correct: u8,
     pub correct_color_wrong_slot: u8,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Row {
-    pegs: [Color; NUM_PEGS_PER_ROW],
+    // Was a fixed size array of 4, which serializes the same as a `Vec`.
+    pegs: Vec<Color>,
     // TODO:2 move out
     max_color: Color,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct PreparingBoard {
     // Will potentially have pegs set to `None`
     pub sparse_password: Row,
     pub ready: bool,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct ActiveBoard {
     // Head => first guess
     // Tail => recent guess
     pub completed_rows: Vec<(Row, ResultPegs)>,
     pub current_guess: Row,
     pub password_to_guess: Row,
+    #[serde(with = "elapsed_since")]
     start_time: Instant,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct CompletedBoard {
     // Head => first guess
-    // Tail => correct guess
-    completed_rows: Vec<(Row, ResultPegs)>,
-    completion_timing: Duration,
+    // Tail => correct guess, or the last allowed guess if the board failed
+    pub completed_rows: Vec<(Row, ResultPegs)>,
+    pub password: Row,
+    pub completion_timing: Duration,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct Players {
-    pub ids: (String, String),
+    // Left then right, unless the board is shared.
     pub as_vec: Vec<String>,
+    /// Every player guesses on the same board, so they're all on the left side.
+    #[serde(default)]
+    pub shared_board: bool,
 }
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PlayerSide {
     Left,
     Right,
 }
 
-pub enum AppError {
-    InvalidInput( /* message */ &'static str),
+/// Why a move was rejected. Sent to the player along with an error message, so clients can
+/// react without parsing the message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IllegalMove {
+    PegOutOfBounds,
+    ColorOutOfBounds,
+    /// A peg is unset, and the game doesn't allow blanks.
+    RowIncomplete,
+    /// The password uses a color more than once, and the game doesn't allow duplicates.
+    DuplicateColors,
+    /// Passwords are still being selected, so there's nothing to guess yet.
+    NotGuessing,
+    /// Guessing has started, so passwords can no longer change.
+    PasswordSelectionOver,
+    /// The player already guessed their opponent's password.
+    AlreadySolved,
+    /// The player used every row without guessing the password.
+    OutOfRows,
+    GameComplete,
+    /// The server picks the password in single player and cooperative games.
+    NoPasswordSelection,
 }
 
 // ------------- impl -------------
 
 impl Row {
-    pub fn new(num_colors: u8) -> Self {
+    pub fn new(num_pegs: usize, num_colors: u8) -> Self {
         Row {
-            pegs: [NO_COLOR, NO_COLOR, NO_COLOR, NO_COLOR],
+            pegs: vec![NO_COLOR; num_pegs],
             max_color: num_colors,
         }
     }
 
-    pub fn try_set(&mut self, peg: usize, color: Color) -> Result<(), AppError> {
-        if peg > NUM_PEGS_PER_ROW {
-            return Err(AppError::InvalidInput("Peg is out of bounds"));
+    pub fn try_set(&mut self, peg: usize, color: Color) -> Result<(), IllegalMove> {
+        if peg >= self.pegs.len() {
+            return Err(IllegalMove::PegOutOfBounds);
         }
 
         if color > self.max_color {
-            return Err(AppError::InvalidInput("Color is out of bounds"));
+            return Err(IllegalMove::ColorOutOfBounds);
         }
 
         self.pegs[peg] = color;
         Ok(())
     }
 
-    pub fn is_complete(&self) -> bool {
-        for peg in 0..NUM_PEGS_PER_ROW {
-            if self.pegs[peg] == NO_COLOR {
-                return false;
-            }
-        }
-        true
+    /// If blanks are allowed, every row is complete, since an unset peg is a blank.
+    pub fn is_complete(&self, allow_blanks: bool) -> bool {
+        allow_blanks || !self.pegs.contains(&NO_COLOR)
+    }
+
+    /// Blanks count as a color, so more than one blank is a duplicate.
+    pub fn has_duplicate_colors(&self) -> bool {
+        self.pegs
+            .iter()
+            .enumerate()
+            .any(|(i, color)| self.pegs[i + 1..].contains(color))
     }
 
     pub fn len(&self) -> usize {
@@ -97,12 +134,38 @@ impl Row {
     pub fn peg(&self, index: usize) -> Color {
         self.pegs[index]
     }
+
+    pub fn max_color(&self) -> Color {
+        self.max_color
+    }
+
+    /// A password following the game's rules, for when the server is the code-maker.
+    pub fn random_password(options: &MastermindOptions, rng: &mut PrngRand) -> Self {
+        let first_color = if options.allow_blanks { NO_COLOR } else { 1 };
+        let colors: Vec<Color> = (first_color..=options.num_colors).collect();
+        let num_pegs = options.num_pegs as usize;
+
+        let pegs = if options.allow_duplicate_colors {
+            (0..num_pegs)
+                .map(|_| colors[rng.gen_range(0, colors.len())])
+                .collect()
+        } else {
+            let mut colors = shuffler::shuffle_with(colors, rng);
+            colors.truncate(num_pegs);
+            colors
+        };
+
+        Row {
+            pegs,
+            max_color: options.num_colors,
+        }
+    }
 }
 
 impl PreparingBoard {
-    pub fn new(num_colors: u8) -> Self {
+    pub fn new(num_pegs: usize, num_colors: u8) -> Self {
         PreparingBoard {
-            sparse_password: Row::new(num_colors),
+            sparse_password: Row::new(num_pegs, num_colors),
             ready: false
         }
     }
@@ -112,7 +175,7 @@ impl ActiveBoard {
     pub fn new(password: Row) -> Self {
         ActiveBoard {
             completed_rows: Vec::new(),
-            current_guess: Row::new(password.max_color),
+            current_guess: Row::new(password.len(), password.max_color),
             password_to_guess: password,
             start_time: Instant::now(),
         }
@@ -120,18 +183,43 @@ impl ActiveBoard {
 }
 
 impl CompletedBoard {
-    pub fn new(completed_rows: Vec<(Row, ResultPegs)>, completion_timing: Duration) -> Self {
+    pub fn new(completed_rows: Vec<(Row, ResultPegs)>, password: Row, completion_timing: Duration) -> Self {
         CompletedBoard {
             completed_rows,
+            password,
             completion_timing,
         }
     }
+
+    /// False if the board ran out of rows.
+    pub fn is_solved(&self) -> bool {
+        match self.completed_rows.last() {
+            Some((_, result_pegs)) => result_pegs.correct as usize == self.password.len(),
+            None => false,
+        }
+    }
+
+    /// Why the player can't make any more moves on this board.
+    pub fn illegal_move(&self) -> IllegalMove {
+        if self.is_solved() {
+            IllegalMove::AlreadySolved
+        } else {
+            IllegalMove::OutOfRows
+        }
+    }
+
+    /// Official scoring, awarded to the code-maker: a point for each row used, and a bonus
+    /// point if the password wasn't guessed.
+    pub fn code_maker_points(&self) -> u32 {
+        self.completed_rows.len() as u32 + if self.is_solved() { 0 } else { 1 }
+    }
 }
 
 impl From<ActiveBoard> for CompletedBoard {
     fn from(active_board: ActiveBoard) -> Self {
         CompletedBoard::new(
             active_board.completed_rows,
+            active_board.password_to_guess,
             Instant::now().saturating_duration_since(active_board.start_time),
         )
     }
@@ -140,18 +228,58 @@ impl From<ActiveBoard> for CompletedBoard {
 impl Players {
     pub fn new(player_id_1: String, player_id_2: String) -> Self {
         Players {
-            ids: (player_id_1.clone(), player_id_2.clone()),
             as_vec: vec![player_id_1, player_id_2],
+            shared_board: false,
+        }
+    }
+
+    pub fn shared(player_ids: Vec<String>) -> Self {
+        Players {
+            as_vec: player_ids,
+            shared_board: true,
+        }
+    }
+
+    /// Only for games where each player has their own board.
+    pub fn get_id(&self, side: PlayerSide) -> &String {
+        match side {
+            PlayerSide::Left => &self.as_vec[0],
+            PlayerSide::Right => &self.as_vec[1],
         }
     }
 
     pub fn get_side(&self, client_player_id: &String) -> Option<PlayerSide> {
-        if client_player_id == &self.ids.0 {
+        let index = self.as_vec.iter().position(|player_id| player_id == client_player_id)?;
+        if self.shared_board || index == 0 {
             Some(PlayerSide::Left)
-        } else if client_player_id == &self.ids.1 {
-            Some(PlayerSide::Right)
         } else {
-            None
+            Some(PlayerSide::Right)
         }
     }
-}
\ No newline at end of file
+}
+
+impl PlayerSide {
+    pub fn opponent(self) -> Self {
+        match self {
+            PlayerSide::Left => PlayerSide::Right,
+            PlayerSide::Right => PlayerSide::Left,
+        }
+    }
+}
+
+/// `Instant`s are only meaningful within a single process, so they're stored as the time elapsed
+/// so far. Time that passes while the game isn't loaded (e.g. during a server restart) is lost.
+mod elapsed_since {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::{Duration, Instant};
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        Instant::now().saturating_duration_since(*instant).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let elapsed = Duration::deserialize(deserializer)?;
+        let now = Instant::now();
+        Ok(now.checked_sub(elapsed).unwrap_or(now))
+    }
+}
@@ -0,0 +1,48 @@
+mod row_tests {
+    use crate::types::{Row, NO_COLOR};
+
+    #[test]
+    fn row_saved_as_fixed_size_array_is_restored() {
+        let row: Row = serde_json::from_str(r#"{"pegs":[1,2,3,4],"max_color":8}"#).unwrap();
+
+        assert_eq!(4, row.len());
+        assert_eq!(vec![1, 2, 3, 4], (0..row.len()).map(|i| row.peg(i)).collect::<Vec<_>>());
+        assert_eq!(8, row.max_color());
+    }
+
+    #[test]
+    fn pegs_are_bounded_by_row_size_and_colors() {
+        let mut row = Row::new(8, 5);
+
+        assert!(row.try_set(7, 5).is_ok());
+        assert!(row.try_set(8, 1).is_err());
+        assert!(row.try_set(0, 6).is_err());
+        assert!(row.try_set(7, NO_COLOR).is_ok());
+    }
+
+    #[test]
+    fn blank_pegs_only_complete_a_row_when_allowed() {
+        let mut row = Row::new(3, 5);
+        assert!(!row.is_complete(false));
+        assert!(row.is_complete(true));
+
+        for i in 0..3 {
+            row.try_set(i, 1).unwrap();
+        }
+        assert!(row.is_complete(false));
+        assert!(row.is_complete(true));
+    }
+
+    #[test]
+    fn repeated_blanks_are_duplicates() {
+        let mut row = Row::new(3, 5);
+        row.try_set(0, 1).unwrap();
+        assert!(row.has_duplicate_colors());
+
+        row.try_set(1, 2).unwrap();
+        assert!(!row.has_duplicate_colors());
+
+        row.try_set(2, 1).unwrap();
+        assert!(row.has_duplicate_colors());
+    }
+}